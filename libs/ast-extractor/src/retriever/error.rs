@@ -6,18 +6,34 @@
 use syn_solidity::{ItemError, Visit};
 
 struct ErrorVisitor {
-    errors: Vec<ItemError>,
+    contract_errors: Vec<ItemError>,
+    file_errors: Vec<ItemError>,
+    inside_contract: bool,
 }
 
 impl ErrorVisitor {
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            contract_errors: Vec::new(),
+            file_errors: Vec::new(),
+            inside_contract: false,
+        }
     }
 }
 
 impl<'ast> Visit<'ast> for ErrorVisitor {
+    fn visit_item_contract(&mut self, i: &syn_solidity::ItemContract) {
+        self.inside_contract = true;
+        syn_solidity::visit::visit_item_contract(self, i);
+        self.inside_contract = false;
+    }
+
     fn visit_item_error(&mut self, i: &ItemError) {
-        self.errors.push(i.clone());
+        if self.inside_contract {
+            self.contract_errors.push(i.clone());
+        } else {
+            self.file_errors.push(i.clone());
+        }
         syn_solidity::visit::visit_item_error(self, i);
     }
 }
@@ -25,7 +41,13 @@ impl<'ast> Visit<'ast> for ErrorVisitor {
 pub fn retrieve_errors_nodes(ast: &syn_solidity::ItemContract) -> Vec<ItemError> {
     let mut visitor = ErrorVisitor::new();
     visitor.visit_item_contract(ast);
-    visitor.errors
+    visitor.contract_errors
+}
+
+pub fn retrieve_errors_file_nodes(ast: &syn_solidity::File) -> Vec<ItemError> {
+    let mut visitor = ErrorVisitor::new();
+    visitor.visit_file(ast);
+    visitor.file_errors
 }
 
 #[cfg(test)]
@@ -82,4 +104,34 @@ mod tests {
             panic!("Item should have a contract");
         }
     }
+
+    #[test]
+    fn test_retrieve_error_file_nodes_empty() {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests");
+        path.push("files");
+        path.push("errors");
+        path.push("empty.sol");
+        let source = fs::read_to_string(path).unwrap();
+        let tokens = TokenStream::from_str(source.as_str()).unwrap();
+        let ast = syn_solidity::parse2(tokens).unwrap();
+
+        let res = retrieve_errors_file_nodes(&ast);
+        assert_eq!(res.len(), 0);
+    }
+
+    #[test]
+    fn test_retrieve_error_file_nodes_one() {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests");
+        path.push("files");
+        path.push("errors");
+        path.push("one.sol");
+        let source = fs::read_to_string(path).unwrap();
+        let tokens = TokenStream::from_str(source.as_str()).unwrap();
+        let ast = syn_solidity::parse2(tokens).unwrap();
+
+        let res = retrieve_errors_file_nodes(&ast);
+        assert_eq!(res.len(), 1);
+    }
 }
@@ -30,9 +30,18 @@ pub fn retrieve_variable_definition_nodes(ast: &syn_solidity::File) -> Vec<Varia
     visitor.variables
 }
 
+pub fn retrieve_variable_definition_contract_nodes(
+    ast: &syn_solidity::ItemContract,
+) -> Vec<VariableDefinition> {
+    let mut visitor = VariableDefinitionVisitor::new();
+    visitor.visit_item_contract(ast);
+    visitor.variables
+}
+
 #[cfg(test)]
 mod tests {
     use proc_macro2::TokenStream;
+    use syn_solidity::Item;
 
     use super::*;
     use std::fs;
@@ -61,4 +70,24 @@ mod tests {
         let res = retrieve_variable_definition_nodes(&ast);
         assert_eq!(res.len(), 1);
     }
+
+    #[test]
+    fn test_retrieve_variable_definition_contract_nodes_one() {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests");
+        path.push("files");
+        path.push("variables_definition");
+        path.push("file.sol");
+        let source = fs::read_to_string(path).unwrap();
+        let tokens = TokenStream::from_str(source.as_str()).unwrap();
+        let ast = syn_solidity::parse2(tokens).unwrap();
+        let item = ast.items.first().unwrap().clone();
+
+        if let Item::Contract(contract) = item {
+            let res = retrieve_variable_definition_contract_nodes(&contract);
+            assert_eq!(res.len(), 1);
+        } else {
+            panic!("Item should be a contract");
+        }
+    }
 }
@@ -15,6 +15,7 @@ use lsp_types::*;
 use serde_json::Value;
 pub use server::LspStdioServer;
 pub use service::LspService;
+use std::time::Duration;
 
 /// Trait implemented by language server backends.
 ///
@@ -1217,6 +1218,23 @@ pub trait LanguageServer {
         eprintln!("Got a response, but it is not implemented");
     }
 
+    /// The delay after which [`on_timeout`] is called if no message is received from the client
+    /// in the meantime, or `None` to wait for the next message.
+    ///
+    /// [`on_timeout`]: Self::on_timeout
+    ///
+    /// This method is called before waiting for each message, allowing the server to run delayed
+    /// work, such as debounced diagnostics, without leaving the thread handling the messages.
+    fn next_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Called when the delay returned by [`next_timeout`] elapsed without any message received
+    /// from the client.
+    ///
+    /// [`next_timeout`]: Self::next_timeout
+    fn on_timeout(&self) {}
+
     // TODO: Add `work_done_progress_cancel()` here (since 3.15.0) when supported by `tower-lsp`.
     // https://github.com/ebkalderon/tower-lsp/issues/176
 }
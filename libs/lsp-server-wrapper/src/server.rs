@@ -92,7 +92,22 @@ impl<'a> LspStdioServer {
     }
 
     fn serve_loop<S: LanguageServer>(&self, service: LspService<S>) -> Result<(), Box<dyn Error>> {
-        for msg in &self.inner.connection.receiver {
+        let receiver = &self.inner.connection.receiver;
+        loop {
+            let msg = match service.next_timeout() {
+                Some(timeout) => match receiver.recv_timeout(timeout) {
+                    Ok(msg) => msg,
+                    Err(e) if e.is_timeout() => {
+                        service.call_timeout();
+                        continue;
+                    }
+                    Err(_) => break,
+                },
+                None => match receiver.recv() {
+                    Ok(msg) => msg,
+                    Err(_) => break,
+                },
+            };
             match msg {
                 Message::Request(req) => {
                     let id = req.id.clone();
@@ -10,6 +10,7 @@ use std::cell::RefCell;
 use std::error::Error;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub(super) struct InnerService<S> {
     backend: Arc<S>,
@@ -330,4 +331,12 @@ impl<S: LanguageServer> LspService<S> {
     pub fn call_response(&self, id: RequestId, result: Option<serde_json::Value>) {
         self.inner.backend.on_response(id, result)
     }
+
+    pub fn next_timeout(&self) -> Option<Duration> {
+        self.inner.backend.next_timeout()
+    }
+
+    pub fn call_timeout(&self) {
+        self.inner.backend.on_timeout()
+    }
 }
@@ -0,0 +1,269 @@
+use solidhunter_lib::project::normalize_path;
+use solidhunter_lib::types::LintResult;
+use std::collections::HashMap;
+use std::process::Command;
+
+/// Lines added or modified in a file, `None` when the whole file is new.
+pub type ChangedLines = HashMap<String, Option<Vec<(usize, usize)>>>;
+
+fn git(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+// A path printed by git, which is quoted with C escapes when it has special characters
+// such as quotes or non-ASCII characters, and followed by a tab in the diff headers when it
+// has spaces
+fn unquote_path(path: &str) -> String {
+    let path = path.trim_end_matches('\t');
+    let quoted = match path
+        .strip_prefix('"')
+        .and_then(|path| path.strip_suffix('"'))
+    {
+        Some(quoted) => quoted,
+        None => return path.to_string(),
+    };
+
+    let mut bytes = Vec::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+        match chars.next() {
+            Some('n') => bytes.push(b'\n'),
+            Some('t') => bytes.push(b'\t'),
+            Some('a') => bytes.push(0x07),
+            Some('b') => bytes.push(0x08),
+            Some('f') => bytes.push(0x0c),
+            Some('r') => bytes.push(b'\r'),
+            Some('v') => bytes.push(0x0b),
+            // the bytes of the non-ASCII characters are written in octal
+            Some(digit @ '0'..='7') => {
+                let octal: String = std::iter::once(digit)
+                    .chain(chars.clone().take(2))
+                    .collect();
+                chars.nth(1);
+                bytes.push(u8::from_str_radix(&octal, 8).unwrap_or(b'?'));
+            }
+            Some(c) => bytes.push(c as u8),
+            None => {}
+        }
+    }
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
+// The new side of the hunk headers of a diff without context, `@@ -a,b +c,d @@`
+fn parse_hunks(diff: &str) -> HashMap<String, Vec<(usize, usize)>> {
+    let mut hunks: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+    let mut current = None;
+
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ ") {
+            current = unquote_path(path).strip_prefix("b/").map(normalize_path);
+        } else if let (Some(header), Some(file)) = (line.strip_prefix("@@ "), &current) {
+            let new_range = header
+                .split_whitespace()
+                .find_map(|range| range.strip_prefix('+'));
+            let parsed = new_range.and_then(|range| match range.split_once(',') {
+                Some((start, count)) => Some((start.parse::<usize>().ok()?, count.parse().ok()?)),
+                None => Some((range.parse::<usize>().ok()?, 1)),
+            });
+            // a hunk removing lines has no new line
+            if let Some((start, count)) = parsed.filter(|(_, count)| *count > 0) {
+                hunks
+                    .entry(file.clone())
+                    .or_default()
+                    .push((start, start + count - 1));
+            }
+        }
+    }
+    hunks
+}
+
+/// The Solidity files of the working tree which differ from `git_ref`, including the
+/// untracked ones, with their changed lines. The paths are relative to the current directory.
+pub fn changed_files(git_ref: &str) -> Result<ChangedLines, String> {
+    let diff = git(&[
+        "diff",
+        "--relative",
+        "--no-color",
+        "--no-ext-diff",
+        "--diff-filter=ACMR",
+        "-U0",
+        git_ref,
+        "--",
+        "*.sol",
+    ])?;
+    let untracked = git(&["ls-files", "--others", "--exclude-standard", "--", "*.sol"])?;
+
+    let mut changed: ChangedLines = parse_hunks(&diff)
+        .into_iter()
+        .map(|(file, lines)| (file, Some(lines)))
+        .collect();
+    // renamed or mode-only changes have no hunk but still differ
+    for file in git(&[
+        "diff",
+        "--relative",
+        "--name-only",
+        "--diff-filter=ACMR",
+        git_ref,
+        "--",
+        "*.sol",
+    ])?
+    .lines()
+    {
+        changed
+            .entry(normalize_path(&unquote_path(file)))
+            .or_insert_with(|| Some(Vec::new()));
+    }
+    for file in untracked.lines() {
+        changed.insert(normalize_path(&unquote_path(file)), None);
+    }
+    Ok(changed)
+}
+
+/// Remove the diagnostics which are not on a changed line.
+pub fn filter_changed_lines(results: &mut [LintResult], changed: &ChangedLines) {
+    for file in results.iter_mut().flatten() {
+        file.diags
+            .retain(|diag| match changed.get(&normalize_path(&diag.uri)) {
+                Some(Some(lines)) => lines.iter().any(|(start, end)| {
+                    diag.range.start.line <= *end && diag.range.end.line >= *start
+                }),
+                Some(None) => true,
+                None => false,
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solidhunter_lib::types::{FileDiags, LintDiag, Position, Range, Severity};
+
+    fn diag(uri: &str, start: usize, end: usize) -> LintDiag {
+        LintDiag {
+            range: Range {
+                start: Position {
+                    line: start,
+                    character: 0,
+                },
+                end: Position {
+                    line: end,
+                    character: 1,
+                },
+            },
+            severity: Severity::WARNING,
+            code: None,
+            source: None,
+            message: String::new(),
+            id: "rule".to_string(),
+            uri: uri.to_string(),
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn hunks_are_parsed() {
+        let cases: &[(&str, &[(usize, usize)])] = &[
+            ("@@ -1,2 +1,3 @@", &[(1, 3)]),
+            // a single line has no count
+            ("@@ -4 +5 @@ contract A {", &[(5, 5)]),
+            // a pure deletion has no new line
+            ("@@ -3,2 +2,0 @@", &[]),
+            ("@@ -1 +1 @@\n@@ -8,0 +9,2 @@", &[(1, 1), (9, 10)]),
+        ];
+        for (hunks, expected) in cases {
+            let diff = format!("--- a/A.sol\n+++ b/A.sol\n{}\n", hunks);
+            let parsed = parse_hunks(&diff);
+            assert_eq!(
+                parsed.get("A.sol").map_or(&[][..], Vec::as_slice),
+                *expected,
+                "{}",
+                hunks
+            );
+        }
+    }
+
+    #[test]
+    fn hunks_are_keyed_by_new_path() {
+        let diff = "diff --git a/Old.sol b/src/New.sol
+similarity index 90%
+rename from Old.sol
+rename to src/New.sol
+--- a/Old.sol
++++ b/src/New.sol
+@@ -2 +2 @@
+-contract Old {}
++contract New {}
+--- a/Removed.sol
++++ /dev/null
+@@ -1 +0,0 @@
+";
+        let parsed = parse_hunks(diff);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed["src/New.sol"], vec![(2, 2)]);
+    }
+
+    #[test]
+    fn quoted_paths_are_unquoted() {
+        let cases = [
+            ("A.sol", "A.sol"),
+            ("b/a b.sol\t", "b/a b.sol"),
+            ("\"b/a b.sol\"", "b/a b.sol"),
+            ("\"b/q\\\"t.sol\"", "b/q\"t.sol"),
+            ("\"b/\\303\\251.sol\"", "b/\u{e9}.sol"),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(unquote_path(path), expected, "{}", path);
+        }
+
+        let diff = "+++ \"b/\\303\\251 b.sol\"\n@@ -1 +1 @@\n";
+        assert_eq!(parse_hunks(diff)["\u{e9} b.sol"], vec![(1, 1)]);
+    }
+
+    #[test]
+    fn diagnostics_out_of_changed_lines_are_removed() {
+        let mut results = vec![Ok(FileDiags::new(
+            String::new(),
+            vec![
+                diag("./A.sol", 1, 1),
+                diag("A.sol", 4, 6),
+                diag("A.sol", 9, 9),
+            ],
+        ))];
+        let changed: ChangedLines = HashMap::from([("A.sol".to_string(), Some(vec![(5, 5)]))]);
+        filter_changed_lines(&mut results, &changed);
+        let kept: Vec<usize> = results[0]
+            .as_ref()
+            .unwrap()
+            .diags
+            .iter()
+            .map(|diag| diag.range.start.line)
+            .collect();
+        assert_eq!(kept, vec![4]);
+
+        // every problem of a new file is kept, none of an unchanged file
+        let mut results = vec![
+            Ok(FileDiags::new(String::new(), vec![diag("New.sol", 3, 3)])),
+            Ok(FileDiags::new(String::new(), vec![diag("Same.sol", 3, 3)])),
+        ];
+        let changed: ChangedLines = HashMap::from([("New.sol".to_string(), None)]);
+        filter_changed_lines(&mut results, &changed);
+        assert_eq!(results[0].as_ref().unwrap().diags.len(), 1);
+        assert!(results[1].as_ref().unwrap().diags.is_empty());
+    }
+}
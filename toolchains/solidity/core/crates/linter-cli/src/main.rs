@@ -1,8 +1,62 @@
-use clap::{arg, Parser};
+use clap::{Parser, ValueEnum};
+use git::{changed_files, filter_changed_lines, ChangedLines};
+use similar::TextDiff;
+use solidhunter_lib::baseline::{Baseline, DEFAULT_BASELINE_FILE};
+use solidhunter_lib::cache::DEFAULT_CACHE_FILE;
 use solidhunter_lib::errors::SolidHunterError;
 use solidhunter_lib::linter::SolidLinter;
+use solidhunter_lib::project::normalize_path;
+use solidhunter_lib::reporters::{
+    CheckstyleReporter, GithubReporter, JsonReporter, JunitReporter, Reporter, SarifReporter,
+    TextReporter,
+};
+use solidhunter_lib::rules::create_documentation;
 use solidhunter_lib::rules::rule_impl::create_rules_file;
-use solidhunter_lib::types::LintResult;
+use solidhunter_lib::rules::schema::create_config_schema;
+use solidhunter_lib::types::{LintResult, Severity};
+use std::io::Read;
+use std::process::ExitCode;
+
+mod git;
+mod watch;
+
+// Exit codes, from the least to the most important
+const EXIT_FINDINGS: u8 = 1;
+const EXIT_CONFIG_ERROR: u8 = 2;
+const EXIT_PARSE_ERROR: u8 = 3;
+const EXIT_IO_ERROR: u8 = 4;
+
+// Path of the content read from the standard input, without --stdin-filename
+const STDIN_FILENAME: &str = "stdin.sol";
+
+#[derive(ValueEnum, Clone, Debug, PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Sarif,
+    Junit,
+    Checkstyle,
+    Github,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum FailOn {
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
+
+impl From<FailOn> for Severity {
+    fn from(fail_on: FailOn) -> Self {
+        match fail_on {
+            FailOn::Error => Severity::ERROR,
+            FailOn::Warning => Severity::WARNING,
+            FailOn::Info => Severity::INFO,
+            FailOn::Hint => Severity::HINT,
+        }
+    }
+}
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -26,6 +80,15 @@ struct Args {
     )]
     to_json: bool,
 
+    #[arg(
+        short = 'f',
+        long = "format",
+        value_enum,
+        default_value = "text",
+        help = "Output format"
+    )]
+    format: Format,
+
     #[arg(
         short = 'v',
         long = "verbose",
@@ -52,22 +115,332 @@ struct Args {
         help = "exposes rules documentation"
     )]
     documentation: bool,
+
+    #[arg(
+        long = "print-schema",
+        default_value = "false",
+        help = "Print the JSON Schema of the rules file"
+    )]
+    print_schema: bool,
+
+    #[arg(
+        long = "fix",
+        default_value = "false",
+        conflicts_with = "fix_dry_run",
+        help = "Fix the problems that can be fixed automatically and rewrite the files"
+    )]
+    fix: bool,
+
+    #[arg(
+        long = "fix-dry-run",
+        default_value = "false",
+        help = "Print a diff of the changes --fix would make, without writing the files"
+    )]
+    fix_dry_run: bool,
+
+    #[arg(
+        long = "fail-on",
+        value_enum,
+        default_value = "error",
+        help = "Exit with an error code if a problem of this severity or above is found"
+    )]
+    fail_on: FailOn,
+
+    #[arg(
+        long = "max-warnings",
+        help = "Exit with an error code if more than this number of warnings are found"
+    )]
+    max_warnings: Option<usize>,
+
+    #[arg(
+        long = "cache",
+        default_value = "false",
+        help = "Only lint the files changed since the previous run"
+    )]
+    cache: bool,
+
+    #[arg(
+        long = "cache-location",
+        default_value = DEFAULT_CACHE_FILE,
+        help = "Specify cache file"
+    )]
+    cache_location: String,
+
+    #[arg(
+        long = "baseline",
+        default_value = DEFAULT_BASELINE_FILE,
+        help = "Specify baseline file, whose findings are not reported when it exists"
+    )]
+    baseline: String,
+
+    #[arg(
+        long = "write-baseline",
+        default_value = "false",
+        conflicts_with_all = ["fix", "fix_dry_run"],
+        help = "Record the current findings in the baseline file"
+    )]
+    write_baseline: bool,
+
+    #[arg(
+        long = "prune-baseline",
+        default_value = "false",
+        help = "Remove the findings which are not found anymore from the baseline file"
+    )]
+    prune_baseline: bool,
+
+    #[arg(
+        long = "include-deps",
+        default_value = "false",
+        help = "Lint the dependencies of a Foundry project too, not only its sources"
+    )]
+    include_deps: bool,
+
+    #[arg(
+        long = "watch",
+        default_value = "false",
+        conflicts_with_all = ["fix", "fix_dry_run", "write_baseline", "prune_baseline"],
+        help = "Lint the changed files again when they are saved"
+    )]
+    watch: bool,
+
+    #[arg(
+        long = "stdin",
+        default_value = "false",
+        conflicts_with_all = ["fix", "fix_dry_run", "watch", "write_baseline", "changed_since"],
+        help = "Lint the content read from the standard input"
+    )]
+    stdin: bool,
+
+    #[arg(
+        long = "stdin-filename",
+        requires = "stdin",
+        help = "Path of the file read from the standard input, to find its rules"
+    )]
+    stdin_filename: Option<String>,
+
+    #[arg(
+        long = "changed-since",
+        value_name = "GIT_REF",
+        conflicts_with = "watch",
+        help = "Only lint the files which differ from a git reference"
+    )]
+    changed_since: Option<String>,
+
+    #[arg(
+        long = "changed-lines-only",
+        default_value = "false",
+        requires = "changed_since",
+        help = "Only report the problems on the lines changed since the git reference"
+    )]
+    changed_lines_only: bool,
+}
+
+impl Args {
+    // --json is kept as a shorthand for --format json
+    fn format(&self) -> Format {
+        if self.to_json {
+            Format::Json
+        } else {
+            self.format.clone()
+        }
+    }
+}
+
+fn create_reporter(format: &Format) -> Box<dyn Reporter> {
+    match format {
+        Format::Text => Box::new(TextReporter),
+        Format::Json => Box::new(JsonReporter),
+        Format::Sarif => Box::new(SarifReporter::new(create_documentation())),
+        Format::Junit => Box::new(JunitReporter),
+        Format::Checkstyle => Box::new(CheckstyleReporter),
+        Format::Github => Box::new(GithubReporter),
+    }
 }
 
-fn print_result(results: Vec<LintResult>) {
-    for result in results {
-        match result {
-            Ok(diags) => {
-                println!("{}", &diags);
+fn print_diff(filepath: &str, old: &str, new: &str, format: &Format) {
+    let diff = TextDiff::from_lines(old, new);
+    let diff = diff
+        .unified_diff()
+        .header(&format!("a/{}", filepath), &format!("b/{}", filepath))
+        .to_string();
+    // keep stdout parsable for machine readable formats
+    if *format == Format::Text {
+        print!("{}", diff);
+    } else {
+        eprint!("{}", diff);
+    }
+}
+
+fn error_exit_code(error: &SolidHunterError) -> u8 {
+    match error {
+        SolidHunterError::AstError(_) | SolidHunterError::LinterError(_) => EXIT_PARSE_ERROR,
+        SolidHunterError::ParsingError(_) => EXIT_IO_ERROR,
+        SolidHunterError::SerdeError(_)
+        | SolidHunterError::IoError(_)
+        | SolidHunterError::ConfigError(_)
+        | SolidHunterError::FoundryConfigError(_) => EXIT_CONFIG_ERROR,
+        SolidHunterError::FileError(_, error) => error_exit_code(error),
+    }
+}
+
+// The most important exit code of the files which couldn't be linted, if any
+fn errors_exit_code(results: &[LintResult]) -> Option<u8> {
+    results
+        .iter()
+        .filter_map(|result| result.as_ref().err())
+        .map(error_exit_code)
+        .max()
+}
+
+// Compute the exit code from the severity of the diagnostics, errors taking precedence
+fn exit_code(results: &[LintResult], args: &Args) -> u8 {
+    if let Some(code) = errors_exit_code(results) {
+        return code;
+    }
+
+    let diags = results.iter().flatten().flat_map(|file| &file.diags);
+    let mut warnings = 0;
+    let mut above_threshold = false;
+    for diag in diags {
+        if diag.severity == Severity::WARNING {
+            warnings += 1;
+        }
+        above_threshold |= diag.severity.is_at_least(args.fail_on.into());
+    }
+
+    if let Some(max_warnings) = args.max_warnings {
+        if warnings > max_warnings {
+            eprintln!(
+                "Found {} warning(s), more than the maximum of {}",
+                warnings, max_warnings
+            );
+            return EXIT_FINDINGS;
+        }
+    }
+    if above_threshold {
+        EXIT_FINDINGS
+    } else {
+        0
+    }
+}
+
+struct FixSummary {
+    fixed_files: usize,
+    fixed_diags: usize,
+    remaining: Vec<LintResult>,
+}
+
+// Fix the files of the paths and lint them again to get the remaining diagnostics
+fn fix_paths(linter: &mut SolidLinter, paths: &[String], args: &Args) -> FixSummary {
+    let mut summary = FixSummary {
+        fixed_files: 0,
+        fixed_diags: 0,
+        remaining: Vec::new(),
+    };
+
+    for path in paths {
+        for filepath in linter.get_path_files(path) {
+            if linter.is_excluded(&filepath) {
+                continue;
             }
-            Err(e) => {
-                println!("{}", e);
+            let original = match std::fs::read_to_string(&filepath) {
+                Ok(content) => content,
+                Err(e) => {
+                    summary
+                        .remaining
+                        .push(Err(SolidHunterError::from(e).in_file(&filepath)));
+                    continue;
+                }
+            };
+            let result = match linter.fix_content(&filepath, &original) {
+                Ok(result) => result,
+                Err(e) => {
+                    summary.remaining.push(Err(e.in_file(&filepath)));
+                    continue;
+                }
+            };
+
+            if !result.applied.is_empty() {
+                summary.fixed_files += 1;
+                summary.fixed_diags += result.applied.len();
+                if args.fix_dry_run {
+                    print_diff(&filepath, &original, &result.content, &args.format());
+                } else if let Err(e) = std::fs::write(&filepath, &result.content) {
+                    summary
+                        .remaining
+                        .push(Err(SolidHunterError::from(e).in_file(&filepath)));
+                    continue;
+                }
             }
+            let remaining = linter.parse_content(&filepath, &result.content);
+            summary
+                .remaining
+                .push(remaining.map_err(|e| e.in_file(&filepath)));
         }
     }
+    summary
+}
+
+// Hide the findings recorded in the baseline, after removing its stale entries if asked
+fn apply_baseline(
+    linter: &SolidLinter,
+    paths: &[String],
+    results: &mut [LintResult],
+    args: &Args,
+) -> Result<usize, SolidHunterError> {
+    if !std::path::Path::new(&args.baseline).is_file() {
+        return Ok(0);
+    }
+    let mut baseline = Baseline::load(&args.baseline)?;
+
+    if args.prune_baseline {
+        let linted_files: Vec<String> = paths
+            .iter()
+            .flat_map(|path| linter.get_path_files(path))
+            .collect();
+        let pruned = baseline.prune(results, &linted_files);
+        baseline.save(&args.baseline)?;
+        eprintln!("Pruned {} finding(s) from the baseline", pruned);
+    }
+    Ok(baseline.filter(results))
+}
+
+fn lint_stdin(linter: &mut SolidLinter, args: &Args) -> LintResult {
+    let mut content = String::new();
+    std::io::stdin().read_to_string(&mut content)?;
+    let filepath = args.stdin_filename.as_deref().unwrap_or(STDIN_FILENAME);
+    linter.parse_content(filepath, &content)
+}
+
+// The files of the paths which differ from the git reference, with their changed lines
+fn changed_paths(
+    linter: &SolidLinter,
+    paths: &[String],
+    git_ref: &str,
+) -> Result<(Vec<String>, ChangedLines), String> {
+    let changed = changed_files(git_ref)?;
+    let files = paths
+        .iter()
+        .flat_map(|path| linter.get_path_files(path))
+        .filter(|filepath| changed.contains_key(&normalize_path(filepath)))
+        .collect();
+    Ok((files, changed))
+}
+
+fn initialize_linter(linter: &mut SolidLinter, args: &Args) -> Result<(), SolidHunterError> {
+    if !args.paths.is_empty() {
+        linter.initialize_rules(
+            &(args.paths[0].as_str().to_owned() + "/" + args.rules_file.as_str()),
+        )?;
+    } else {
+        linter.initialize_rules(&args.rules_file)?;
+    }
+    linter.initialize_foundry(args.paths.first().map_or(".", String::as_str))?;
+    linter.set_include_dependencies(args.include_deps);
+    linter.initialize_excluded_files(args.exclude.as_ref(), &args.paths)
 }
 
-fn main() -> Result<(), SolidHunterError> {
+fn main() -> ExitCode {
     let args = Args::parse();
 
     if args.documentation {
@@ -82,10 +455,19 @@ fn main() -> Result<(), SolidHunterError> {
                 println!("{}", e);
             }
         }
-        return Ok(());
+        return ExitCode::SUCCESS;
+    }
+
+    if args.print_schema {
+        match serde_json::to_string_pretty(&create_config_schema()) {
+            Ok(schema) => println!("{}", schema),
+            Err(e) => println!("{}", e),
+        }
+        return ExitCode::SUCCESS;
     }
 
-    if !args.to_json {
+    let format = args.format();
+    if format == Format::Text {
         println!();
         println!("SolidHunter: Fast and efficient Solidity linter");
         println!(
@@ -102,7 +484,23 @@ fn main() -> Result<(), SolidHunterError> {
         println!("Using rules file: {}", args.rules_file);
         println!("Verbose output: {}", args.verbose);
         println!("Excluded files: {:?}", args.exclude);
+        println!("Output format: {:?}", format);
         println!("Documentation output: {}", args.documentation);
+        println!("Fix: {}", args.fix);
+        println!("Fix dry run: {}", args.fix_dry_run);
+        println!("Fail on: {:?}", args.fail_on);
+        println!("Max warnings: {:?}", args.max_warnings);
+        println!("Cache: {}", args.cache);
+        println!("Cache location: {}", args.cache_location);
+        println!("Baseline: {}", args.baseline);
+        println!("Write baseline: {}", args.write_baseline);
+        println!("Prune baseline: {}", args.prune_baseline);
+        println!("Include dependencies: {}", args.include_deps);
+        println!("Watch: {}", args.watch);
+        println!("Stdin: {}", args.stdin);
+        println!("Stdin filename: {:?}", args.stdin_filename);
+        println!("Changed since: {:?}", args.changed_since);
+        println!("Changed lines only: {}", args.changed_lines_only);
     }
 
     if args.init {
@@ -118,52 +516,120 @@ fn main() -> Result<(), SolidHunterError> {
             }
         }
         println!("Done!");
-        return Ok(());
+        return ExitCode::SUCCESS;
     }
 
-    let mut linter: SolidLinter = SolidLinter::new();
-    if !args.paths.is_empty() {
-        linter.initialize_rules(
-            &(args.paths[0].as_str().to_owned() + "/" + args.rules_file.as_str()),
-        )?;
+    // If no path is specified, we use the current directory
+    let mut paths = if args.paths.is_empty() {
+        vec![".".to_string()]
     } else {
-        linter.initialize_rules(&args.rules_file)?;
+        args.paths.clone()
+    };
+
+    if args.watch {
+        return watch::watch(&args, &paths);
     }
-    linter.initialize_excluded_files(args.exclude.as_ref(), &args.paths)?;
 
-    let mut results = vec![];
-    for path in &args.paths {
-        let result = linter.parse_path(path);
-        results.push(result);
+    let mut linter: SolidLinter = SolidLinter::new();
+    if let Err(e) = initialize_linter(&mut linter, &args) {
+        match e {
+            SolidHunterError::ConfigError(_) => eprintln!("{}", e),
+            _ => eprintln!("Error: {:?}", e),
+        }
+        return ExitCode::from(EXIT_CONFIG_ERROR);
     }
-    // If no path is specified, we use the current directory
-    if args.paths.is_empty() {
-        let result = linter.parse_path(".");
-        results.push(result);
+    if args.cache {
+        linter.initialize_cache(&args.cache_location);
     }
-    for path_result in results {
-        if !args.to_json {
-            print_result(path_result);
-        } else {
-            for res in path_result {
-                match res {
-                    Ok(diags) => {
-                        let json = serde_json::to_string_pretty(&diags);
-                        match json {
-                            Ok(j) => {
-                                println!("{}", j);
-                            }
-                            Err(e) => {
-                                println!("{}", e);
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        println!("{}", e);
-                    }
-                }
+
+    let mut changed_lines = None;
+    if let Some(git_ref) = &args.changed_since {
+        match changed_paths(&linter, &paths, git_ref) {
+            Ok((changed_paths, lines)) => {
+                paths = changed_paths;
+                changed_lines = Some(lines);
             }
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::from(EXIT_CONFIG_ERROR);
+            }
+        }
+    }
+
+    let mut fix_summary = None;
+    let mut would_fix = false;
+    let mut results = if args.fix || args.fix_dry_run {
+        let summary = fix_paths(&mut linter, &paths, &args);
+        let remaining_diags: usize = summary
+            .remaining
+            .iter()
+            .flatten()
+            .map(|diags| diags.diags.len())
+            .sum();
+
+        would_fix = args.fix_dry_run && summary.fixed_diags > 0;
+        fix_summary = Some(format!(
+            "{} {} problem(s) in {} file(s), {} problem(s) remaining",
+            if args.fix_dry_run {
+                "Would fix"
+            } else {
+                "Fixed"
+            },
+            summary.fixed_diags,
+            summary.fixed_files,
+            remaining_diags
+        ));
+        summary.remaining
+    } else if args.stdin {
+        vec![lint_stdin(&mut linter, &args)]
+    } else {
+        let mut results = vec![];
+        for path in &paths {
+            results.append(&mut linter.parse_path(path));
+        }
+        if let Err(e) = linter.save_cache() {
+            eprintln!("Failed to write the cache file: {}", e);
+        }
+        results
+    };
+
+    if args.write_baseline {
+        let baseline = Baseline::from_results(&results);
+        if let Err(e) = baseline.save(&args.baseline) {
+            eprintln!("Failed to write the baseline file: {}", e);
+            return ExitCode::from(EXIT_CONFIG_ERROR);
+        }
+        println!(
+            "Recorded {} finding(s) in {}",
+            baseline.len(),
+            args.baseline
+        );
+        return ExitCode::from(errors_exit_code(&results).unwrap_or(0));
+    }
+
+    if let (true, Some(changed_lines)) = (args.changed_lines_only, &changed_lines) {
+        filter_changed_lines(&mut results, changed_lines);
+    }
+
+    let hidden = match apply_baseline(&linter, &paths, &mut results, &args) {
+        Ok(hidden) => hidden,
+        Err(e) => {
+            eprintln!("Failed to use the baseline file {}: {}", args.baseline, e);
+            return ExitCode::from(EXIT_CONFIG_ERROR);
         }
+    };
+
+    print!("{}", create_reporter(&format).report(&results));
+    if let (Format::Text, Some(summary)) = (&format, fix_summary) {
+        println!("{}", summary);
+    }
+    if format == Format::Text && hidden > 0 {
+        println!("{} problem(s) hidden by the baseline", hidden);
+    }
+
+    let code = exit_code(&results, &args);
+    if would_fix {
+        return ExitCode::from(code.max(EXIT_FINDINGS));
     }
-    Ok(())
+    ExitCode::from(code)
 }
@@ -0,0 +1,235 @@
+use crate::{apply_baseline, create_reporter, initialize_linter, Args, Format, EXIT_CONFIG_ERROR};
+use glob::glob;
+use solidhunter_lib::errors::SolidHunterError;
+use solidhunter_lib::foundry::{FOUNDRY_CONFIG_FILE, REMAPPINGS_FILE};
+use solidhunter_lib::linter::SolidLinter;
+use solidhunter_lib::project::absolute_path;
+use solidhunter_lib::types::{FileDiags, LintResult};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+use std::{fs, thread};
+
+// There is no portable file system notification API in std, the files are polled instead
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+const CLEAR_SCREEN: &str = "\x1B[2J\x1B[1;1H";
+
+type Snapshot = HashMap<String, Option<SystemTime>>;
+
+fn modified(path: &str) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+// The files changing the rules or the files to lint when they change
+fn config_files(args: &Args, paths: &[String]) -> Vec<String> {
+    let rules_file = Path::new(&args.rules_file)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(&args.rules_file)
+        .to_string();
+    let names = [
+        rules_file.as_str(),
+        ".solidhunterignore",
+        FOUNDRY_CONFIG_FILE,
+        REMAPPINGS_FILE,
+    ];
+
+    let mut files = vec![args.rules_file.clone()];
+    for path in paths {
+        for name in names {
+            if let Ok(entries) = glob(&format!("{}/**/{}", path, name)) {
+                files.extend(entries.flatten().map(|entry| entry.display().to_string()));
+            }
+        }
+    }
+    files
+}
+
+fn source_files(linter: &SolidLinter, paths: &[String]) -> Snapshot {
+    paths
+        .iter()
+        .flat_map(|path| linter.get_path_files(path))
+        .map(|filepath| {
+            let modified = modified(&filepath);
+            (filepath, modified)
+        })
+        .collect()
+}
+
+fn config_snapshot(args: &Args, paths: &[String]) -> Snapshot {
+    config_files(args, paths)
+        .into_iter()
+        .map(|filepath| {
+            let modified = modified(&filepath);
+            (filepath, modified)
+        })
+        .collect()
+}
+
+fn create_linter(args: &Args) -> Result<SolidLinter, String> {
+    let mut linter = SolidLinter::new();
+    initialize_linter(&mut linter, args).map_err(|e| e.to_string())?;
+    Ok(linter)
+}
+
+struct Watcher<'a> {
+    args: &'a Args,
+    paths: &'a [String],
+    linter: SolidLinter,
+    sources: Snapshot,
+    configs: Snapshot,
+    // the errors can't be cloned to be reported again, their message is kept instead, without
+    // the file which is the key
+    results: BTreeMap<String, Result<FileDiags, String>>,
+    // the last configuration error, kept on the screen until the configuration is fixed
+    config_error: Option<String>,
+}
+
+impl<'a> Watcher<'a> {
+    fn lint_all(&mut self) {
+        self.sources = source_files(&self.linter, self.paths);
+        self.results.clear();
+        for path in self.paths {
+            let filepaths = self.linter.get_path_files(path);
+            // the results of a folder are in the order of its files
+            let results = self.linter.parse_path(path);
+            for (filepath, result) in filepaths.into_iter().zip(results) {
+                self.results
+                    .insert(filepath, result.map_err(|e| e.cause().to_string()));
+            }
+        }
+    }
+
+    fn lint_file(&mut self, filepath: &str) {
+        let result = self.linter.parse_file(filepath.to_string());
+        self.results.insert(
+            filepath.to_string(),
+            result.map_err(|e| e.cause().to_string()),
+        );
+    }
+
+    // The files whose diagnostics may depend on a file, such as the files importing it or
+    // inheriting from its contracts
+    fn dependents(&self, filepath: &str) -> Vec<String> {
+        self.linter.project().dependencies(filepath)
+    }
+
+    // Lint the changed files again, then the files depending on them, and return whether
+    // something changed
+    fn lint_changes(&mut self) -> bool {
+        let sources = source_files(&self.linter, self.paths);
+        let mut dependents: BTreeSet<String> = BTreeSet::new();
+        let mut linted: BTreeSet<String> = BTreeSet::new();
+
+        for (filepath, modified) in &sources {
+            if self.sources.get(filepath) != Some(modified) {
+                // the files depending on it before and after the change
+                dependents.extend(self.dependents(filepath));
+                self.lint_file(filepath);
+                dependents.extend(self.dependents(filepath));
+                linted.insert(absolute_path(filepath));
+            }
+        }
+        for filepath in self.sources.keys() {
+            if !sources.contains_key(filepath) {
+                dependents.extend(self.dependents(filepath));
+                self.linter.delete_file(filepath);
+                self.results.remove(filepath);
+                linted.insert(absolute_path(filepath));
+            }
+        }
+
+        let changed = !linted.is_empty();
+        for filepath in sources.keys() {
+            let path = absolute_path(filepath);
+            if dependents.contains(&path) && !linted.contains(&path) {
+                self.lint_file(filepath);
+            }
+        }
+        self.sources = sources;
+        changed
+    }
+
+    // Rebuild the linter with the new configuration, keeping the previous one if it's invalid
+    fn reload(&mut self) {
+        match create_linter(self.args) {
+            Ok(linter) => {
+                self.linter = linter;
+                self.config_error = None;
+                self.lint_all();
+            }
+            Err(e) => self.config_error = Some(e),
+        }
+    }
+
+    fn print(&self) {
+        let format = self.args.format();
+        let mut results: Vec<LintResult> = self
+            .results
+            .iter()
+            .map(|(filepath, result)| match result {
+                Ok(diags) => Ok(diags.clone()),
+                Err(message) => {
+                    Err(SolidHunterError::LinterError(message.clone()).in_file(filepath))
+                }
+            })
+            .collect();
+        let hidden = apply_baseline(&self.linter, self.paths, &mut results, self.args).unwrap_or(0);
+
+        if format == Format::Text {
+            print!("{}", CLEAR_SCREEN);
+        }
+        print!("{}", create_reporter(&format).report(&results));
+        if format == Format::Text {
+            if hidden > 0 {
+                println!("{} problem(s) hidden by the baseline", hidden);
+            }
+            if let Some(e) = &self.config_error {
+                println!("{}", e);
+            }
+            println!("Watching for changes, press Ctrl+C to stop...");
+        }
+    }
+}
+
+/// Lint the paths, then lint the changed files again and print the results each time.
+pub fn watch(args: &Args, paths: &[String]) -> ExitCode {
+    let linter = match create_linter(args) {
+        Ok(linter) => linter,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::from(EXIT_CONFIG_ERROR);
+        }
+    };
+    let mut watcher = Watcher {
+        args,
+        paths,
+        linter,
+        sources: HashMap::new(),
+        configs: config_snapshot(args, paths),
+        results: BTreeMap::new(),
+        config_error: None,
+    };
+    watcher.lint_all();
+    watcher.print();
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let configs = config_snapshot(args, paths);
+        let changed = if configs != watcher.configs {
+            watcher.configs = configs;
+            watcher.reload();
+            true
+        } else {
+            watcher.lint_changes()
+        };
+        if changed {
+            watcher.print();
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+// A project in a fresh temporary folder, with its rules file and solidity files
+fn create_project(name: &str, rules: &str, files: &[(&str, &str)]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("solidhunter-cli-{}", name));
+    let _ = std::fs::remove_dir_all(&path);
+    std::fs::create_dir_all(&path).unwrap();
+    std::fs::write(
+        path.join(".solidhunter.json"),
+        format!("{{\"name\": \"solidhunter\", \"rules\": [{}]}}", rules),
+    )
+    .unwrap();
+    for (filename, content) in files {
+        std::fs::write(path.join(filename), content).unwrap();
+    }
+    path
+}
+
+fn run(project: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_solidhunter"))
+        .arg(project.to_str().unwrap())
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+const GLOBAL_IMPORT: &str =
+    "pragma solidity 0.8.19;\n\nimport \"./Token.sol\";\n\ncontract A is Token {}\n";
+const TOKEN: &str = "pragma solidity 0.8.19;\n\ncontract Token {}\n";
+
+#[test]
+fn fix_skips_excluded_files() {
+    let project = create_project(
+        "fix-excluded",
+        "{\"id\": \"no-global-import\", \"severity\": \"WARNING\"}",
+        &[
+            ("a.sol", GLOBAL_IMPORT),
+            ("excluded.sol", GLOBAL_IMPORT),
+            ("Token.sol", TOKEN),
+            (".solidhunterignore", "excluded.sol\n"),
+        ],
+    );
+
+    let output = run(&project, &["--fix-dry-run"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("Would fix 1 problem(s) in 1 file(s), 0 problem(s) remaining"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("excluded.sol"), "{}", stdout);
+}
+
+const REASON_STRING_WARNING: &str = "{\"id\": \"reason-string\", \"severity\": \"WARNING\"}";
+const REASON_STRING_ERROR: &str = "{\"id\": \"reason-string\", \"severity\": \"ERROR\"}";
+const MISSING_REASON: &str =
+    "pragma solidity 0.8.19;\n\ncontract A {\n    function f() public {\n        require(true);\n    }\n}\n";
+
+fn exit_code(output: &Output) -> i32 {
+    output.status.code().unwrap()
+}
+
+#[test]
+fn findings_above_fail_on_fail() {
+    let project = create_project(
+        "fail-on-error",
+        REASON_STRING_ERROR,
+        &[("a.sol", MISSING_REASON)],
+    );
+    assert_eq!(exit_code(&run(&project, &[])), 1);
+
+    let project = create_project(
+        "fail-on-warning",
+        REASON_STRING_WARNING,
+        &[("a.sol", MISSING_REASON)],
+    );
+    assert_eq!(exit_code(&run(&project, &[])), 0);
+    assert_eq!(exit_code(&run(&project, &["--fail-on", "warning"])), 1);
+}
+
+#[test]
+fn warnings_above_max_warnings_fail() {
+    let project = create_project(
+        "max-warnings",
+        REASON_STRING_WARNING,
+        &[("a.sol", MISSING_REASON), ("b.sol", MISSING_REASON)],
+    );
+    assert_eq!(exit_code(&run(&project, &["--max-warnings", "2"])), 0);
+    assert_eq!(exit_code(&run(&project, &["--max-warnings", "1"])), 1);
+}
+
+#[test]
+fn errors_have_distinct_exit_codes() {
+    let project = create_project(
+        "parse-error",
+        REASON_STRING_WARNING,
+        &[("a.sol", "contract A {")],
+    );
+    assert_eq!(exit_code(&run(&project, &[])), 3);
+
+    let project = create_project(
+        "config-error",
+        "{\"id\": \"unknown-rule\", \"severity\": \"WARNING\"}",
+        &[("a.sol", MISSING_REASON)],
+    );
+    assert_eq!(exit_code(&run(&project, &[])), 2);
+
+    // a nested configuration only fails the files it applies to
+    let project = create_project(
+        "nested-config-error",
+        REASON_STRING_WARNING,
+        &[("a.sol", MISSING_REASON)],
+    );
+    std::fs::create_dir(project.join("sub")).unwrap();
+    std::fs::write(project.join("sub").join("b.sol"), MISSING_REASON).unwrap();
+    std::fs::write(
+        project.join("sub").join(".solidhunter.json"),
+        "{\"name\": \"sub\", \"rules\": [{\"id\": \"unknown-rule\", \"severity\": \"WARNING\"}]}",
+    )
+    .unwrap();
+    assert_eq!(exit_code(&run(&project, &[])), 2);
+}
+
+#[cfg(unix)]
+#[test]
+fn unreadable_files_have_an_exit_code() {
+    let project = create_project(
+        "io-error",
+        REASON_STRING_WARNING,
+        &[("a.sol", MISSING_REASON)],
+    );
+    std::os::unix::fs::symlink(project.join("missing.sol"), project.join("b.sol")).unwrap();
+    assert_eq!(exit_code(&run(&project, &[])), 4);
+}
@@ -0,0 +1,180 @@
+use crate::cache::hash_content;
+use crate::errors::SolidHunterError;
+use crate::types::{FileDiags, LintDiag, LintResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+pub const DEFAULT_BASELINE_FILE: &str = ".solidhunter-baseline.json";
+
+type FindingKey = (String, String, String);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct BaselineEntry {
+    rule_id: String,
+    file: String,
+    /// Hash of the message of the finding and of the first line of code it applies to, with
+    /// its whitespace normalized.
+    fingerprint: String,
+    /// Number of identical findings, such as the same problem on two lines with the same code.
+    count: usize,
+}
+
+impl BaselineEntry {
+    fn key(&self) -> FindingKey {
+        (
+            self.rule_id.clone(),
+            self.file.clone(),
+            self.fingerprint.clone(),
+        )
+    }
+}
+
+/// Findings accepted when adopting the linter, which are not reported anymore.
+///
+/// The findings are identified by their rule, their file, their message and the line of code
+/// they start on rather than by their position, so that they still match when the code around them moves.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Baseline {
+    findings: Vec<BaselineEntry>,
+}
+
+fn normalize_file(filepath: &str) -> String {
+    filepath.trim_start_matches("./").to_string()
+}
+
+// The first line of the diagnostic with its whitespace collapsed, so that reindenting or
+// reformatting the code keeps the fingerprint, and changes to the rest of a long range, such
+// as a function body, do not
+fn normalized_line(diag: &LintDiag, content: &str) -> String {
+    content
+        .lines()
+        .nth(diag.range.start.line.saturating_sub(1))
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .unwrap_or_default()
+}
+
+pub fn fingerprint(diag: &LintDiag, content: &str) -> String {
+    hash_content(&format!(
+        "{}\n{}",
+        diag.message,
+        normalized_line(diag, content)
+    ))
+}
+
+fn finding_key(diag: &LintDiag, content: &str) -> FindingKey {
+    (
+        diag.id.clone(),
+        normalize_file(&diag.uri),
+        fingerprint(diag, content),
+    )
+}
+
+fn count_findings(results: &[LintResult]) -> HashMap<FindingKey, usize> {
+    let mut counts = HashMap::new();
+    for file in results.iter().flatten() {
+        for diag in &file.diags {
+            *counts
+                .entry(finding_key(diag, &file.source_file_content))
+                .or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+impl Baseline {
+    /// Record the findings of a run.
+    pub fn from_results(results: &[LintResult]) -> Self {
+        let mut baseline = Baseline {
+            findings: count_findings(results)
+                .into_iter()
+                .map(|((rule_id, file, fingerprint), count)| BaselineEntry {
+                    rule_id,
+                    file,
+                    fingerprint,
+                    count,
+                })
+                .collect(),
+        };
+        baseline.sort();
+        baseline
+    }
+
+    pub fn load(path: &str) -> Result<Self, SolidHunterError> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), SolidHunterError> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.findings.iter().map(|entry| entry.count).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    // Sorted entries keep the file stable, for it to be reviewed and committed
+    fn sort(&mut self) {
+        self.findings.sort_by(|a, b| {
+            (&a.file, &a.rule_id, &a.fingerprint).cmp(&(&b.file, &b.rule_id, &b.fingerprint))
+        });
+    }
+
+    /// Remove the diagnostics recorded in the baseline from the results, and return how many
+    /// were removed.
+    pub fn filter(&self, results: &mut [LintResult]) -> usize {
+        let mut remaining: HashMap<FindingKey, usize> = self
+            .findings
+            .iter()
+            .map(|entry| (entry.key(), entry.count))
+            .collect();
+        let mut filtered = 0;
+
+        for file in results.iter_mut().flatten() {
+            let FileDiags {
+                source_file_content,
+                diags,
+            } = file;
+            diags.retain(
+                |diag| match remaining.get_mut(&finding_key(diag, source_file_content)) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        filtered += 1;
+                        false
+                    }
+                    _ => true,
+                },
+            );
+        }
+        filtered
+    }
+
+    /// Remove the entries which are not found anymore in the linted files, or whose file was
+    /// deleted, and return how many findings were removed. The entries of the files which
+    /// were not linted are kept.
+    pub fn prune(&mut self, results: &[LintResult], linted_files: &[String]) -> usize {
+        let counts = count_findings(results);
+        let linted_files: HashSet<String> = linted_files
+            .iter()
+            .map(|filepath| normalize_file(filepath))
+            .collect();
+        let before = self.len();
+
+        self.findings
+            .retain_mut(|entry| match counts.get(&entry.key()) {
+                Some(count) => {
+                    entry.count = entry.count.min(*count);
+                    true
+                }
+                None => !linted_files.contains(&entry.file) && Path::new(&entry.file).exists(),
+            });
+        before - self.len()
+    }
+}
@@ -0,0 +1,151 @@
+use crate::errors::SolidHunterError;
+use crate::project::FileSummary;
+use crate::types::LintDiag;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+pub const DEFAULT_CACHE_FILE: &str = ".solidhunter-cache";
+
+const LINTER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    content_hash: String,
+    config_hash: String,
+    // the hash of the files the diagnostics depend on, see `ProjectGraph::dependencies`
+    #[serde(default)]
+    context_hash: String,
+    diags: Vec<LintDiag>,
+    // the imports and contracts of the file, for the project graph to know the cached files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    summary: Option<FileSummary>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct CacheData {
+    version: String,
+    files: HashMap<String, CacheEntry>,
+}
+
+/// Diagnostics of the files linted by a previous run.
+///
+/// The entries are only valid for the same linter version, and are looked up with the hash
+/// of the file content, of the rules configuration of the file and of the files it depends
+/// on, as the rules also look at the imported and inheriting contracts.
+#[derive(Debug, Clone)]
+pub struct LintCache {
+    path: String,
+    data: CacheData,
+    dirty: bool,
+}
+
+// 64-bit FNV-1a, stable across platforms and compiler versions unlike the std hasher
+pub fn hash_content(content: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in content.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+impl LintCache {
+    /// Load the cache file at `path`. A missing, unreadable or outdated cache starts empty.
+    pub fn load(path: &str) -> Self {
+        let data = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheData>(&content).ok())
+            .filter(|data| data.version == LINTER_VERSION);
+
+        LintCache {
+            path: path.to_string(),
+            dirty: data.is_none(),
+            data: data.unwrap_or_else(|| CacheData {
+                version: LINTER_VERSION.to_string(),
+                files: HashMap::new(),
+            }),
+        }
+    }
+
+    fn entry(&self, filepath: &str, content: &str, config_hash: &str) -> Option<&CacheEntry> {
+        self.data.files.get(filepath).filter(|entry| {
+            entry.config_hash == config_hash && entry.content_hash == hash_content(content)
+        })
+    }
+
+    pub fn get(
+        &self,
+        filepath: &str,
+        content: &str,
+        config_hash: &str,
+        context_hash: &str,
+    ) -> Option<Vec<LintDiag>> {
+        self.entry(filepath, content, config_hash)
+            .filter(|entry| entry.context_hash == context_hash)
+            .map(|entry| entry.diags.clone())
+    }
+
+    /// The summary of the file stored along its diagnostics, if the file did not change. It
+    /// is needed to know the files it depends on, before checking its diagnostics.
+    pub fn get_summary(
+        &self,
+        filepath: &str,
+        content: &str,
+        config_hash: &str,
+    ) -> Option<FileSummary> {
+        self.entry(filepath, content, config_hash)
+            .and_then(|entry| entry.summary.clone())
+    }
+
+    pub fn insert(
+        &mut self,
+        filepath: &str,
+        content: &str,
+        config_hash: &str,
+        context_hash: &str,
+        diags: &[LintDiag],
+    ) {
+        self.insert_with_summary(filepath, content, config_hash, context_hash, diags, None);
+    }
+
+    pub fn insert_with_summary(
+        &mut self,
+        filepath: &str,
+        content: &str,
+        config_hash: &str,
+        context_hash: &str,
+        diags: &[LintDiag],
+        summary: Option<FileSummary>,
+    ) {
+        self.data.files.insert(
+            filepath.to_string(),
+            CacheEntry {
+                content_hash: hash_content(content),
+                config_hash: config_hash.to_string(),
+                context_hash: context_hash.to_string(),
+                diags: diags.to_vec(),
+                summary,
+            },
+        );
+        self.dirty = true;
+    }
+
+    pub fn remove(&mut self, filepath: &str) {
+        if self.data.files.remove(filepath).is_some() {
+            self.dirty = true;
+        }
+    }
+
+    /// Write the cache file if it changed since it was loaded.
+    pub fn save(&mut self) -> Result<(), SolidHunterError> {
+        if !self.dirty {
+            return Ok(());
+        }
+        fs::write(&self.path, serde_json::to_string(&self.data)?)?;
+        self.dirty = false;
+        Ok(())
+    }
+}
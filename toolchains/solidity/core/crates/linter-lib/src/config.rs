@@ -0,0 +1,164 @@
+use crate::errors::SolidHunterError;
+use crate::rules::rule_impl::{apply_rules, merge_rules, parse_rules, remove_rules};
+use crate::rules::types::*;
+use glob::Pattern;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+struct ConfigLevel {
+    dir: Option<PathBuf>,
+    config: Rules,
+}
+
+/// The root config and the configs of the directories below it, merged down the tree
+/// to get the rules of each file.
+pub(crate) struct ConfigTree {
+    root: ConfigLevel,
+    filename: String,
+    directories: HashMap<PathBuf, Option<Rules>>,
+}
+
+fn override_matches(rules_override: &RulesOverride, dir: Option<&Path>, filepath: &Path) -> bool {
+    let relative = dir
+        .and_then(|dir| filepath.strip_prefix(dir).ok())
+        .unwrap_or(filepath);
+
+    rules_override.files.iter().any(|pattern| {
+        let pattern = match Pattern::new(pattern.trim_start_matches("./")) {
+            Ok(pattern) => pattern,
+            Err(_) => return false,
+        };
+        if pattern.as_str().contains('/') {
+            pattern.matches_path(relative)
+        } else {
+            filepath
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| pattern.matches(name))
+        }
+    })
+}
+
+fn apply_level(entries: &mut Vec<RuleEntry>, config: &Rules, dir: Option<&Path>, filepath: &Path) {
+    apply_rules(entries, config);
+    for rules_override in &config.overrides {
+        if override_matches(rules_override, dir, filepath) {
+            merge_rules(entries, &rules_override.rules);
+            remove_rules(entries, &rules_override.disabled);
+        }
+    }
+}
+
+impl ConfigTree {
+    /// `path` is the path of the config file, if it was read from the disk.
+    pub fn new(config: Rules, path: Option<&Path>) -> Self {
+        let dir = path
+            .and_then(|path| path.parent())
+            .map(|dir| {
+                if dir.as_os_str().is_empty() {
+                    Path::new(".")
+                } else {
+                    dir
+                }
+            })
+            .and_then(|dir| fs::canonicalize(dir).ok());
+        let filename = path
+            .and_then(|path| path.file_name())
+            .and_then(|name| name.to_str())
+            .unwrap_or(".solidhunter.json")
+            .to_string();
+
+        ConfigTree {
+            root: ConfigLevel { dir, config },
+            filename,
+            directories: HashMap::new(),
+        }
+    }
+
+    /// The rules of the root config, without its overrides.
+    pub fn root_rules(&self) -> Vec<RuleEntry> {
+        let mut entries = Vec::new();
+        apply_rules(&mut entries, &self.root.config);
+        entries
+    }
+
+    fn directory_config(&mut self, dir: &Path) -> Result<Option<&Rules>, SolidHunterError> {
+        if !self.directories.contains_key(dir) {
+            let path = dir.join(&self.filename);
+            let config = if path.is_file() {
+                Some(parse_rules(&path.to_string_lossy())?)
+            } else {
+                None
+            };
+            self.directories.insert(dir.to_path_buf(), config);
+        }
+        Ok(self.directories[dir].as_ref())
+    }
+
+    // Directories between the root config and the file, from the top
+    fn nested_directories(&self, filepath: &Path) -> Vec<PathBuf> {
+        let root = match &self.root.dir {
+            Some(root) => root,
+            None => return Vec::new(),
+        };
+        let mut dirs: Vec<PathBuf> = filepath
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| *dir != root.as_path() && dir.starts_with(root))
+            .map(Path::to_path_buf)
+            .collect();
+        dirs.reverse();
+        dirs
+    }
+
+    /// The rules of a file: the ones of the root config, then of every config found in the
+    /// directories down to the file, each followed by its overrides matching the file.
+    pub fn file_rules(&mut self, filepath: &str) -> Result<Vec<RuleEntry>, SolidHunterError> {
+        let filepath = fs::canonicalize(filepath).unwrap_or_else(|_| PathBuf::from(filepath));
+        let mut entries = Vec::new();
+        apply_level(
+            &mut entries,
+            &self.root.config,
+            self.root.dir.as_deref(),
+            &filepath,
+        );
+
+        for dir in self.nested_directories(&filepath) {
+            if let Some(config) = self.directory_config(&dir)? {
+                apply_level(&mut entries, config, Some(&dir), &filepath);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Where to disable a rule for a file, none without config file.
+    pub fn rule_location(
+        &mut self,
+        filepath: &str,
+        id: &str,
+    ) -> Result<Option<RuleLocation>, SolidHunterError> {
+        let filepath = fs::canonicalize(filepath).unwrap_or_else(|_| PathBuf::from(filepath));
+        let enabling_override = |config: &Rules, dir: &Path| {
+            config.overrides.iter().rposition(|rules_override| {
+                override_matches(rules_override, Some(dir), &filepath)
+                    && rules_override.rules.iter().any(|rule| rule.id == id)
+            })
+        };
+        let mut location = self.root.dir.as_ref().map(|dir| RuleLocation {
+            config_path: dir.join(&self.filename),
+            override_index: enabling_override(&self.root.config, dir),
+        });
+
+        for dir in self.nested_directories(&filepath) {
+            let config_path = dir.join(&self.filename);
+            if let Some(config) = self.directory_config(&dir)? {
+                location = Some(RuleLocation {
+                    config_path,
+                    override_index: enabling_override(config, &dir),
+                });
+            }
+        }
+        Ok(location)
+    }
+}
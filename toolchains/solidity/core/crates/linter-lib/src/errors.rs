@@ -1,5 +1,33 @@
+use std::fmt;
 use thiserror::Error;
 
+/// A problem found while validating a configuration file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    /// JSON path of the faulty value, such as `$.rules[2].data`.
+    pub path: String,
+    pub message: String,
+    /// The closest valid value, for typos.
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (did you mean \"{}\"?)", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+fn format_config_errors(errors: &[ConfigError]) -> String {
+    errors
+        .iter()
+        .map(|error| format!("\n  {}", error))
+        .collect()
+}
+
 #[derive(Error, Debug)]
 pub enum SolidHunterError {
     // Linter errors
@@ -9,10 +37,44 @@ pub enum SolidHunterError {
     ParsingError(#[from] std::io::Error),
     #[error("SolidHunterError: Serde error occured")]
     SerdeError(#[from] serde_json::Error),
-    #[error("SolidHunterError: Something went wrong")]
+    /// An error whose message is already formatted, such as an error reported again.
+    #[error("{0}")]
     LinterError(String),
+    /// An error of the file at the path.
+    #[error("{0}: {1}")]
+    FileError(String, Box<SolidHunterError>),
 
     // RulesError
     #[error("SolidHunterError: IO error occured with Rules")]
     IoError(std::io::Error),
+    #[error("SolidHunterError: Invalid configuration:{}", format_config_errors(.0))]
+    ConfigError(Vec<ConfigError>),
+    #[error("SolidHunterError: Invalid foundry.toml: {0}")]
+    FoundryConfigError(#[from] toml::de::Error),
+}
+
+impl SolidHunterError {
+    /// The error, in the file at `path` unless its file is already known.
+    pub fn in_file(self, path: &str) -> Self {
+        match self {
+            SolidHunterError::FileError(..) => self,
+            error => SolidHunterError::FileError(path.to_string(), Box::new(error)),
+        }
+    }
+
+    /// The path of the file the error happened in, if it is known.
+    pub fn filepath(&self) -> Option<&str> {
+        match self {
+            SolidHunterError::FileError(path, _) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// The error without the file it happened in.
+    pub fn cause(&self) -> &SolidHunterError {
+        match self {
+            SolidHunterError::FileError(_, error) => error.cause(),
+            error => error,
+        }
+    }
 }
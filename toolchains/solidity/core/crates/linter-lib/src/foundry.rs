@@ -0,0 +1,180 @@
+use crate::errors::SolidHunterError;
+use crate::project::{absolute_path, normalize_path, Remapping};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const FOUNDRY_CONFIG_FILE: &str = "foundry.toml";
+pub const REMAPPINGS_FILE: &str = "remappings.txt";
+
+const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Deserialize, Debug, Clone, Default)]
+struct FoundryProfile {
+    src: Option<String>,
+    test: Option<String>,
+    script: Option<String>,
+    libs: Option<Vec<String>>,
+    remappings: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+struct FoundryToml {
+    #[serde(default)]
+    profile: HashMap<String, FoundryProfile>,
+}
+
+/// The layout and the remappings of a Foundry project.
+#[derive(Debug, Clone)]
+pub struct FoundryProject {
+    pub root: PathBuf,
+    pub src: String,
+    pub test: String,
+    pub script: String,
+    pub libs: Vec<String>,
+    /// The remappings of `foundry.toml`, then of `remappings.txt`, then the ones detected
+    /// from the libraries, with their paths absolute like the paths of the project graph.
+    pub remappings: Vec<Remapping>,
+    // canonical paths of the source directories, to match the linted files against
+    source_dirs: Vec<PathBuf>,
+}
+
+fn join_root(root: &Path, path: &str) -> String {
+    let joined = normalize_path(&root.join(path).to_string_lossy());
+    // keep the trailing slash of the directory remappings
+    if path.ends_with('/') && !joined.ends_with('/') {
+        joined + "/"
+    } else {
+        joined
+    }
+}
+
+// The dependencies installed with `forge install`, remapped as `name/=lib/name/src/`
+fn detect_lib_remappings(root: &Path, libs: &[String]) -> Vec<String> {
+    let mut remappings = Vec::new();
+    for lib in libs {
+        let entries = match fs::read_dir(root.join(lib)) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        let mut dirs: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect();
+        dirs.sort();
+        for dir in dirs {
+            let name = match dir.file_name().and_then(|name| name.to_str()) {
+                Some(name) => name.to_string(),
+                None => continue,
+            };
+            let target = if dir.join("src").is_dir() {
+                format!("{}/{}/src/", lib, name)
+            } else {
+                format!("{}/{}/", lib, name)
+            };
+            remappings.push(format!("{}/={}", name, target));
+        }
+    }
+    remappings
+}
+
+impl FoundryProject {
+    /// Find the Foundry project of a path, in the path itself or in one of its parents.
+    pub fn find(path: &Path) -> Result<Option<Self>, SolidHunterError> {
+        let dir = if path.is_file() {
+            path.parent().unwrap_or(Path::new("."))
+        } else {
+            path
+        };
+        // the paths are kept absolute but not canonical, to be the keys of the project graph
+        let dir = PathBuf::from(absolute_path(&dir.to_string_lossy()));
+        match dir
+            .ancestors()
+            .find(|dir| dir.join(FOUNDRY_CONFIG_FILE).is_file())
+        {
+            Some(root) => Self::load(root).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Load the project whose `foundry.toml` is in `root`, with the profile selected by the
+    /// `FOUNDRY_PROFILE` environment variable on top of the default one.
+    pub fn load(root: &Path) -> Result<Self, SolidHunterError> {
+        let content = fs::read_to_string(root.join(FOUNDRY_CONFIG_FILE))?;
+        let config: FoundryToml = toml::from_str(&content)?;
+        let default = config
+            .profile
+            .get(DEFAULT_PROFILE)
+            .cloned()
+            .unwrap_or_default();
+        let profile = std::env::var("FOUNDRY_PROFILE")
+            .ok()
+            .and_then(|name| config.profile.get(&name).cloned())
+            .unwrap_or_default();
+
+        let src = profile.src.or(default.src).unwrap_or("src".to_string());
+        let test = profile.test.or(default.test).unwrap_or("test".to_string());
+        let script = profile
+            .script
+            .or(default.script)
+            .unwrap_or("script".to_string());
+        let libs = profile
+            .libs
+            .or(default.libs)
+            .unwrap_or(vec!["lib".to_string()]);
+
+        let mut remappings_lines = profile
+            .remappings
+            .or(default.remappings)
+            .unwrap_or_default();
+        if let Ok(content) = fs::read_to_string(root.join(REMAPPINGS_FILE)) {
+            remappings_lines.extend(content.lines().map(str::to_string));
+        }
+        remappings_lines.extend(detect_lib_remappings(root, &libs));
+
+        // the first remapping of a prefix wins, like with forge
+        let mut remappings: Vec<Remapping> = Vec::new();
+        for line in remappings_lines {
+            let remapping = match Remapping::parse(&line) {
+                Some(remapping) => Remapping {
+                    context: remapping.context.map(|context| join_root(root, &context)),
+                    prefix: remapping.prefix,
+                    target: join_root(root, &remapping.target),
+                },
+                None => continue,
+            };
+            let known = remappings.iter().any(|known| {
+                known.prefix == remapping.prefix && known.context == remapping.context
+            });
+            if !known {
+                remappings.push(remapping);
+            }
+        }
+
+        let source_dirs = [&src, &test, &script]
+            .iter()
+            .filter_map(|dir| fs::canonicalize(root.join(dir)).ok())
+            .collect();
+
+        Ok(FoundryProject {
+            root: root.to_path_buf(),
+            src,
+            test,
+            script,
+            libs,
+            remappings,
+            source_dirs,
+        })
+    }
+
+    /// Whether a file is in the `src`, `test` or `script` directory of the project, rather
+    /// than in a dependency.
+    pub fn is_project_source(&self, filepath: &str) -> bool {
+        match fs::canonicalize(filepath) {
+            Ok(filepath) => self.source_dirs.iter().any(|dir| filepath.starts_with(dir)),
+            Err(_) => false,
+        }
+    }
+}
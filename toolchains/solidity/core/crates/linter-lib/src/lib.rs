@@ -1,5 +1,11 @@
+pub mod baseline;
+pub mod cache;
+mod config;
 pub mod errors;
+pub mod foundry;
 mod ignore;
 pub mod linter;
+pub mod project;
+pub mod reporters;
 pub mod rules;
 pub mod types;
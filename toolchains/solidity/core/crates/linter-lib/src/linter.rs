@@ -1,4 +1,8 @@
+use crate::cache::{hash_content, LintCache};
+use crate::config::ConfigTree;
 use crate::errors::SolidHunterError;
+use crate::foundry::FoundryProject;
+use crate::project::{absolute_path, FileSummary, ProjectGraph, Remapping};
 use crate::rules::create_default_rules;
 use crate::rules::factory::RuleFactory;
 use crate::rules::rule_impl::parse_rules;
@@ -9,7 +13,15 @@ use std::fs;
 
 use crate::ignore::get_excluded_files;
 use glob::glob;
+use std::collections::HashMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Barrier, Mutex, OnceLock};
+use std::thread;
+
+// Maximum number of times a file is linted and fixed again to apply overlapping fixes
+const MAX_FIX_PASSES: usize = 10;
 
 #[derive(Debug, Clone)]
 pub struct SolidFile {
@@ -20,9 +32,19 @@ pub struct SolidFile {
 
 pub struct SolidLinter {
     files: Vec<SolidFile>,
+    files_index: HashMap<String, usize>,
+    // the imports and contracts of every linted file, including the ones not kept in the store
+    project: ProjectGraph,
     rule_factory: RuleFactory,
-    rules: Vec<Box<dyn RuleType>>,
+    config: ConfigTree,
+    // the rules are created once for each distinct configuration, keyed by its hash
+    rule_sets: HashMap<String, Vec<Box<dyn RuleType>>>,
+    config_hash: String,
     excluded_files: Vec<String>,
+    cache: Option<LintCache>,
+    foundry: Option<FoundryProject>,
+    // lint the dependencies of a Foundry project when walking a folder
+    include_dependencies: bool,
 }
 
 impl Default for SolidLinter {
@@ -33,41 +55,127 @@ impl Default for SolidLinter {
 
 impl SolidLinter {
     pub fn new() -> Self {
-        SolidLinter {
+        let mut linter = SolidLinter {
             files: Vec::new(),
+            files_index: HashMap::new(),
+            project: ProjectGraph::default(),
             rule_factory: RuleFactory::default(),
-            rules: vec![],
+            config: ConfigTree::new(Rules::default(), None),
+            rule_sets: HashMap::new(),
+            config_hash: String::new(),
             excluded_files: Vec::new(),
-        }
+            cache: None,
+            foundry: None,
+            include_dependencies: false,
+        };
+
+        // the default configurations only have known rules
+        let _ = linter._set_config(Rules::default(), None);
+        linter
     }
 
     pub fn new_fileless() -> Self {
-        let default_rules = create_default_rules();
-        let mut linter = SolidLinter {
-            files: Vec::new(),
-            rule_factory: RuleFactory::default(),
-            rules: Vec::new(),
-            excluded_files: Vec::new(),
+        let mut linter = SolidLinter::new();
+        let config = Rules {
+            rules: create_default_rules(),
+            ..Default::default()
         };
 
-        for rule in default_rules {
-            linter.rules.push(linter.rule_factory.create_rule(rule));
-        }
-
+        let _ = linter._set_config(config, None);
         linter
     }
 
     pub fn initialize_rules(&mut self, rules_config: &str) -> Result<(), SolidHunterError> {
         let res = parse_rules(rules_config)?;
-        for rule in res.rules {
-            self.rules.push(self.rule_factory.create_rule(rule));
+        self._set_config(res, Some(Path::new(rules_config)))
+    }
+
+    fn _set_config(&mut self, config: Rules, path: Option<&Path>) -> Result<(), SolidHunterError> {
+        self.config = ConfigTree::new(config, path);
+        self.rule_sets.clear();
+        self.config_hash = self._load_rules(self.config.root_rules())?;
+        Ok(())
+    }
+
+    // Create the rules of a configuration if needed and return its hash
+    fn _load_rules(&mut self, entries: Vec<RuleEntry>) -> Result<String, SolidHunterError> {
+        // the configuration hash covers every rule entry, to invalidate the cache when one changes
+        let config_hash = hash_content(&serde_json::to_string(&entries).unwrap_or_default());
+        if !self.rule_sets.contains_key(&config_hash) {
+            let rules = entries
+                .into_iter()
+                .map(|rule| self.rule_factory.create_rule(rule))
+                .collect::<Result<_, _>>()?;
+            self.rule_sets.insert(config_hash.clone(), rules);
+        }
+        Ok(config_hash)
+    }
+
+    fn _load_file_rules(&mut self, filepath: &str) -> Result<String, SolidHunterError> {
+        let entries = self.config.file_rules(filepath)?;
+        self._load_rules(entries)
+    }
+
+    /// Load the cache of a previous run, to skip the unchanged files in `parse_path`.
+    pub fn initialize_cache(&mut self, cache_file: &str) {
+        self.cache = Some(LintCache::load(cache_file));
+    }
+
+    pub fn save_cache(&mut self) -> Result<(), SolidHunterError> {
+        match &mut self.cache {
+            Some(cache) => cache.save(),
+            None => Ok(()),
+        }
+    }
+
+    /// Read the `foundry.toml` of the project of `path`, if any, to resolve the imports with
+    /// its remappings from its root and only lint its sources when walking a folder. Without
+    /// Foundry project, the imports are resolved from the directory of `path`.
+    pub fn initialize_foundry(&mut self, path: &str) -> Result<(), SolidHunterError> {
+        self.foundry = FoundryProject::find(Path::new(path))?;
+        match &self.foundry {
+            Some(foundry) => {
+                self.project.set_root(&foundry.root.to_string_lossy());
+                self.project.set_remappings(foundry.remappings.clone());
+            }
+            None if Path::new(path).is_file() => {
+                self.project.set_root(
+                    &Path::new(path)
+                        .parent()
+                        .unwrap_or(Path::new("."))
+                        .to_string_lossy(),
+                );
+            }
+            None => self.project.set_root(path),
         }
         Ok(())
     }
 
+    pub fn set_include_dependencies(&mut self, include_dependencies: bool) {
+        self.include_dependencies = include_dependencies;
+    }
+
+    /// Remappings used to resolve the imports of the files.
+    pub fn set_remappings(&mut self, remappings: Vec<Remapping>) {
+        self.project.set_remappings(remappings);
+    }
+
+    pub fn project(&self) -> &ProjectGraph {
+        &self.project
+    }
+
+    /// The config file where to disable a rule for a file, and the override to disable it in.
+    pub fn rule_location(
+        &mut self,
+        filepath: &str,
+        id: &str,
+    ) -> Result<Option<RuleLocation>, SolidHunterError> {
+        self.config.rule_location(filepath, id)
+    }
+
     pub fn get_documentation(&self) -> Vec<RuleDocumentation> {
         let mut res = Vec::new();
-        for rule in &self.rules {
+        for rule in &self.rule_sets[&self.config_hash] {
             res.push(rule.get_documentation())
         }
         res
@@ -88,21 +196,16 @@ impl SolidLinter {
         Ok(())
     }
 
-    pub fn initialize_rules_content(&mut self, rules_config: &str) -> Result<(), SolidHunterError> {
-        let res = parse_rules_content(rules_config)?;
-        for rule in res.rules {
-            self.rules.push(self.rule_factory.create_rule(rule));
-        }
-        Ok(())
+    /// Whether the file is excluded from the lint, by the command line or a `.solidhunterignore`.
+    pub fn is_excluded(&self, filepath: &str) -> bool {
+        self.excluded_files
+            .iter()
+            .any(|excluded| excluded == filepath)
     }
 
-    fn _file_exists(&self, path: &str) -> bool {
-        for file in &self.files {
-            if file.path == path {
-                return true;
-            }
-        }
-        false
+    pub fn initialize_rules_content(&mut self, rules_config: &str) -> Result<(), SolidHunterError> {
+        let res = parse_rules_content(rules_config)?;
+        self._set_config(res, None)
     }
 
     fn _add_file(
@@ -110,141 +213,200 @@ impl SolidLinter {
         path: &str,
         ast: osmium_libs_solidity_ast_extractor::File,
         content: &str,
-    ) {
-        if self._file_exists(path) {
-            for file in &mut self.files {
-                if file.path == path {
-                    file.data = ast.clone();
-                    file.content = String::from(content);
-                }
+    ) -> usize {
+        let file = SolidFile {
+            data: ast,
+            path: String::from(path),
+            content: String::from(content),
+        };
+        self.project.add_file(FileSummary::new(path, &file.data));
+        match self.files_index.get(path) {
+            Some(&idx) => {
+                self.files[idx] = file;
+                idx
+            }
+            None => {
+                self.files.push(file);
+                self.files_index
+                    .insert(String::from(path), self.files.len() - 1);
+                self.files.len() - 1
             }
-        } else {
-            let file = SolidFile {
-                data: ast,
-                path: String::from(path),
-                content: String::from(content),
-            };
-            self.files.push(file);
         }
     }
 
     pub fn parse_file(&mut self, filepath: String) -> LintResult {
+        self._parse_file(&filepath)
+            .map_err(|error| error.in_file(&filepath))
+    }
+
+    fn _parse_file(&mut self, filepath: &str) -> LintResult {
+        let filepath = filepath.to_string();
         let content = fs::read_to_string(filepath.clone())?;
         if self.excluded_files.contains(&filepath) {
             return Ok(FileDiags::new(content, Vec::new()));
         }
-        self.parse_content(&filepath, content.as_str())
+        let config_hash = self._load_file_rules(&filepath)?;
+        let summary = self
+            .cache
+            .as_ref()
+            .and_then(|c| c.get_summary(&filepath, &content, &config_hash));
+        if let Some(summary) = summary {
+            self.project.add_file(summary);
+            let context_hash = context_hash(&self.project, &filepath, &HashMap::new());
+            let cached = self
+                .cache
+                .as_ref()
+                .and_then(|c| c.get(&filepath, &content, &config_hash, &context_hash));
+            if let Some(diags) = cached {
+                return Ok(FileDiags::new(content, diags));
+            }
+        }
+        let res = self.parse_content(&filepath, content.as_str());
+        if let (Some(cache), Ok(file_diags)) = (&mut self.cache, &res) {
+            let summary = self.project.file(&filepath).cloned();
+            let context_hash = context_hash(&self.project, &filepath, &HashMap::new());
+            cache.insert_with_summary(
+                &filepath,
+                &content,
+                &config_hash,
+                &context_hash,
+                &file_diags.diags,
+                summary,
+            );
+        }
+        res
     }
 
-    fn _check_is_in_disable_range(&self, diag: &LintDiag, disable_ranges: &[DisableRange]) -> bool {
-        let mut rules_occurrences = vec![];
+    pub fn parse_content(&mut self, filepath: &str, content: &str) -> LintResult {
+        let res = osmium_libs_solidity_ast_extractor::extract::extract_ast_from_content(content)?;
 
-        let filtered_range = disable_ranges
-            .iter()
-            // we only care about ranges that start before the diag
-            .filter(|range| range.start_line <= diag.range.start.line)
-            .map(|range| {
-                if range.rule_ids.is_empty() {
-                    DisableRange {
-                        rule_ids: vec!["".to_string()], // empty rule means all rules
-                        ..range.clone()
-                    }
-                } else {
-                    range.clone()
-                }
-            })
-            .collect::<Vec<DisableRange>>();
-
-        for range in &filtered_range {
-            match range.ignore_type {
-                Ignore::SameLine | Ignore::NextLine => {
-                    if range.start_line == diag.range.start.line
-                        && (range.rule_ids.contains(&diag.id)
-                            || range.rule_ids.contains(&"".to_string()))
-                    {
-                        return true;
-                    }
-                }
-                Ignore::Disable => {
-                    for rule in &range.rule_ids {
-                        let mut found = false;
-                        for (rule_id, occurences) in &mut rules_occurrences {
-                            if *rule_id == rule {
-                                *occurences += 1;
-                                found = true;
-                                break;
-                            }
-                        }
-                        if !found {
-                            rules_occurrences.push((rule, 1));
-                        }
-                    }
-                }
-                Ignore::Enable => {
-                    for rule in &range.rule_ids {
-                        for (rule_id, occurences) in &mut rules_occurrences {
-                            if *rule_id == rule {
-                                *occurences -= 1;
-                                break;
-                            }
-                        }
-                        // TODO: global disable followed by a scoped enable might not work
-                    }
-                }
-            }
-        }
+        let config_hash = self._load_file_rules(filepath)?;
+        let idx = self._add_file(filepath, res, content);
+        let context = LintContext {
+            project: &self.project,
+        };
+        let res = diagnose_file(&self.rule_sets[&config_hash], &self.files[idx], &context);
+        Ok(FileDiags::new(content.to_string(), res))
+    }
 
-        let disabled_rules = rules_occurrences
-            .iter()
-            .filter(|(_, occurences)| *occurences > 0)
-            .map(|(rule, _)| rule.to_string())
-            .collect::<Vec<String>>();
+    pub fn fix_file(&mut self, filepath: String) -> Result<FixResult, SolidHunterError> {
+        self._fix_file(&filepath)
+            .map_err(|error| error.in_file(&filepath))
+    }
 
-        for rule in disabled_rules {
-            if rule.is_empty() || rule == diag.id {
-                return true;
-            }
+    fn _fix_file(&mut self, filepath: &str) -> Result<FixResult, SolidHunterError> {
+        let content = fs::read_to_string(filepath)?;
+        if self.is_excluded(filepath) {
+            return Ok(apply_fixes(&content, &[]));
         }
-        false
+        self.fix_content(filepath, content.as_str())
     }
 
-    fn _check_is_diag_ignored(&self, diag: &LintDiag, file: &SolidFile) -> bool {
-        let ignore_comments: Vec<IgnoreComment> = file
-            .content
-            .lines()
-            .enumerate()
-            .filter_map(|(line_number, line)| IgnoreComment::from_line(line_number + 1, line))
-            .collect();
-        let disable_ranges = build_disable_ranges(ignore_comments);
+    pub fn fix_content(
+        &mut self,
+        filepath: &str,
+        content: &str,
+    ) -> Result<FixResult, SolidHunterError> {
+        let diags = self.parse_content(filepath, content)?;
+        let mut result = self.apply_fixes(content, &diags.diags);
 
-        self._check_is_in_disable_range(diag, &disable_ranges)
+        // overlapping fixes are skipped, lint the fixed content again to apply them
+        let mut passes = 1;
+        while passes < MAX_FIX_PASSES && !result.applied.is_empty() && !result.skipped.is_empty() {
+            let diags = match self.parse_content(filepath, &result.content) {
+                Ok(diags) => diags,
+                Err(_) => break,
+            };
+            let mut next = self.apply_fixes(&result.content, &diags.diags);
+            if next.applied.is_empty() {
+                break;
+            }
+            result.applied.append(&mut next.applied);
+            result.content = next.content;
+            result.skipped = next.skipped;
+            passes += 1;
+        }
+        Ok(result)
     }
 
-    pub fn parse_content(&mut self, filepath: &str, content: &str) -> LintResult {
-        let res = osmium_libs_solidity_ast_extractor::extract::extract_ast_from_content(content)?;
-
-        self._add_file(filepath, res, content);
-        let mut res: Vec<_> = vec![];
+    // Apply the fixes of the diagnostics to the content, skipping the ones overlapping a previous fix
+    pub fn apply_fixes(&self, content: &str, diags: &[LintDiag]) -> FixResult {
+        apply_fixes(content, diags)
+    }
 
-        for rule in &self.rules {
-            let mut diags = rule.diagnose(&self.files[self.files.len() - 1], &self.files);
-            for diag in &mut diags {
-                if !self._check_is_diag_ignored(diag, &self.files[self.files.len() - 1]) {
-                    res.push(diag.clone());
+    // List the solidity files of a path, which can be a file or a folder. The files of a
+    // folder are only the sources of the Foundry project, if any, not its dependencies
+    pub fn get_path_files(&self, path: &str) -> Vec<String> {
+        if Path::new(&path).is_file() {
+            return vec![path.to_string()];
+        }
+        let foundry = self.foundry.as_ref().filter(|_| !self.include_dependencies);
+        let mut files = Vec::new();
+        if let Ok(entries) = glob(&(path.to_owned() + "/**/*.sol")) {
+            for entry in entries.flatten() {
+                let filepath = entry.into_os_string().into_string().unwrap();
+                if foundry.is_none_or(|foundry| foundry.is_project_source(&filepath)) {
+                    files.push(filepath);
                 }
             }
         }
-        Ok(FileDiags::new(content.to_string(), res))
+        files
     }
 
+    // The files are linted in parallel, then kept in the project graph for the next lints
     pub fn parse_folder(&mut self, folder: &str) -> Vec<LintResult> {
-        let mut result: Vec<LintResult> = Vec::new();
-        if let Ok(entries) = glob(&(folder.to_owned() + "/**/*.sol")) {
-            for entry in entries.flatten() {
-                result.push(self.parse_file(entry.into_os_string().into_string().unwrap()));
-            }
-        }
-        result
+        let filepaths = self.get_path_files(folder);
+        let config_hashes: Vec<Option<String>> = filepaths
+            .iter()
+            .map(|filepath| self._load_file_rules(filepath).ok())
+            .collect();
+        let jobs: Vec<Option<FileJob>> = filepaths
+            .iter()
+            .zip(&config_hashes)
+            .map(|(filepath, config_hash)| {
+                config_hash.as_ref().map(|config_hash| FileJob {
+                    filepath,
+                    config_hash,
+                    rules: &self.rule_sets[config_hash],
+                })
+            })
+            .collect();
+        let (results, project) = lint_files_in_parallel(
+            &jobs,
+            &self.excluded_files,
+            self.cache.as_ref(),
+            &self.project,
+        );
+        self.project = project;
+
+        filepaths
+            .into_iter()
+            .zip(config_hashes)
+            .zip(results)
+            .map(
+                |((filepath, config_hash), result)| match (result, config_hash) {
+                    (WorkerResult::Linted(diags, summary, context_hash), Some(config_hash)) => {
+                        if let Some(cache) = &mut self.cache {
+                            let content = &diags.source_file_content;
+                            let diags = &diags.diags;
+                            cache.insert_with_summary(
+                                &filepath,
+                                content,
+                                &config_hash,
+                                &context_hash,
+                                diags,
+                                Some(summary),
+                            );
+                        }
+                        Ok(diags)
+                    }
+                    (WorkerResult::Linted(diags, ..), None) | (WorkerResult::Skipped(diags), _) => {
+                        Ok(diags)
+                    }
+                    (WorkerResult::Failed, _) => self.parse_file(filepath),
+                },
+            )
+            .collect()
     }
     pub fn parse_path(&mut self, path: &str) -> Vec<LintResult> {
         if Path::new(&path).is_file() {
@@ -255,16 +417,311 @@ impl SolidLinter {
     }
 
     pub fn delete_file(&mut self, path: &str) {
-        loop {
-            let idx = self.files.iter().position(|x| x.path == path);
-            match idx {
-                Some(idx) => {
-                    self.files.remove(idx);
+        self.project.remove_file(path);
+        if let Some(idx) = self.files_index.remove(path) {
+            self.files.swap_remove(idx);
+            if let Some(moved) = self.files.get(idx) {
+                self.files_index.insert(moved.path.clone(), idx);
+            }
+        }
+    }
+}
+
+fn check_is_in_disable_range(diag: &LintDiag, disable_ranges: &[DisableRange]) -> bool {
+    let mut rules_occurrences = vec![];
+
+    let filtered_range = disable_ranges
+        .iter()
+        // we only care about ranges that start before the diag
+        .filter(|range| range.start_line <= diag.range.start.line)
+        .map(|range| {
+            if range.rule_ids.is_empty() {
+                DisableRange {
+                    rule_ids: vec!["".to_string()], // empty rule means all rules
+                    ..range.clone()
+                }
+            } else {
+                range.clone()
+            }
+        })
+        .collect::<Vec<DisableRange>>();
+
+    for range in &filtered_range {
+        match range.ignore_type {
+            Ignore::SameLine | Ignore::NextLine => {
+                if range.start_line == diag.range.start.line
+                    && (range.rule_ids.contains(&diag.id)
+                        || range.rule_ids.contains(&"".to_string()))
+                {
+                    return true;
+                }
+            }
+            Ignore::Disable => {
+                for rule in &range.rule_ids {
+                    let mut found = false;
+                    for (rule_id, occurences) in &mut rules_occurrences {
+                        if *rule_id == rule {
+                            *occurences += 1;
+                            found = true;
+                            break;
+                        }
+                    }
+                    if !found {
+                        rules_occurrences.push((rule, 1));
+                    }
                 }
-                None => {
-                    break;
+            }
+            Ignore::Enable => {
+                for rule in &range.rule_ids {
+                    for (rule_id, occurences) in &mut rules_occurrences {
+                        if *rule_id == rule {
+                            *occurences -= 1;
+                            break;
+                        }
+                    }
+                    // TODO: global disable followed by a scoped enable might not work
                 }
             }
         }
     }
+
+    let disabled_rules = rules_occurrences
+        .iter()
+        .filter(|(_, occurences)| *occurences > 0)
+        .map(|(rule, _)| rule.to_string())
+        .collect::<Vec<String>>();
+
+    for rule in disabled_rules {
+        if rule.is_empty() || rule == diag.id {
+            return true;
+        }
+    }
+    false
+}
+
+// Run the rules on a file, without the diagnostics disabled by comments
+fn diagnose_file(
+    rules: &[Box<dyn RuleType>],
+    file: &SolidFile,
+    context: &LintContext,
+) -> Vec<LintDiag> {
+    let ignore_comments: Vec<IgnoreComment> = file
+        .content
+        .lines()
+        .enumerate()
+        .filter_map(|(line_number, line)| IgnoreComment::from_line(line_number + 1, line))
+        .collect();
+    let disable_ranges = build_disable_ranges(ignore_comments);
+
+    let mut res = Vec::new();
+    for rule in rules {
+        for diag in rule.diagnose(file, context) {
+            if !check_is_in_disable_range(&diag, &disable_ranges) {
+                res.push(diag);
+            }
+        }
+    }
+    res
+}
+
+enum WorkerResult {
+    /// The file is excluded or its diagnostics are cached.
+    Skipped(FileDiags),
+    /// The diagnostics, with the summary and the context hash of the file to cache them.
+    Linted(FileDiags, FileSummary, String),
+    Failed,
+}
+
+enum PreparedFile {
+    Done(WorkerResult),
+    /// The file did not change since it was cached, but the files it depends on are only
+    /// known once the project graph is built.
+    Cached(String, FileSummary),
+    Parsed(SolidFile, FileSummary),
+}
+
+// Syntax trees and parsing errors are bound to the thread that created them, so a worker
+// only sends back the diagnostics and the caller lints the failed files again
+struct FileJob<'a> {
+    filepath: &'a str,
+    config_hash: &'a str,
+    rules: &'a [Box<dyn RuleType>],
+}
+
+// Parse a file to be linted once the project graph is built, unless it is skipped
+fn prepare_file_in_worker(
+    job: &FileJob,
+    excluded_files: &[String],
+    cache: Option<&LintCache>,
+) -> PreparedFile {
+    let filepath = job.filepath;
+    let content = match fs::read_to_string(filepath) {
+        Ok(content) => content,
+        Err(_) => return PreparedFile::Done(WorkerResult::Failed),
+    };
+    if excluded_files.iter().any(|excluded| excluded == filepath) {
+        let diags = FileDiags::new(content, Vec::new());
+        return PreparedFile::Done(WorkerResult::Skipped(diags));
+    }
+    if let Some(summary) =
+        cache.and_then(|cache| cache.get_summary(filepath, &content, job.config_hash))
+    {
+        return PreparedFile::Cached(content, summary);
+    }
+    match parse_solid_file(filepath, content) {
+        Some(file) => {
+            let summary = FileSummary::new(filepath, &file.data);
+            PreparedFile::Parsed(file, summary)
+        }
+        None => PreparedFile::Done(WorkerResult::Failed),
+    }
+}
+
+// The hash of the paths and contents of the files a file depends on. The content hashes
+// are taken from `hashes`, by absolute path, else the files are read from the disk.
+fn context_hash(
+    project: &ProjectGraph,
+    filepath: &str,
+    hashes: &HashMap<String, String>,
+) -> String {
+    let mut context = String::new();
+    for dependency in project.dependencies(filepath) {
+        let hash = hashes.get(&dependency).cloned().or_else(|| {
+            fs::read_to_string(&dependency)
+                .ok()
+                .map(|content| hash_content(&content))
+        });
+        context.push_str(&format!("{}:{}\n", dependency, hash.unwrap_or_default()));
+    }
+    hash_content(&context)
+}
+
+fn parse_solid_file(filepath: &str, content: String) -> Option<SolidFile> {
+    let ast =
+        osmium_libs_solidity_ast_extractor::extract::extract_ast_from_content(&content).ok()?;
+    Some(SolidFile {
+        data: ast,
+        path: filepath.to_string(),
+        content,
+    })
+}
+
+// The project graph with every file of the folder, and the content hashes of the parsed
+// and cached files by absolute path
+type SharedFiles = (ProjectGraph, HashMap<String, String>);
+
+// Lint the files on every available core, the results being in the order of the files.
+// The workers first parse their files, then wait for the project graph to be built with
+// every file before diagnosing them. Files without a job, whose configuration couldn't be
+// loaded, are failed. Each file is parsed once, by the worker linting it, and the rules get
+// the other files of the folder from the project graph.
+fn lint_files_in_parallel(
+    jobs: &[Option<FileJob>],
+    excluded_files: &[String],
+    cache: Option<&LintCache>,
+    project: &ProjectGraph,
+) -> (Vec<WorkerResult>, ProjectGraph) {
+    let workers = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(jobs.len());
+    let next = AtomicUsize::new(0);
+    let summaries: Mutex<Vec<FileSummary>> = Mutex::new(Vec::new());
+    let hashes: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+    let barrier = Barrier::new(workers);
+    let shared: OnceLock<SharedFiles> = OnceLock::new();
+    let build_shared = || {
+        let mut graph = project.clone();
+        for summary in summaries.lock().map(|s| s.clone()).unwrap_or_default() {
+            graph.add_file(summary);
+        }
+        let hashes = hashes.lock().map(|h| h.clone()).unwrap_or_default();
+        (graph, hashes)
+    };
+
+    let mut results: Vec<WorkerResult> = jobs.iter().map(|_| WorkerResult::Failed).collect();
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::new();
+                    let mut parsed = Vec::new();
+                    let mut cached = Vec::new();
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let job = match jobs.get(idx) {
+                            Some(Some(job)) => job,
+                            Some(None) => continue,
+                            None => break,
+                        };
+                        // a panic would leave the other workers waiting for this one
+                        let prepared = catch_unwind(AssertUnwindSafe(|| {
+                            prepare_file_in_worker(job, excluded_files, cache)
+                        }));
+                        let (summary, content_hash) = match prepared {
+                            Ok(PreparedFile::Done(result)) => {
+                                results.push((idx, result));
+                                continue;
+                            }
+                            Ok(PreparedFile::Cached(content, summary)) => {
+                                let content_hash = hash_content(&content);
+                                cached.push((idx, job, content, summary.clone()));
+                                (summary, content_hash)
+                            }
+                            Ok(PreparedFile::Parsed(file, summary)) => {
+                                let content_hash = hash_content(&file.content);
+                                parsed.push((idx, job, file, summary.clone()));
+                                (summary, content_hash)
+                            }
+                            Err(_) => continue,
+                        };
+                        if let Ok(summaries) = &mut summaries.lock() {
+                            summaries.push(summary);
+                        }
+                        if let Ok(hashes) = &mut hashes.lock() {
+                            hashes.insert(absolute_path(job.filepath), content_hash);
+                        }
+                    }
+
+                    if barrier.wait().is_leader() {
+                        let _ = shared.set(build_shared());
+                    }
+                    barrier.wait();
+
+                    if let Some((project, hashes)) = shared.get() {
+                        // the cached diagnostics are kept if the dependencies did not change,
+                        // else the file is parsed to be linted
+                        for (idx, job, content, summary) in cached {
+                            let context_hash = context_hash(project, job.filepath, hashes);
+                            let diags = cache.and_then(|cache| {
+                                cache.get(job.filepath, &content, job.config_hash, &context_hash)
+                            });
+                            if let Some(diags) = diags {
+                                let diags = FileDiags::new(content, diags);
+                                results.push((idx, WorkerResult::Skipped(diags)));
+                            } else if let Some(file) = parse_solid_file(job.filepath, content) {
+                                parsed.push((idx, job, file, summary));
+                            }
+                        }
+                        let context = LintContext { project };
+                        for (idx, job, file, summary) in parsed {
+                            let context_hash = context_hash(project, job.filepath, hashes);
+                            let diags = diagnose_file(job.rules, &file, &context);
+                            let diags = FileDiags::new(file.content, diags);
+                            results.push((idx, WorkerResult::Linted(diags, summary, context_hash)));
+                        }
+                    }
+                    results
+                })
+            })
+            .collect();
+        // the files of a worker that panicked are linted again by the caller
+        for handle in handles {
+            for (idx, result) in handle.join().unwrap_or_default() {
+                results[idx] = result;
+            }
+        }
+    });
+
+    let (graph, _) = shared.into_inner().unwrap_or_else(build_shared);
+    (results, graph)
 }
@@ -0,0 +1,556 @@
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_import_directive_nodes,
+};
+use osmium_libs_solidity_ast_extractor::{ContractKind, File, ImportPath, Item};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+/// Replaces the `prefix` of import paths by `target`, such as `@openzeppelin/=lib/openzeppelin/`.
+/// With a `context`, the remapping only applies to the files under that directory.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Remapping {
+    pub context: Option<String>,
+    pub prefix: String,
+    pub target: String,
+}
+
+impl Remapping {
+    /// Parse a remapping written as `[context:]prefix=target`.
+    pub fn parse(remapping: &str) -> Option<Self> {
+        let (left, target) = remapping.trim().split_once('=')?;
+        let (context, prefix) = match left.split_once(':') {
+            Some((context, prefix)) => (Some(context.to_string()), prefix),
+            None => (None, left),
+        };
+        if prefix.is_empty() {
+            return None;
+        }
+        Some(Remapping {
+            context: context.filter(|context| !context.is_empty()),
+            prefix: prefix.to_string(),
+            target: target.to_string(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractType {
+    Contract,
+    AbstractContract,
+    Interface,
+    Library,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ContractInfo {
+    pub name: String,
+    pub kind: ContractType,
+    /// Names of the inherited contracts, in declaration order.
+    pub bases: Vec<String>,
+    pub file: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ImportInfo {
+    /// The path as written in the import directive.
+    pub path: String,
+    /// The names bound by `import {A, B as C} from "..."`, `A` and `C`, empty for the
+    /// other forms.
+    pub symbols: Vec<String>,
+    /// The unit alias of `import "..." as A` and `import * as A from "..."`.
+    pub alias: Option<String>,
+}
+
+/// The imports and contracts of a file, which is all the project graph keeps of it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FileSummary {
+    pub path: String,
+    pub imports: Vec<ImportInfo>,
+    pub contracts: Vec<ContractInfo>,
+    /// The names declared at the top level of the file.
+    pub symbols: Vec<String>,
+}
+
+impl FileSummary {
+    pub fn new(path: &str, ast: &File) -> Self {
+        let path = absolute_path(path);
+        let imports = retrieve_import_directive_nodes(ast)
+            .into_iter()
+            .map(|import| {
+                let (symbols, alias) = match &import.path {
+                    ImportPath::Plain(plain) => (
+                        Vec::new(),
+                        plain.alias.as_ref().map(|alias| alias.alias.to_string()),
+                    ),
+                    ImportPath::Aliases(aliases) => (
+                        aliases
+                            .imports
+                            .iter()
+                            .map(|(symbol, alias)| {
+                                alias
+                                    .as_ref()
+                                    .map_or(symbol, |alias| &alias.alias)
+                                    .to_string()
+                            })
+                            .collect(),
+                        None,
+                    ),
+                    ImportPath::Glob(glob) => (
+                        Vec::new(),
+                        glob.alias.as_ref().map(|alias| alias.alias.to_string()),
+                    ),
+                };
+                ImportInfo {
+                    path: import.path.path().value(),
+                    symbols,
+                    alias,
+                }
+            })
+            .collect();
+        let contracts = retrieve_contract_nodes(ast)
+            .into_iter()
+            .map(|contract| ContractInfo {
+                name: contract.name.to_string(),
+                kind: match contract.kind {
+                    ContractKind::AbstractContract(..) => ContractType::AbstractContract,
+                    ContractKind::Contract(_) => ContractType::Contract,
+                    ContractKind::Interface(_) => ContractType::Interface,
+                    ContractKind::Library(_) => ContractType::Library,
+                },
+                bases: contract
+                    .inheritance
+                    .iter()
+                    .flat_map(|inheritance| inheritance.inheritance.iter())
+                    .map(|base| base.name.last().to_string())
+                    .collect(),
+                file: path.clone(),
+            })
+            .collect();
+
+        let symbols = ast
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Variable(var) => Some(&var.name),
+                item => item.name(),
+            })
+            .map(|name| name.to_string())
+            .collect();
+
+        FileSummary {
+            path,
+            imports,
+            contracts,
+            symbols,
+        }
+    }
+}
+
+/// Resolve the `.` and `..` components of a path without touching the disk, so that the
+/// different spellings of a path are the same key.
+pub fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let mut components: Vec<&str> = Vec::new();
+    for component in path.split('/') {
+        match component {
+            "" | "." if !components.is_empty() || path.starts_with('/') => {}
+            "." => {}
+            ".." if components
+                .last()
+                .is_some_and(|last| *last != ".." && !last.is_empty()) =>
+            {
+                components.pop();
+            }
+            _ => components.push(component),
+        }
+    }
+    let normalized = components.join("/");
+    if path.starts_with('/') {
+        format!("/{}", normalized)
+    } else {
+        normalized
+    }
+}
+
+/// The absolute form of a path, relative paths being resolved from the current directory,
+/// which is the form of the paths kept in the project graph.
+pub fn absolute_path(path: &str) -> String {
+    if Path::new(path).is_absolute() {
+        return normalize_path(path);
+    }
+    match std::env::current_dir() {
+        Ok(dir) => normalize_path(&dir.join(path).to_string_lossy()),
+        Err(_) => normalize_path(path),
+    }
+}
+
+fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+/// The import graph and the contract inheritance graph of the files known to the linter.
+/// The imports of a file are resolved when it is added, and the graph keeps the files
+/// importing each file and the files declaring each contract, so that the queries do not
+/// walk every file.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectGraph {
+    remappings: Vec<Remapping>,
+    // the directory of the non-relative imports which are not remapped
+    root: Option<String>,
+    files: HashMap<String, FileSummary>,
+    // the resolved paths of the imports of each file, whether they exist or not
+    imports: HashMap<String, Vec<String>>,
+    // the imported paths which exist on the disk, even if they are not in the graph
+    on_disk: HashSet<String>,
+    importers: HashMap<String, BTreeSet<String>>,
+    // the files declaring a contract, and the files declaring a contract inheriting from it
+    contracts: HashMap<String, BTreeSet<String>>,
+    derived: HashMap<String, BTreeSet<String>>,
+}
+
+impl ProjectGraph {
+    pub fn set_remappings(&mut self, remappings: Vec<Remapping>) {
+        self.remappings = remappings;
+        self.resolve_all();
+    }
+
+    pub fn remappings(&self) -> &[Remapping] {
+        &self.remappings
+    }
+
+    /// The directory the non-relative imports and the relative remappings are resolved
+    /// from, the current directory by default.
+    pub fn set_root(&mut self, root: &str) {
+        self.root = Some(absolute_path(root));
+        self.resolve_all();
+    }
+
+    pub fn add_file(&mut self, summary: FileSummary) {
+        let path = absolute_path(&summary.path);
+        self.remove_file(&path);
+
+        let imports: Vec<String> = summary
+            .imports
+            .iter()
+            .map(|import| self.import_path(&path, &import.path))
+            .collect();
+        for import in &imports {
+            if !self.on_disk.contains(import) && Path::new(import).is_file() {
+                self.on_disk.insert(import.clone());
+            }
+            self.importers
+                .entry(import.clone())
+                .or_default()
+                .insert(path.clone());
+        }
+        for contract in &summary.contracts {
+            self.contracts
+                .entry(contract.name.clone())
+                .or_default()
+                .insert(path.clone());
+            for base in &contract.bases {
+                self.derived
+                    .entry(base.clone())
+                    .or_default()
+                    .insert(path.clone());
+            }
+        }
+        self.imports.insert(path.clone(), imports);
+        self.files.insert(path, summary);
+    }
+
+    pub fn remove_file(&mut self, path: &str) {
+        let path = absolute_path(path);
+        let summary = match self.files.remove(&path) {
+            Some(summary) => summary,
+            None => return,
+        };
+        for import in self.imports.remove(&path).unwrap_or_default() {
+            remove_from_index(&mut self.importers, &import, &path);
+        }
+        for contract in &summary.contracts {
+            remove_from_index(&mut self.contracts, &contract.name, &path);
+            for base in &contract.bases {
+                remove_from_index(&mut self.derived, base, &path);
+            }
+        }
+    }
+
+    pub fn file(&self, path: &str) -> Option<&FileSummary> {
+        self.files.get(&absolute_path(path))
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = &FileSummary> {
+        self.files.values()
+    }
+
+    // The imports depend on the remappings and the root, so they are resolved again
+    fn resolve_all(&mut self) {
+        let summaries: Vec<FileSummary> = self.files.drain().map(|(_, file)| file).collect();
+        self.imports.clear();
+        self.on_disk.clear();
+        self.importers.clear();
+        self.contracts.clear();
+        self.derived.clear();
+        for summary in summaries {
+            self.add_file(summary);
+        }
+    }
+
+    fn root_path(&self, path: &str) -> String {
+        match &self.root {
+            Some(root) if !Path::new(path).is_absolute() => {
+                normalize_path(&format!("{}/{}", root, path))
+            }
+            _ => absolute_path(path),
+        }
+    }
+
+    // The longest matching prefix wins, and a remapping with a context over one without
+    fn remap(&self, from: &str, import: &str) -> Option<String> {
+        self.remappings
+            .iter()
+            .filter(|remapping| import.starts_with(&remapping.prefix))
+            .filter(|remapping| {
+                remapping
+                    .context
+                    .as_ref()
+                    .is_none_or(|context| from.starts_with(&self.root_path(context)))
+            })
+            .max_by_key(|remapping| {
+                (
+                    remapping.context.as_ref().map_or(0, String::len),
+                    remapping.prefix.len(),
+                )
+            })
+            .map(|remapping| format!("{}{}", remapping.target, &import[remapping.prefix.len()..]))
+    }
+
+    // The path an import refers to, whether the file exists or not
+    fn import_path(&self, from: &str, import: &str) -> String {
+        if import.starts_with("./") || import.starts_with("../") {
+            normalize_path(&format!("{}/{}", parent_dir(from), import))
+        } else {
+            self.root_path(&self.remap(from, import).unwrap_or(import.to_string()))
+        }
+    }
+
+    fn is_known(&self, path: &str) -> bool {
+        self.files.contains_key(path) || self.on_disk.contains(path)
+    }
+
+    /// The path of the file imported by `import` in the file `from`, if it is known to the
+    /// graph or exists on the disk.
+    pub fn resolve_import(&self, from: &str, import: &str) -> Option<String> {
+        let candidate = self.import_path(&absolute_path(from), import);
+        if self.is_known(&candidate) || Path::new(&candidate).is_file() {
+            Some(candidate)
+        } else {
+            None
+        }
+    }
+
+    /// The files directly imported by a file.
+    pub fn imports(&self, path: &str) -> Vec<String> {
+        self.imports
+            .get(&absolute_path(path))
+            .map(|imports| {
+                imports
+                    .iter()
+                    .filter(|import| self.is_known(import))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The files directly importing a file.
+    pub fn imported_by(&self, path: &str) -> Vec<String> {
+        let path = absolute_path(path);
+        if !self.is_known(&path) {
+            return Vec::new();
+        }
+        self.importers
+            .get(&path)
+            .map(|importers| importers.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// The files imported by a file, directly or through other files.
+    pub fn transitive_imports(&self, path: &str) -> Vec<String> {
+        let path = absolute_path(path);
+        let mut visited: HashSet<String> = HashSet::from([path.clone()]);
+        let mut queue = VecDeque::from([path]);
+        let mut imports = Vec::new();
+
+        while let Some(file) = queue.pop_front() {
+            for import in self.imports(&file) {
+                if visited.insert(import.clone()) {
+                    imports.push(import.clone());
+                    queue.push_back(import);
+                }
+            }
+        }
+        imports
+    }
+
+    /// The names a file binds in the files importing all of it: its top-level declarations
+    /// and the names it imports, none if it or a file it imports all of is not in the graph.
+    pub fn exported_symbols(&self, path: &str) -> Option<BTreeSet<String>> {
+        let mut symbols = BTreeSet::new();
+        self.collect_exported_symbols(&absolute_path(path), &mut HashSet::new(), &mut symbols)?;
+        Some(symbols)
+    }
+
+    fn collect_exported_symbols(
+        &self,
+        path: &str,
+        visited: &mut HashSet<String>,
+        symbols: &mut BTreeSet<String>,
+    ) -> Option<()> {
+        if !visited.insert(path.to_string()) {
+            return Some(());
+        }
+        let summary = self.files.get(path)?;
+        symbols.extend(summary.symbols.iter().cloned());
+        let resolved = self.imports.get(path)?;
+        for (import, resolved) in summary.imports.iter().zip(resolved) {
+            match &import.alias {
+                Some(alias) => {
+                    symbols.insert(alias.clone());
+                }
+                None if !import.symbols.is_empty() => {
+                    symbols.extend(import.symbols.iter().cloned())
+                }
+                None => self.collect_exported_symbols(resolved, visited, symbols)?,
+            }
+        }
+        Some(())
+    }
+
+    /// The contract `name` as seen from the file `from`: declared in it, else in the files
+    /// it imports, else anywhere in the project.
+    pub fn find_contract(&self, from: &str, name: &str) -> Option<&ContractInfo> {
+        let files = self.contracts.get(name)?;
+        let from = absolute_path(from);
+        let in_file = |path: &str| {
+            self.files
+                .get(path)
+                .and_then(|file| file.contracts.iter().find(|contract| contract.name == name))
+        };
+
+        // the imports are only walked when several files declare the contract
+        let path = if files.contains(&from) {
+            from
+        } else if files.len() == 1 {
+            files.first()?.clone()
+        } else {
+            self.transitive_imports(&from)
+                .into_iter()
+                .find(|path| files.contains(path))
+                .or_else(|| files.first().cloned())?
+        };
+        in_file(&path)
+    }
+
+    /// Every contract a contract inherits from, directly or not, the closest first. Bases
+    /// which are not found in the project are skipped.
+    pub fn ancestors(&self, from: &str, name: &str) -> Vec<&ContractInfo> {
+        let mut ancestors: Vec<&ContractInfo> = Vec::new();
+        let mut queue: VecDeque<&ContractInfo> =
+            self.find_contract(from, name).into_iter().collect();
+
+        while let Some(contract) = queue.pop_front() {
+            for base in &contract.bases {
+                if let Some(base) = self.find_contract(&contract.file, base) {
+                    let known = ancestors
+                        .iter()
+                        .any(|ancestor| ancestor.name == base.name && ancestor.file == base.file);
+                    if !known && base.name != name {
+                        ancestors.push(base);
+                        queue.push_back(base);
+                    }
+                }
+            }
+        }
+        ancestors
+    }
+
+    /// The contracts inheriting directly from the contract `name`.
+    pub fn derived_contracts(&self, name: &str) -> Vec<&ContractInfo> {
+        self.derived
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter_map(|path| self.files.get(path))
+            .flat_map(|file| &file.contracts)
+            .filter(|contract| contract.bases.iter().any(|base| base == name))
+            .collect()
+    }
+
+    /// Every contract inheriting from the contract `name`, directly or not.
+    pub fn descendants(&self, name: &str) -> Vec<&ContractInfo> {
+        let mut descendants: Vec<&ContractInfo> = Vec::new();
+        let mut queue = vec![name.to_string()];
+
+        while let Some(name) = queue.pop() {
+            for derived in self.derived_contracts(&name) {
+                let known = descendants
+                    .iter()
+                    .any(|known| known.name == derived.name && known.file == derived.file);
+                if !known {
+                    descendants.push(derived);
+                    queue.push(derived.name.clone());
+                }
+            }
+        }
+        descendants
+    }
+
+    /// The files importing a file, directly or through other files.
+    pub fn transitive_importers(&self, path: &str) -> Vec<String> {
+        let path = absolute_path(path);
+        let mut visited: HashSet<String> = HashSet::from([path.clone()]);
+        let mut queue = VecDeque::from([path]);
+        let mut importers = Vec::new();
+
+        while let Some(file) = queue.pop_front() {
+            for importer in self.imported_by(&file) {
+                if visited.insert(importer.clone()) {
+                    importers.push(importer.clone());
+                    queue.push_back(importer);
+                }
+            }
+        }
+        importers
+    }
+
+    /// The other files whose content may change the diagnostics of a file: the files it
+    /// imports or which import it, directly or not, and the files of the contracts its
+    /// contracts inherit from or are inherited by, sorted.
+    pub fn dependencies(&self, path: &str) -> Vec<String> {
+        let path = absolute_path(path);
+        let mut dependencies: BTreeSet<String> = BTreeSet::new();
+        dependencies.extend(self.transitive_imports(&path));
+        dependencies.extend(self.transitive_importers(&path));
+        for contract in self.file(&path).map_or(&[][..], |file| &file.contracts) {
+            let ancestors = self.ancestors(&path, &contract.name);
+            let descendants = self.descendants(&contract.name);
+            for related in ancestors.into_iter().chain(descendants) {
+                dependencies.insert(related.file.clone());
+            }
+        }
+        dependencies.remove(&path);
+        dependencies.into_iter().collect()
+    }
+}
+
+fn remove_from_index(index: &mut HashMap<String, BTreeSet<String>>, key: &str, path: &str) {
+    if let Some(paths) = index.get_mut(key) {
+        paths.remove(path);
+        if paths.is_empty() {
+            index.remove(key);
+        }
+    }
+}
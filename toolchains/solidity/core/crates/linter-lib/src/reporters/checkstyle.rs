@@ -0,0 +1,44 @@
+use super::{escape_xml, group_by_file, Reporter};
+use crate::types::{LintResult, Severity};
+
+/// Checkstyle XML output, understood by most CI code quality plugins.
+pub struct CheckstyleReporter;
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::ERROR => "error",
+        Severity::WARNING => "warning",
+        Severity::INFO | Severity::HINT => "info",
+    }
+}
+
+impl Reporter for CheckstyleReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        let mut output = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        output.push_str("<checkstyle version=\"4.3\">\n");
+
+        for (uri, diags) in group_by_file(results) {
+            output.push_str(&format!("  <file name=\"{}\">\n", escape_xml(&uri)));
+            for diag in diags {
+                output.push_str(&format!(
+                    "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"solidhunter.{}\" />\n",
+                    diag.range.start.line,
+                    diag.range.start.character + 1,
+                    severity_name(diag.severity),
+                    escape_xml(&diag.message),
+                    escape_xml(&diag.id)
+                ));
+            }
+            output.push_str("  </file>\n");
+        }
+        for error in results.iter().filter_map(|result| result.as_ref().err()) {
+            output.push_str(&format!(
+                "  <file name=\"{}\">\n    <error severity=\"error\" message=\"{}\" source=\"solidhunter\" />\n  </file>\n",
+                escape_xml(error.filepath().unwrap_or_default()),
+                escape_xml(&error.cause().to_string())
+            ));
+        }
+        output.push_str("</checkstyle>\n");
+        output
+    }
+}
@@ -0,0 +1,51 @@
+use super::Reporter;
+use crate::types::{LintResult, Severity};
+
+/// GitHub Actions workflow commands, displayed as annotations on pull requests.
+pub struct GithubReporter;
+
+fn command_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::ERROR => "error",
+        Severity::WARNING => "warning",
+        Severity::INFO | Severity::HINT => "notice",
+    }
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+impl Reporter for GithubReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        let mut output = String::new();
+        for result in results {
+            match result {
+                Ok(diags) => {
+                    for diag in &diags.diags {
+                        output.push_str(&format!(
+                            "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}\n",
+                            command_name(diag.severity),
+                            escape_property(diag.uri.trim_start_matches("./")),
+                            diag.range.start.line,
+                            diag.range.start.character + 1,
+                            diag.range.end.line,
+                            diag.range.end.character + 1,
+                            escape_property(&diag.id),
+                            escape_data(&diag.message)
+                        ));
+                    }
+                }
+                Err(e) => output.push_str(&format!("::error::{}\n", escape_data(&e.to_string()))),
+            }
+        }
+        output
+    }
+}
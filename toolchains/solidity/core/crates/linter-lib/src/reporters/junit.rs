@@ -0,0 +1,70 @@
+use super::{escape_xml, group_by_file, Reporter};
+use crate::types::{LintDiag, LintResult};
+
+/// JUnit XML output, with one test case per file and rule.
+pub struct JunitReporter;
+
+fn failure(diags: &[&LintDiag]) -> String {
+    let details: Vec<String> = diags
+        .iter()
+        .map(|diag| {
+            format!(
+                "{}:{}:{}: {}",
+                diag.uri,
+                diag.range.start.line,
+                diag.range.start.character + 1,
+                diag.message
+            )
+        })
+        .collect();
+
+    format!(
+        "      <failure message=\"{}\" type=\"{:?}\">{}</failure>\n",
+        escape_xml(&format!("{} problem(s)", diags.len())),
+        diags[0].severity,
+        escape_xml(&details.join("\n"))
+    )
+}
+
+impl Reporter for JunitReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        let mut testcases = String::new();
+        let mut tests = 0;
+        let mut failures = 0;
+        let mut errors = 0;
+
+        for (uri, diags) in group_by_file(results) {
+            let mut rules: Vec<(&str, Vec<&LintDiag>)> = Vec::new();
+            for diag in diags {
+                match rules.iter_mut().find(|(id, _)| *id == diag.id) {
+                    Some((_, rule_diags)) => rule_diags.push(diag),
+                    None => rules.push((&diag.id, vec![diag])),
+                }
+            }
+            for (id, rule_diags) in rules {
+                tests += 1;
+                failures += 1;
+                testcases.push_str(&format!(
+                    "    <testcase classname=\"{}\" name=\"{}\">\n{}    </testcase>\n",
+                    escape_xml(&uri),
+                    escape_xml(id),
+                    failure(&rule_diags)
+                ));
+            }
+        }
+        for error in results.iter().filter_map(|result| result.as_ref().err()) {
+            tests += 1;
+            errors += 1;
+            testcases.push_str(&format!(
+                "    <testcase classname=\"solidhunter\" name=\"{}\">\n      <error message=\"{}\" />\n    </testcase>\n",
+                escape_xml(error.filepath().unwrap_or("parsing")),
+                escape_xml(&error.cause().to_string())
+            ));
+        }
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<testsuites>\n  <testsuite name=\"solidhunter\" tests=\"{}\" failures=\"{}\" errors=\"{}\">\n{}  </testsuite>\n</testsuites>\n",
+            tests, failures, errors, testcases
+        )
+    }
+}
@@ -0,0 +1,48 @@
+use crate::types::{LintDiag, LintResult};
+
+pub mod checkstyle;
+pub mod github;
+pub mod json;
+pub mod junit;
+pub mod sarif;
+pub mod text;
+
+pub use checkstyle::CheckstyleReporter;
+pub use github::GithubReporter;
+pub use json::JsonReporter;
+pub use junit::JunitReporter;
+pub use sarif::SarifReporter;
+pub use text::TextReporter;
+
+pub trait Reporter {
+    /// Format the results of a lint run.
+    fn report(&self, results: &[LintResult]) -> String;
+}
+
+// Group the diagnostics by file, keeping the order in which the files were linted
+pub(crate) fn group_by_file(results: &[LintResult]) -> Vec<(String, Vec<&LintDiag>)> {
+    let mut files: Vec<(String, Vec<&LintDiag>)> = Vec::new();
+
+    for diag in results.iter().flatten().flat_map(|diags| &diags.diags) {
+        match files.iter_mut().find(|(uri, _)| *uri == diag.uri) {
+            Some((_, diags)) => diags.push(diag),
+            None => files.push((diag.uri.clone(), vec![diag])),
+        }
+    }
+    files
+}
+
+pub(crate) fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
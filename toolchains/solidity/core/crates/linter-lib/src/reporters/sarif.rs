@@ -0,0 +1,306 @@
+use super::Reporter;
+use crate::rules::types::RuleDocumentation;
+use crate::types::{LintDiag, LintResult, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const INFORMATION_URI: &str = "https://github.com/astrodevs-labs/osmium";
+// The directory the uris are relative to, the current directory of the analysis
+const SRCROOT: &str = "%SRCROOT%";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub original_uri_base_ids: BTreeMap<String, SarifArtifactLocation>,
+    pub results: Vec<SarifResult>,
+    pub invocations: Vec<SarifInvocation>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    pub name: String,
+    pub version: String,
+    pub information_uri: String,
+    pub rules: Vec<SarifRule>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    pub id: String,
+    pub short_description: SarifMessage,
+    pub help_uri: String,
+    pub default_configuration: SarifConfiguration,
+    pub properties: SarifRuleProperties,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifConfiguration {
+    pub level: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifRuleProperties {
+    pub category: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifMessage {
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_index: Option<usize>,
+    pub level: String,
+    pub message: SarifMessage,
+    pub locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    pub physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    pub region: SarifRegion,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifInvocation {
+    pub execution_successful: bool,
+    pub tool_execution_notifications: Vec<SarifNotification>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SarifNotification {
+    pub level: String,
+    pub message: SarifMessage,
+}
+
+fn level_from_severity(severity: Severity) -> String {
+    match severity {
+        Severity::ERROR => "error",
+        Severity::WARNING => "warning",
+        Severity::INFO | Severity::HINT => "note",
+    }
+    .to_string()
+}
+
+// Percent-encode the characters of a path which are not allowed in an uri
+fn encode_uri_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+// The file uri of an absolute path, `file:///C:/...` for Windows paths
+fn file_uri(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let path = path.trim_end_matches('/');
+    if path.starts_with('/') {
+        format!("file://{}", encode_uri_path(path))
+    } else {
+        format!("file:///{}", encode_uri_path(path))
+    }
+}
+
+// SARIF uris are relative to the analysis root, the files out of it get an absolute uri
+fn artifact_location(path: &str, root: Option<&Path>) -> SarifArtifactLocation {
+    let relative = match root {
+        Some(root) if Path::new(path).is_absolute() => Path::new(path).strip_prefix(root).ok(),
+        _ => Some(Path::new(path)),
+    };
+    match relative {
+        Some(relative) => {
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            SarifArtifactLocation {
+                uri: encode_uri_path(relative.trim_start_matches("./")),
+                uri_base_id: Some(SRCROOT.to_string()),
+            }
+        }
+        None => SarifArtifactLocation {
+            uri: file_uri(Path::new(path)),
+            uri_base_id: None,
+        },
+    }
+}
+
+fn rule_from_documentation(doc: &RuleDocumentation) -> SarifRule {
+    SarifRule {
+        id: doc.id.clone(),
+        short_description: SarifMessage {
+            text: doc.description.clone(),
+        },
+        help_uri: doc.source_link.clone(),
+        default_configuration: SarifConfiguration {
+            level: level_from_severity(doc.severity),
+        },
+        properties: SarifRuleProperties {
+            category: doc.category.clone(),
+            tags: vec![doc.category.clone()],
+        },
+    }
+}
+
+fn result_from_diag(diag: &LintDiag, rules: &[SarifRule], root: Option<&Path>) -> SarifResult {
+    SarifResult {
+        rule_id: diag.id.clone(),
+        rule_index: rules.iter().position(|rule| rule.id == diag.id),
+        level: level_from_severity(diag.severity),
+        message: SarifMessage {
+            text: diag.message.clone(),
+        },
+        locations: vec![SarifLocation {
+            physical_location: SarifPhysicalLocation {
+                artifact_location: artifact_location(&diag.uri, root),
+                // SARIF columns are 1-based
+                region: SarifRegion {
+                    start_line: diag.range.start.line,
+                    start_column: diag.range.start.character + 1,
+                    end_line: diag.range.end.line,
+                    end_column: diag.range.end.character + 1,
+                },
+            },
+        }],
+    }
+}
+
+impl SarifLog {
+    /// The log of the results, with the metadata of the rules reported found in the
+    /// documentation.
+    pub fn new(results: &[LintResult], documentation: &[RuleDocumentation]) -> Self {
+        let root = std::env::current_dir().ok();
+        let mut rules: Vec<SarifRule> = Vec::new();
+        for diag in results.iter().flatten().flat_map(|diags| &diags.diags) {
+            if rules.iter().any(|rule| rule.id == diag.id) {
+                continue;
+            }
+            if let Some(doc) = documentation.iter().find(|doc| doc.id == diag.id) {
+                rules.push(rule_from_documentation(doc));
+            }
+        }
+        let mut sarif_results = Vec::new();
+        let mut notifications = Vec::new();
+
+        for result in results {
+            match result {
+                Ok(file_diags) => {
+                    for diag in &file_diags.diags {
+                        sarif_results.push(result_from_diag(diag, &rules, root.as_deref()));
+                    }
+                }
+                Err(e) => notifications.push(SarifNotification {
+                    level: "error".to_string(),
+                    message: SarifMessage {
+                        text: e.to_string(),
+                    },
+                }),
+            }
+        }
+
+        SarifLog {
+            schema: SARIF_SCHEMA.to_string(),
+            version: SARIF_VERSION.to_string(),
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "solidhunter".to_string(),
+                        version: env!("CARGO_PKG_VERSION").to_string(),
+                        information_uri: INFORMATION_URI.to_string(),
+                        rules,
+                    },
+                },
+                original_uri_base_ids: root
+                    .iter()
+                    .map(|root| {
+                        let location = SarifArtifactLocation {
+                            // the base of relative uris ends with a slash
+                            uri: format!("{}/", file_uri(root)),
+                            uri_base_id: None,
+                        };
+                        (SRCROOT.to_string(), location)
+                    })
+                    .collect(),
+                results: sarif_results,
+                invocations: vec![SarifInvocation {
+                    execution_successful: notifications.is_empty(),
+                    tool_execution_notifications: notifications,
+                }],
+            }],
+        }
+    }
+}
+
+/// SARIF 2.1.0 log, with the metadata of the rules taken from their documentation, which
+/// should cover every rule the nested configs may enable.
+pub struct SarifReporter {
+    documentation: Vec<RuleDocumentation>,
+}
+
+impl SarifReporter {
+    pub fn new(documentation: Vec<RuleDocumentation>) -> Self {
+        SarifReporter { documentation }
+    }
+}
+
+impl Reporter for SarifReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        match serde_json::to_string_pretty(&SarifLog::new(results, &self.documentation)) {
+            Ok(json) => format!("{}\n", json),
+            Err(e) => format!("{}\n", e),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use super::Reporter;
+use crate::types::LintResult;
+
+/// Human readable output, with the highlighted source of each diagnostic.
+pub struct TextReporter;
+
+impl Reporter for TextReporter {
+    fn report(&self, results: &[LintResult]) -> String {
+        let mut output = String::new();
+        for result in results {
+            match result {
+                Ok(diags) => output.push_str(&format!("{}\n", diags)),
+                Err(e) => output.push_str(&format!("{}\n", e)),
+            }
+        }
+        output
+    }
+}
@@ -0,0 +1,249 @@
+use crate::linter::SolidFile;
+use crate::project::absolute_path;
+use crate::rules::gas::storage::PackedTypes;
+use crate::rules::types::*;
+use crate::rules::utils::{
+    is_constant, is_constant_expr, is_constant_or_immutable, range_from_spanned, WritesVisitor,
+};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes, retrieve_variable_definition_contract_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+use std::collections::HashSet;
+
+// global
+pub const RULE_ID: &str = "constant-or-immutable";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct ConstantOrImmutable {
+    data: RuleEntry,
+}
+
+// The names declared in a function, its parameters included, hiding the state variables
+#[derive(Default)]
+struct LocalsVisitor {
+    names: HashSet<String>,
+}
+
+impl<'ast> Visit<'ast> for LocalsVisitor {
+    fn visit_variable_declaration(&mut self, var: &'ast VariableDeclaration) {
+        if let Some(name) = &var.name {
+            self.names.insert(name.as_string());
+        }
+    }
+}
+
+// The state variables assigned by the constructor of the contract, and by its other
+// functions and modifiers or the ones of its derived contracts
+#[derive(Default)]
+struct Assignments {
+    constructor: HashSet<String>,
+    others: HashSet<String>,
+    // any variable may be written by an assembly block
+    has_assembly: bool,
+}
+
+impl Assignments {
+    fn add_function(&mut self, function: &ItemFunction, in_constructor: bool) {
+        let mut locals = LocalsVisitor::default();
+        locals.visit_item_function(function);
+        let mut writes = WritesVisitor::default();
+        writes.visit_item_function(function);
+        self.has_assembly |= writes.has_assembly;
+
+        let assigned = if in_constructor {
+            &mut self.constructor
+        } else {
+            &mut self.others
+        };
+        assigned.extend(
+            writes
+                .writes
+                .iter()
+                .map(|ident| ident.as_string())
+                .filter(|name| !locals.names.contains(name)),
+        );
+    }
+}
+
+fn is_private(var: &VariableDefinition) -> bool {
+    matches!(var.attributes.visibility(), Some(Visibility::Private(_)))
+}
+
+// Where to add the keyword: after the attributes of the variable, or its type
+fn keyword_position(var: &VariableDefinition) -> Position {
+    match var.attributes.0.last() {
+        Some(attribute) => range_from_spanned(attribute).end,
+        None => range_from_spanned(&var.ty).end,
+    }
+}
+
+impl ConstantOrImmutable {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        var: &VariableDefinition,
+        reason: &str,
+        keyword: &str,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(&var.name),
+            message: format!(
+                "State variable \"{}\" {}, it should be declared {}",
+                var.name.as_string(),
+                reason,
+                keyword
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: Some(Fix {
+                message: format!("Declare \"{}\" {}", var.name.as_string(), keyword),
+                edits: vec![TextEdit::insert(
+                    keyword_position(var),
+                    format!(" {}", keyword),
+                )],
+            }),
+            uri: file.path.clone(),
+        }
+    }
+}
+
+/// A state variable which should be declared `constant` or `immutable`, with the reason.
+pub(crate) struct Candidate {
+    pub var: VariableDefinition,
+    pub reason: &'static str,
+    pub keyword: &'static str,
+}
+
+/// The state variables of the contracts of a file which are never assigned after their
+/// declaration, or only in the constructor. The gas rule reports the same variables.
+pub(crate) fn find_candidates(file: &SolidFile, context: &LintContext) -> Vec<Candidate> {
+    let mut candidates = Vec::new();
+    let path = absolute_path(&file.path);
+    let types = PackedTypes::new(file, context);
+    let contracts = retrieve_contract_nodes(&file.data);
+
+    for contract in &contracts {
+        if contract.is_interface() || contract.is_library() {
+            continue;
+        }
+        let descendants = context.project.descendants(&contract.name.as_string());
+        // derived contracts out of this file may assign the non-private variables
+        let open = descendants.iter().any(|derived| derived.file != path);
+
+        let mut assignments = Assignments::default();
+        for function in retrieve_functions_nodes(contract) {
+            let in_constructor = matches!(function.kind, FunctionKind::Constructor(_));
+            assignments.add_function(&function, in_constructor);
+        }
+        for derived in &contracts {
+            if descendants
+                .iter()
+                .any(|d| d.name == derived.name.as_string())
+            {
+                for function in retrieve_functions_nodes(derived) {
+                    assignments.add_function(&function, false);
+                }
+            }
+        }
+
+        if assignments.has_assembly {
+            continue;
+        }
+
+        let vars = retrieve_variable_definition_contract_nodes(contract);
+        let constants: Vec<String> = vars
+            .iter()
+            .filter(|var| is_constant(var))
+            .map(|var| var.name.as_string())
+            .collect();
+
+        for var in vars {
+            let name = var.name.as_string();
+            if is_constant_or_immutable(&var)
+                || (open && !is_private(&var))
+                || assignments.others.contains(&name)
+            {
+                continue;
+            }
+            // only value types can be immutable, strings and bytes can also be constant
+            let value_type = types.size(&var.ty).is_some();
+            let constant_type = value_type || matches!(var.ty, Type::String(_) | Type::Bytes(_));
+
+            let found = if assignments.constructor.contains(&name) {
+                value_type.then_some(("is only assigned in the constructor", "immutable"))
+            } else if let Some((_, initializer)) = &var.initializer {
+                let reason = "is never assigned after its declaration";
+                if constant_type && is_constant_expr(initializer, &constants) {
+                    Some((reason, "constant"))
+                } else {
+                    value_type.then_some((reason, "immutable"))
+                }
+            } else {
+                None
+            };
+            if let Some((reason, keyword)) = found {
+                candidates.push(Candidate {
+                    var,
+                    reason,
+                    keyword,
+                });
+            }
+        }
+    }
+    candidates
+}
+
+impl RuleType for ConstantOrImmutable {
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        find_candidates(file, context)
+            .iter()
+            .map(|candidate| {
+                self.create_diag(file, &candidate.var, candidate.reason, candidate.keyword)
+            })
+            .collect()
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "State variables never assigned after their declaration should be constant, and the ones only assigned in the constructor immutable. The assignments of every function and modifier of the contract and of its derived contracts are checked.".to_string(),
+            category: "best-practices".to_string(),
+            example_config: "{\"id\": \"constant-or-immutable\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/constant_or_immutable.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/ConstantOrImmutable".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Constant and immutable variables".to_string(),
+                    code: "uint256 private constant FEE = 100;\naddress private immutable owner;\n\nconstructor() {\n    owner = msg.sender;\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Variables never assigned outside of the declaration and the constructor".to_string(),
+                    code: "uint256 private fee = 100;\naddress private owner;\n\nconstructor() {\n    owner = msg.sender;\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl ConstantOrImmutable {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = ConstantOrImmutable { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
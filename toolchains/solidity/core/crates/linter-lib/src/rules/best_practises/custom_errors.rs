@@ -36,13 +36,14 @@ impl CustomErrors {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for CustomErrors {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
 
         for contract in retriever::retrieve_contract_nodes(&file.data) {
@@ -0,0 +1,45 @@
+use crate::linter::SolidFile;
+use crate::project::absolute_path;
+use crate::rules::types::LintContext;
+use crate::rules::utils::ReferencesVisitor;
+use osmium_libs_solidity_ast_extractor::{Item, ItemContract, ItemFunction, Visit};
+
+/// The names referenced anywhere in the file.
+pub fn file_references(file: &SolidFile) -> ReferencesVisitor {
+    let mut visitor = ReferencesVisitor::default();
+    visitor.visit_file(&file.data);
+    visitor
+}
+
+/// Whether other files of the project may use the items declared at the top level of
+/// the file, which is the case when they import it.
+pub fn is_imported(file: &SolidFile, context: &LintContext) -> bool {
+    !context.project.imported_by(&file.path).is_empty()
+}
+
+/// Whether contracts of other files inherit from the contract, and may use its internal
+/// items.
+pub fn has_external_descendants(
+    file: &SolidFile,
+    context: &LintContext,
+    contract: &ItemContract,
+) -> bool {
+    let path = absolute_path(&file.path);
+    context
+        .project
+        .descendants(&contract.name.as_string())
+        .iter()
+        .any(|derived| derived.file != path)
+}
+
+/// The functions declared at the top level of the file, out of the contracts.
+pub fn free_functions(file: &SolidFile) -> Vec<&ItemFunction> {
+    file.data
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Function(function) => Some(function),
+            _ => None,
+        })
+        .collect()
+}
@@ -62,19 +62,36 @@ impl ExplicitTypesVisitor {
     }
 }
 
+// Return the alias of a type, only uint/int have one
+fn alias_of(ty: &str) -> Option<&'static str> {
+    match ty {
+        "uint" => Some("uint256"),
+        "int" => Some("int256"),
+        "uint256" => Some("uint"),
+        "int256" => Some("int"),
+        _ => None,
+    }
+}
+
 impl ExplicitTypes {
-    fn create_diag(&self, file: &SolidFile, ty: Box<dyn Spanned>, var: Option<String>) -> LintDiag {
-        LintDiag {
-            range: Range {
-                start: Position {
-                    line: ty.span().start().line,
-                    character: ty.span().start().column,
-                },
-                end: Position {
-                    line: ty.span().end().line,
-                    character: ty.span().end().column,
-                },
+    fn create_diag(&self, file: &SolidFile, ty: &Type, var: Option<String>) -> LintDiag {
+        let range = Range {
+            start: Position {
+                line: ty.span().start().line,
+                character: ty.span().start().column,
+            },
+            end: Position {
+                line: ty.span().end().line,
+                character: ty.span().end().column,
             },
+        };
+        let fix = alias_of(&ty.to_string()).map(|alias| Fix {
+            message: format!("Replace {} with {}", ty, alias),
+            edits: vec![TextEdit::replace(range.clone(), alias.to_string())],
+        });
+
+        LintDiag {
+            range,
             id: RULE_ID.to_string(),
             message: format!(
                 "Rule is set with {} type [var/s: {}]",
@@ -84,13 +101,14 @@ impl ExplicitTypes {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for ExplicitTypes {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
         let mut visitor = ExplicitTypesVisitor {
             explicit: self.rule == "explicit",
@@ -100,17 +118,17 @@ impl RuleType for ExplicitTypes {
         };
         visitor.visit_file(&file.data);
         for def in visitor.defs {
-            res.push(self.create_diag(file, Box::new(def.ty), Some(def.name.0.to_string())));
+            res.push(self.create_diag(file, &def.ty, Some(def.name.0.to_string())));
         }
         for decl in visitor.decls {
             let name = match decl.name {
                 Some(ident) => Some(ident.0.to_string()),
                 _ => None,
             };
-            res.push(self.create_diag(file, Box::new(decl.ty), name));
+            res.push(self.create_diag(file, &decl.ty, name));
         }
         for ty in visitor.types {
-            res.push(self.create_diag(file, Box::new(ty), None));
+            res.push(self.create_diag(file, &ty, None));
         }
         res
     }
@@ -169,15 +187,9 @@ impl ExplicitTypes {
         let mut value = DEFAULT_RULE.to_string();
 
         if let Some(data) = &data.data {
-            let parsed: Result<String, serde_json::Error> = serde_json::from_value(data.clone());
-            match parsed {
-                Ok(val) => value = val,
-                Err(_) => {
-                    eprintln!("{} rule : bad config data", RULE_ID);
-                }
+            if let Ok(val) = serde_json::from_value::<String>(data.clone()) {
+                value = val;
             }
-        } else {
-            eprintln!("{} rule : bad config data", RULE_ID);
         }
         let rule = ExplicitTypes { rule: value, data };
         Box::new(rule)
@@ -189,4 +201,8 @@ impl ExplicitTypes {
             data: Some(DEFAULT_RULE.into()),
         }
     }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Enum(vec!["explicit", "implicit"])
+    }
 }
@@ -17,7 +17,7 @@ pub struct FunctionMaxLines {
 }
 
 impl RuleType for FunctionMaxLines {
-    fn diagnose(&self, _file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, _file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
 
         for contract in
@@ -37,6 +37,7 @@ impl RuleType for FunctionMaxLines {
                         severity: self.data.severity,
                         code: None,
                         source: None,
+                        fix: None,
                         message: format!(
                             "Function body contains {} lines but allowed no more than {} lines",
                             end - start,
@@ -103,15 +104,9 @@ impl FunctionMaxLines {
         let mut max_number_lines = DEFAULT_MAX_LINES;
 
         if let Some(data) = &data.data {
-            let parsed: Result<usize, serde_json::Error> = serde_json::from_value(data.clone());
-            match parsed {
-                Ok(val) => max_number_lines = val,
-                Err(_) => {
-                    eprintln!("{} rule : bad config data", RULE_ID);
-                }
+            if let Ok(val) = serde_json::from_value::<usize>(data.clone()) {
+                max_number_lines = val;
             }
-        } else {
-            eprintln!("{} rule : bad config data", RULE_ID);
         }
         let rule = FunctionMaxLines {
             number_max_lines: max_number_lines,
@@ -127,4 +122,8 @@ impl FunctionMaxLines {
             data: Some(DEFAULT_MAX_LINES.into()),
         }
     }
+
+    pub fn data_schema() -> DataSchema {
+        DataSchema::Integer
+    }
 }
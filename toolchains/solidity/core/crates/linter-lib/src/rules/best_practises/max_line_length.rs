@@ -36,21 +36,20 @@ impl MaxLineLength {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for MaxLineLength {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
-        let mut line_idx = 1;
 
-        for line in file.content.lines() {
+        for (line_idx, line) in (1..).zip(file.content.lines()) {
             if line.len() > self.max_len {
                 res.push(self.create_diag(file, line_idx, line));
             }
-            line_idx += 1;
         }
         res
     }
@@ -81,15 +80,9 @@ impl MaxLineLength {
         let mut max_line_length = DEFAULT_LENGTH;
 
         if let Some(data) = &data.data {
-            let parsed: Result<usize, serde_json::Error> = serde_json::from_value(data.clone());
-            match parsed {
-                Ok(val) => max_line_length = val,
-                Err(_) => {
-                    eprintln!("{} rule : bad config data", RULE_ID);
-                }
+            if let Ok(val) = serde_json::from_value::<usize>(data.clone()) {
+                max_line_length = val;
             }
-        } else {
-            eprintln!("{} rule : bad config data", RULE_ID);
         }
         let rule = MaxLineLength {
             max_len: max_line_length,
@@ -105,4 +98,8 @@ impl MaxLineLength {
             data: Some(DEFAULT_LENGTH.into()),
         }
     }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Integer
+    }
 }
@@ -41,13 +41,14 @@ impl MaxStatesCount {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for MaxStatesCount {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
 
         let mut count = 0;
@@ -93,15 +94,9 @@ impl MaxStatesCount {
         let mut max_states = DEFAULT_MAX_STATES;
 
         if let Some(data) = &data.data {
-            let parsed: Result<usize, serde_json::Error> = serde_json::from_value(data.clone());
-            match parsed {
-                Ok(val) => max_states = val,
-                Err(_) => {
-                    eprintln!("{} rule : bad config data", RULE_ID);
-                }
+            if let Ok(val) = serde_json::from_value::<usize>(data.clone()) {
+                max_states = val;
             }
-        } else {
-            eprintln!("{} rule : bad config data", RULE_ID);
         }
         let rule = MaxStatesCount { max_states, data };
         Box::new(rule)
@@ -114,4 +109,8 @@ impl MaxStatesCount {
             data: Some(DEFAULT_MAX_STATES.into()),
         }
     }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Integer
+    }
 }
@@ -1,20 +1,28 @@
-use crate::rules::types::{RuleEntry, RulesMap};
+use crate::rules::types::{DataSchemasMap, RuleEntry, RulesMap};
 use std::collections::HashMap;
 
 #[macro_use]
 pub mod max_line_length;
+pub mod constant_or_immutable;
 pub mod custom_errors;
+pub mod dead_code;
 pub mod explicit_types;
 pub mod function_max_lines;
 pub mod max_states_count;
 pub mod no_console;
 pub mod no_empty_block;
 pub mod no_global_import;
+pub mod no_unused_errors;
+pub mod no_unused_events;
+pub mod no_unused_functions;
+pub mod no_unused_import;
+pub mod no_unused_vars;
 pub mod one_contract_per_file;
 pub mod payable_fallback;
 pub mod reason_string;
 
 // List all rules
+use crate::rules::best_practises::constant_or_immutable::ConstantOrImmutable;
 use crate::rules::best_practises::custom_errors::CustomErrors;
 use crate::rules::best_practises::explicit_types::ExplicitTypes;
 use crate::rules::best_practises::function_max_lines::FunctionMaxLines;
@@ -23,6 +31,11 @@ use crate::rules::best_practises::max_states_count::MaxStatesCount;
 use crate::rules::best_practises::no_console::NoConsole;
 use crate::rules::best_practises::no_empty_block::NoEmptyBlock;
 use crate::rules::best_practises::no_global_import::NoGlobalImport;
+use crate::rules::best_practises::no_unused_errors::NoUnusedErrors;
+use crate::rules::best_practises::no_unused_events::NoUnusedEvents;
+use crate::rules::best_practises::no_unused_functions::NoUnusedFunctions;
+use crate::rules::best_practises::no_unused_import::NoUnusedImport;
+use crate::rules::best_practises::no_unused_vars::NoUnusedVars;
 use crate::rules::best_practises::one_contract_per_file::OneContractPerFile;
 use crate::rules::best_practises::payable_fallback::PayableFallback;
 use crate::rules::best_practises::reason_string::ReasonString;
@@ -41,6 +54,12 @@ pub fn create_default_rules() -> Vec<RuleEntry> {
         NoEmptyBlock::create_default(),
         ExplicitTypes::create_default(),
         PayableFallback::create_default(),
+        ConstantOrImmutable::create_default(),
+        NoUnusedVars::create_default(),
+        NoUnusedImport::create_default(),
+        NoUnusedFunctions::create_default(),
+        NoUnusedErrors::create_default(),
+        NoUnusedEvents::create_default(),
     ]
 }
 
@@ -74,6 +93,54 @@ pub fn create_rules() -> RulesMap {
         payable_fallback::RULE_ID.to_string(),
         PayableFallback::create,
     );
+    rules.insert(
+        constant_or_immutable::RULE_ID.to_string(),
+        ConstantOrImmutable::create,
+    );
+    rules.insert(no_unused_vars::RULE_ID.to_string(), NoUnusedVars::create);
+    rules.insert(
+        no_unused_import::RULE_ID.to_string(),
+        NoUnusedImport::create,
+    );
+    rules.insert(
+        no_unused_functions::RULE_ID.to_string(),
+        NoUnusedFunctions::create,
+    );
+    rules.insert(
+        no_unused_errors::RULE_ID.to_string(),
+        NoUnusedErrors::create,
+    );
+    rules.insert(
+        no_unused_events::RULE_ID.to_string(),
+        NoUnusedEvents::create,
+    );
 
     rules
 }
+
+pub fn create_data_schemas() -> DataSchemasMap {
+    let mut schemas = HashMap::new();
+
+    schemas.insert(
+        explicit_types::RULE_ID.to_string(),
+        ExplicitTypes::data_schema(),
+    );
+    schemas.insert(
+        function_max_lines::RULE_ID.to_string(),
+        FunctionMaxLines::data_schema(),
+    );
+    schemas.insert(
+        max_line_length::RULE_ID.to_string(),
+        MaxLineLength::data_schema(),
+    );
+    schemas.insert(
+        max_states_count::RULE_ID.to_string(),
+        MaxStatesCount::data_schema(),
+    );
+    schemas.insert(
+        reason_string::RULE_ID.to_string(),
+        ReasonString::data_schema(),
+    );
+
+    schemas
+}
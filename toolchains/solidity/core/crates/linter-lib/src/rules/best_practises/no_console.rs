@@ -1,7 +1,7 @@
 use osmium_libs_solidity_ast_extractor::*;
 
 use crate::linter::SolidFile;
-use crate::rules::types::{Example, Examples, RuleDocumentation, RuleEntry, RuleType};
+use crate::rules::types::{Example, Examples, LintContext, RuleDocumentation, RuleEntry, RuleType};
 use crate::types::{LintDiag, Position, Range, Severity};
 
 // global
@@ -37,13 +37,14 @@ impl NoConsole {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for NoConsole {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res: Vec<LintDiag> = Vec::new();
 
         // Check functions calls
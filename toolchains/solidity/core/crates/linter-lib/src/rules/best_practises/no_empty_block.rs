@@ -18,7 +18,7 @@ pub struct NoEmptyBlock {
 }
 
 impl RuleType for NoEmptyBlock {
-    fn diagnose(&self, _file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, _file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
         let _reports = check_empty_block(_file);
         for report in _reports.iter().flatten() {
@@ -28,6 +28,7 @@ impl RuleType for NoEmptyBlock {
                 range: report.clone(),
                 code: None,
                 source: None,
+                fix: None,
                 message: DEFAULT_MESSAGE.to_string(),
                 uri: _file.path.clone(),
             });
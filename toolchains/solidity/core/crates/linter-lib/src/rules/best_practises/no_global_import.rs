@@ -1,7 +1,13 @@
-use osmium_libs_solidity_ast_extractor::{ImportPath, Item, Spanned};
+use osmium_libs_solidity_ast_extractor::retriever::retrieve_import_directive_nodes;
+use osmium_libs_solidity_ast_extractor::{
+    ImportDirective, ImportGlob, ImportPath, ImportPlain, Item,
+};
+use std::collections::HashSet;
 
 use crate::linter::SolidFile;
+use crate::rules::best_practises::dead_code::{file_references, is_imported};
 use crate::rules::types::*;
+use crate::rules::utils::{range_from_spanned, ReferencesVisitor};
 use crate::types::*;
 
 // global
@@ -16,17 +22,18 @@ pub struct NoGlobalImport {
 }
 
 impl RuleType for NoGlobalImport {
-    fn diagnose(&self, _file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, _file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
 
-        let reports = check_global_import(_file);
-        for report in reports.into_iter().flatten() {
+        let reports = check_global_import(_file, _context);
+        for (report, fix) in reports {
             res.push(LintDiag {
                 id: RULE_ID.to_string(),
                 range: report,
                 severity: self.data.severity,
                 code: None,
                 source: None,
+                fix,
                 message: DEFAULT_MESSAGE.to_string(),
                 uri: _file.path.clone(),
             });
@@ -75,41 +82,88 @@ impl RuleType for NoGlobalImport {
     }
 }
 
-fn check_global_import(file: &SolidFile) -> Vec<Option<Range>> {
-    let mut reports: Vec<Option<Range>> = Vec::new();
-
-    file.data.items.iter().for_each(|item| {
-        if let Item::Import(import) = item {
-            if let ImportPath::Plain(plain) = &import.path {
-                if plain.alias.is_none() {
-                    reports.push(Some(Range {
-                        start: Position {
-                            line: plain.span().start().line,
-                            character: plain.span().start().column,
-                        },
-                        end: Position {
-                            line: plain.span().end().line,
-                            character: plain.span().end().column,
-                        },
-                    }));
+// The names the file binds itself, by its top-level declarations and its other imports
+fn bound_names(file: &SolidFile) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for item in &file.data.items {
+        match item {
+            Item::Import(import) => match &import.path {
+                ImportPath::Aliases(aliases) => {
+                    names.extend(aliases.imports.iter().map(|(name, alias)| {
+                        alias
+                            .as_ref()
+                            .map_or(name, |alias| &alias.alias)
+                            .as_string()
+                    }))
                 }
-            }
-            if let ImportPath::Glob(glob) = &import.path {
-                if glob.alias.is_none() {
-                    reports.push(Some(Range {
-                        start: Position {
-                            line: glob.span().start().line,
-                            character: glob.span().start().column,
-                        },
-                        end: Position {
-                            line: glob.span().end().line,
-                            character: glob.span().end().column,
-                        },
-                    }));
+                ImportPath::Plain(ImportPlain { alias, .. })
+                | ImportPath::Glob(ImportGlob { alias, .. }) => {
+                    names.extend(alias.iter().map(|alias| alias.alias.as_string()))
                 }
+            },
+            Item::Variable(var) => {
+                names.insert(var.name.as_string());
             }
+            item => names.extend(item.name().map(|name| name.as_string())),
+        }
+    }
+    names
+}
+
+// Import the names of the imported file used by the file, as in `import {A, B} from "..."`.
+// None if the names the imported file binds aren't all known, the file uses none of them,
+// or the files importing this one may rely on the names it binds.
+fn create_fix(
+    file: &SolidFile,
+    context: &LintContext,
+    import: &ImportDirective,
+    references: &ReferencesVisitor,
+) -> Option<Fix> {
+    if references.has_assembly || is_imported(file, context) {
+        return None;
+    }
+    let path = import.path.path().value();
+    let resolved = context.project.resolve_import(&file.path, &path)?;
+    let bound = bound_names(file);
+    let names: Vec<String> = context
+        .project
+        .exported_symbols(&resolved)?
+        .into_iter()
+        .filter(|name| references.idents.contains(name) && !bound.contains(name))
+        .collect();
+    if names.is_empty() {
+        return None;
+    }
+
+    let names = format!("{{{}}}", names.join(", "));
+    let edit = match &import.path {
+        ImportPath::Plain(plain) => {
+            TextEdit::insert(range_from_spanned(plain).start, format!("{} from ", names))
         }
-    });
+        ImportPath::Glob(glob) => TextEdit {
+            range: range_from_spanned(&glob.star_token),
+            new_text: names.clone(),
+        },
+        ImportPath::Aliases(_) => return None,
+    };
+    Some(Fix {
+        message: format!("Import {} from \"{}\"", names, path),
+        edits: vec![edit],
+    })
+}
+
+fn check_global_import(file: &SolidFile, context: &LintContext) -> Vec<(Range, Option<Fix>)> {
+    let mut reports = Vec::new();
+    let references = file_references(file);
+
+    for import in retrieve_import_directive_nodes(&file.data) {
+        let range = match &import.path {
+            ImportPath::Plain(plain) if plain.alias.is_none() => range_from_spanned(plain),
+            ImportPath::Glob(glob) if glob.alias.is_none() => range_from_spanned(glob),
+            _ => continue,
+        };
+        reports.push((range, create_fix(file, context, &import, &references)));
+    }
     reports
 }
 
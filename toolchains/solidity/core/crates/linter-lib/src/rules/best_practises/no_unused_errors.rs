@@ -0,0 +1,99 @@
+use crate::linter::SolidFile;
+use crate::rules::best_practises::dead_code::{
+    file_references, has_external_descendants, is_imported,
+};
+use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_errors_file_nodes, retrieve_errors_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "no-unused-errors";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct NoUnusedErrors {
+    data: RuleEntry,
+}
+
+impl NoUnusedErrors {
+    fn create_diag(&self, file: &SolidFile, error: &ItemError) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(&error.name),
+            message: format!("Custom error \"{}\" is never used", error.name.as_string()),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for NoUnusedErrors {
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let references = file_references(file);
+
+        // the errors of an interface are reverted by its implementations
+        let mut errors = Vec::new();
+        for contract in retrieve_contract_nodes(&file.data) {
+            if !contract.is_interface() && !has_external_descendants(file, context, &contract) {
+                errors.append(&mut retrieve_errors_nodes(&contract));
+            }
+        }
+        if !is_imported(file, context) {
+            errors.append(&mut retrieve_errors_file_nodes(&file.data));
+        }
+
+        for error in &errors {
+            if !references.is_referenced(&error.name.as_string()) {
+                res.push(self.create_diag(file, error));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Custom errors should be used. The errors of interfaces, of contracts derived in other files and of files imported by other files are not checked.".to_string(),
+            category: "best-practices".to_string(),
+            example_config: "{\"id\": \"no-unused-errors\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/no_unused_errors.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoUnusedErrors".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Error reverted".to_string(),
+                    code: "error Unauthorized();\n\nfunction withdraw() external {\n    if (msg.sender != owner) revert Unauthorized();\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Error never reverted".to_string(),
+                    code: "error Unauthorized();\n\nfunction withdraw() external {\n    require(msg.sender == owner);\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl NoUnusedErrors {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = NoUnusedErrors { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
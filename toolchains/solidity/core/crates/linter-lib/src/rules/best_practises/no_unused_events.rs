@@ -0,0 +1,99 @@
+use crate::linter::SolidFile;
+use crate::rules::best_practises::dead_code::{
+    file_references, has_external_descendants, is_imported,
+};
+use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_events_contract_nodes, retrieve_events_file_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "no-unused-events";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct NoUnusedEvents {
+    data: RuleEntry,
+}
+
+impl NoUnusedEvents {
+    fn create_diag(&self, file: &SolidFile, event: &ItemEvent) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(&event.name),
+            message: format!("Event \"{}\" is never emitted", event.name.as_string()),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for NoUnusedEvents {
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let references = file_references(file);
+
+        // the events of an interface are emitted by its implementations
+        let mut events = Vec::new();
+        for contract in retrieve_contract_nodes(&file.data) {
+            if !contract.is_interface() && !has_external_descendants(file, context, &contract) {
+                events.append(&mut retrieve_events_contract_nodes(&contract));
+            }
+        }
+        if !is_imported(file, context) {
+            events.append(&mut retrieve_events_file_nodes(&file.data));
+        }
+
+        for event in &events {
+            if !references.is_referenced(&event.name.as_string()) {
+                res.push(self.create_diag(file, event));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Events should be emitted. The events of interfaces, of contracts derived in other files and of files imported by other files are not checked.".to_string(),
+            category: "best-practices".to_string(),
+            example_config: "{\"id\": \"no-unused-events\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/no_unused_events.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoUnusedEvents".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Event emitted".to_string(),
+                    code: "event Deposit(address from, uint256 amount);\n\nfunction deposit() external payable {\n    emit Deposit(msg.sender, msg.value);\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Event never emitted".to_string(),
+                    code: "event Deposit(address from, uint256 amount);\n\nfunction deposit() external payable {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl NoUnusedEvents {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = NoUnusedEvents { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
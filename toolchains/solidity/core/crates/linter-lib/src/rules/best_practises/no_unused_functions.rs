@@ -0,0 +1,120 @@
+use crate::linter::SolidFile;
+use crate::rules::best_practises::dead_code::{
+    file_references, has_external_descendants, is_imported,
+};
+use crate::rules::types::*;
+use crate::rules::utils::{range_from_spanned, ReferencesVisitor};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "no-unused-functions";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct NoUnusedFunctions {
+    data: RuleEntry,
+}
+
+impl NoUnusedFunctions {
+    fn create_diag(&self, file: &SolidFile, name: &SolIdent, visibility: &str) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(name),
+            message: format!(
+                "{} function \"{}\" is never called",
+                visibility,
+                name.as_string()
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for NoUnusedFunctions {
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let file_references = file_references(file);
+
+        for contract in retrieve_contract_nodes(&file.data) {
+            let mut contract_references = ReferencesVisitor::default();
+            contract_references.visit_item_contract(&contract);
+            // the internal functions of libraries are called from the files importing them,
+            // the ones of contracts from their derived contracts
+            let internal_open = if contract.is_library() {
+                is_imported(file, context)
+            } else {
+                has_external_descendants(file, context, &contract)
+            };
+
+            for function in retrieve_functions_nodes(&contract) {
+                let name = match (&function.kind, &function.name) {
+                    (FunctionKind::Function(_), Some(name)) => name,
+                    _ => continue,
+                };
+                // overriding functions are called by their base contracts
+                if function.attributes.r#override().is_some() {
+                    continue;
+                }
+                let name_str = name.as_string();
+                if function.attributes.has_private() {
+                    if !contract_references.is_referenced(&name_str) {
+                        res.push(self.create_diag(file, name, "Private"));
+                    }
+                } else if function.attributes.has_internal()
+                    && !internal_open
+                    && !file_references.is_referenced(&name_str)
+                {
+                    res.push(self.create_diag(file, name, "Internal"));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Private and internal functions should be called. The internal functions of contracts derived in other files, and of libraries imported by other files, are not checked.".to_string(),
+            category: "best-practices".to_string(),
+            example_config: "{\"id\": \"no-unused-functions\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/no_unused_functions.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoUnusedFunctions".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Private function called".to_string(),
+                    code: "function withdraw() external {\n    _send(msg.sender);\n}\n\nfunction _send(address to) private {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Private function never called".to_string(),
+                    code: "function withdraw() external {}\n\nfunction _send(address to) private {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl NoUnusedFunctions {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = NoUnusedFunctions { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::linter::SolidFile;
+use crate::rules::best_practises::dead_code::file_references;
+use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::retrieve_import_directive_nodes;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "no-unused-import";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct NoUnusedImport {
+    data: RuleEntry,
+}
+
+// The names an import directive binds in the file: the symbols or their aliases, and
+// the alias of the whole imported file
+fn imported_names(import: &ImportDirective) -> Vec<&SolIdent> {
+    match &import.path {
+        ImportPath::Aliases(aliases) => aliases
+            .imports
+            .iter()
+            .map(|(name, alias)| alias.as_ref().map_or(name, |alias| &alias.alias))
+            .collect(),
+        ImportPath::Plain(ImportPlain { alias, .. })
+        | ImportPath::Glob(ImportGlob { alias, .. }) => {
+            alias.iter().map(|alias| &alias.alias).collect()
+        }
+    }
+}
+
+impl NoUnusedImport {
+    fn create_diag(&self, file: &SolidFile, name: &SolIdent) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(name),
+            message: format!("Imported name \"{}\" is never used", name.as_string()),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for NoUnusedImport {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let references = file_references(file);
+
+        for import in retrieve_import_directive_nodes(&file.data) {
+            for name in imported_names(&import) {
+                if !references.idents.contains(&name.as_string()) {
+                    res.push(self.create_diag(file, name));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "The symbols imported by name, and the aliases of imported files, should be used in the file.".to_string(),
+            category: "best-practices".to_string(),
+            example_config: "{\"id\": \"no-unused-import\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/no_unused_import.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoUnusedImport".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Imported symbol used".to_string(),
+                    code: "import {ERC20} from \"./ERC20.sol\";\n\ncontract Token is ERC20 {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Imported symbol never used".to_string(),
+                    code: "import {ERC20, IERC20} from \"./ERC20.sol\";\n\ncontract Token is ERC20 {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl NoUnusedImport {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = NoUnusedImport { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
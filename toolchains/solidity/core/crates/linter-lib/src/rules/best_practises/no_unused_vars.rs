@@ -0,0 +1,134 @@
+use crate::linter::SolidFile;
+use crate::rules::best_practises::dead_code::free_functions;
+use crate::rules::types::*;
+use crate::rules::utils::{range_from_spanned, ReferencesVisitor};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "no-unused-vars";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+
+pub struct NoUnusedVars {
+    data: RuleEntry,
+}
+
+// The local variables declared in a block
+#[derive(Default)]
+struct DeclarationsVisitor {
+    names: Vec<SolIdent>,
+}
+
+impl<'ast> Visit<'ast> for DeclarationsVisitor {
+    fn visit_variable_declaration(&mut self, var: &'ast VariableDeclaration) {
+        if let Some(name) = &var.name {
+            self.names.push(name.clone());
+        }
+    }
+}
+
+impl NoUnusedVars {
+    fn create_diag(&self, file: &SolidFile, name: &SolIdent, kind: &str) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(name),
+            message: format!("{} \"{}\" is never used", kind, name.as_string()),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    fn check_function(&self, file: &SolidFile, function: &ItemFunction) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let block = match &function.body {
+            FunctionBody::Block(block) => block,
+            FunctionBody::Empty(_) => return res,
+        };
+        let mut references = ReferencesVisitor::default();
+        references.visit_item_function(function);
+        if references.has_assembly {
+            return res;
+        }
+
+        // the parameters of empty functions, such as hooks to override, are expected
+        if !block.stmts.is_empty() {
+            for param in function.arguments.iter() {
+                if let Some(name) = &param.name {
+                    if !references.idents.contains(&name.as_string()) {
+                        res.push(self.create_diag(file, name, "Parameter"));
+                    }
+                }
+            }
+        }
+        let mut declarations = DeclarationsVisitor::default();
+        declarations.visit_block(block);
+        for name in &declarations.names {
+            if !references.idents.contains(&name.as_string()) {
+                res.push(self.create_diag(file, name, "Variable"));
+            }
+        }
+        res
+    }
+}
+
+impl RuleType for NoUnusedVars {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retrieve_contract_nodes(&file.data) {
+            for function in retrieve_functions_nodes(&contract) {
+                res.append(&mut self.check_function(file, &function));
+            }
+        }
+        for function in free_functions(file) {
+            res.append(&mut self.check_function(file, function));
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Local variables and function parameters should be used. The parameters of functions with an empty body and the functions containing assembly are not checked.".to_string(),
+            category: "best-practices".to_string(),
+            example_config: "{\"id\": \"no-unused-vars\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/best_practises/no_unused_vars.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoUnusedVars".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Unnamed parameter".to_string(),
+                    code: "function transfer(address to, uint256) external {\n    emit Transfer(to);\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Variable never used".to_string(),
+                    code: "function transfer(address to) external {\n    uint256 amount = 1;\n    emit Transfer(to);\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl NoUnusedVars {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = NoUnusedVars { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -19,7 +19,7 @@ pub struct PayableFallback {
 }
 
 impl RuleType for PayableFallback {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
         let reports = check_fallback_payable(file);
 
@@ -30,6 +30,7 @@ impl RuleType for PayableFallback {
                 range: report,
                 code: None,
                 source: None,
+                fix: None,
                 message: DEFAULT_MESSAGE.to_string(),
                 uri: file.path.clone(),
             });
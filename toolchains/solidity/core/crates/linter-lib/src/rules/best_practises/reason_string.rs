@@ -1,8 +1,11 @@
 use osmium_libs_solidity_ast_extractor::*;
 
 use crate::linter::SolidFile;
-use crate::rules::types::{Examples, Options, RuleDocumentation, RuleEntry, RuleType};
-use crate::types::{LintDiag, Position, Range, Severity};
+use crate::rules::types::{
+    DataSchema, Examples, LintContext, Options, RuleDocumentation, RuleEntry, RuleType,
+};
+use crate::rules::utils::{range_from_spanned, text_from_range};
+use crate::types::{Fix, LintDiag, Position, Range, Severity, TextEdit};
 
 // global
 pub const RULE_ID: &str = "reason-string";
@@ -22,6 +25,7 @@ impl ReasonString {
         file: &SolidFile,
         location: (LineColumn, LineColumn),
         message: String,
+        fix: Option<Fix>,
     ) -> LintDiag {
         LintDiag {
             id: RULE_ID.to_string(),
@@ -39,13 +43,36 @@ impl ReasonString {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix,
             uri: file.path.clone(),
         }
     }
+
+    // Use the checked condition as the reason string, e.g. require(a > b) -> require(a > b, "a > b")
+    fn create_fix(&self, file: &SolidFile, call: &ExprCall, condition: &Expr) -> Option<Fix> {
+        let condition = text_from_range(&file.content, &range_from_spanned(condition))?;
+        let reason: String = condition
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .replace('\\', "")
+            .replace('"', "'")
+            .chars()
+            .take(self.max_length)
+            .collect();
+        let mut position = range_from_spanned(&call.args).end;
+        // insert before the closing parenthesis
+        position.character = position.character.checked_sub(1)?;
+
+        Some(Fix {
+            message: "Add a reason string".to_string(),
+            edits: vec![TextEdit::insert(position, format!(", \"{}\"", reason))],
+        })
+    }
 }
 
 impl RuleType for ReasonString {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
 
         for contract in retriever::retrieve_contract_nodes(&file.data) {
@@ -57,7 +84,7 @@ impl RuleType for ReasonString {
                                 && string.values[0].value().len() > self.max_length
                             {
                                 let location = (string.span().start(), string.span().end());
-                                res.push(self.create_diag(file, location, format!("Error message for revert is too long. Should be less than {} characters", self.max_length)));
+                                res.push(self.create_diag(file, location, format!("Error message for revert is too long. Should be less than {} characters", self.max_length), None));
                             }
                         } else {
                             let location = (
@@ -68,6 +95,7 @@ impl RuleType for ReasonString {
                                 file,
                                 location,
                                 "Provide an error message for revert".to_string(),
+                                None,
                             ));
                         }
                     }
@@ -107,16 +135,25 @@ impl RuleType for ReasonString {
                                                         "Error message for revert is too long. Should be less than {} characters",
                                                         self.max_length
                                                     ),
+                                                    None,
                                                 ),
                                             );
                                         }
                                     }
                                 } else {
                                     let location = (ident.0.span().start(), ident.0.span().end());
+                                    // assert does not accept a reason string
+                                    let fix = match expr_args.len() {
+                                        1 if *ident == "require" => {
+                                            self.create_fix(file, call, &expr_args[0])
+                                        }
+                                        _ => None,
+                                    };
                                     res.push(self.create_diag(
                                         file,
                                         location,
                                         "Provide an error message for revert".to_string(),
+                                        fix,
                                     ));
                                 }
                             }
@@ -152,15 +189,9 @@ impl ReasonString {
         let mut max_length = DEFAULT_LENGTH;
 
         if let Some(data) = &data.data {
-            let parsed: Result<usize, serde_json::Error> = serde_json::from_value(data.clone());
-            match parsed {
-                Ok(val) => max_length = val,
-                Err(_) => {
-                    eprintln!("{} rule : bad config data", RULE_ID);
-                }
+            if let Ok(val) = serde_json::from_value::<usize>(data.clone()) {
+                max_length = val;
             }
-        } else {
-            eprintln!("{} rule : bad config data", RULE_ID);
         }
         let rule = ReasonString { max_length, data };
         Box::new(rule)
@@ -173,4 +204,8 @@ impl ReasonString {
             data: Some(DEFAULT_LENGTH.into()),
         }
     }
+
+    pub fn data_schema() -> DataSchema {
+        DataSchema::Integer
+    }
 }
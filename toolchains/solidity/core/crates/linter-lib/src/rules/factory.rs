@@ -1,5 +1,7 @@
+use crate::errors::{ConfigError, SolidHunterError};
 use crate::rules::create_rules;
 use crate::rules::types::*;
+use crate::rules::validation::closest_match;
 use std::collections::HashMap;
 
 pub struct RuleFactory {
@@ -21,11 +23,17 @@ impl RuleFactory {
         }
     }
 
-    pub fn create_rule(&self, rule: RuleEntry) -> Box<dyn RuleType> {
-        let rule_type = self._buildables.get(&rule.id);
-        if rule_type.is_none() {
-            panic!("Rule {} not found", &rule.id);
+    pub fn create_rule(&self, rule: RuleEntry) -> Result<Box<dyn RuleType>, SolidHunterError> {
+        match self._buildables.get(&rule.id) {
+            Some(create) => Ok(create(rule)),
+            None => {
+                let ids: Vec<&str> = self._buildables.keys().map(String::as_str).collect();
+                Err(SolidHunterError::ConfigError(vec![ConfigError {
+                    path: "$.rules".to_string(),
+                    message: format!("unknown rule \"{}\"", rule.id),
+                    suggestion: closest_match(&rule.id, &ids).map(str::to_string),
+                }]))
+            }
         }
-        rule_type.unwrap()(rule)
     }
 }
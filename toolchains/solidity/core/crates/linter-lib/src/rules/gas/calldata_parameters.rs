@@ -0,0 +1,111 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{range_from_spanned, WritesVisitor};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-calldata-parameters";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+pub struct GasCalldataParameters {
+    data: RuleEntry,
+}
+
+impl GasCalldataParameters {
+    fn create_diag(&self, file: &SolidFile, param: &SolIdent, location: &Storage) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(param),
+            message: format!(
+                "Parameter \"{}\" is never modified, declare it as calldata instead of memory",
+                param.as_string()
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: Some(Fix {
+                message: "Use calldata".to_string(),
+                edits: vec![TextEdit::replace(
+                    range_from_spanned(location),
+                    "calldata".to_string(),
+                )],
+            }),
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasCalldataParameters {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retrieve_contract_nodes(&file.data) {
+            for function in retrieve_functions_nodes(&contract) {
+                let body = match &function.body {
+                    FunctionBody::Block(body) => body,
+                    FunctionBody::Empty(_) => continue,
+                };
+                if !function.attributes.has_external() {
+                    continue;
+                }
+                let mut writes = WritesVisitor::default();
+                writes.visit_block(body);
+
+                for param in function.arguments.iter() {
+                    if let (Some(location @ Storage::Memory(_)), Some(name)) =
+                        (&param.storage, &param.name)
+                    {
+                        if !writes.is_written(&name.as_string()) {
+                            res.push(self.create_diag(file, name, location));
+                        }
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Parameters of external functions that are never modified should be declared as calldata, which avoids copying them to memory.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-calldata-parameters\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/calldata_parameters.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasCalldataParameters".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Read-only parameter in calldata".to_string(),
+                    code: "function sum(uint256[] calldata values) external pure returns (uint256);".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Read-only parameter copied to memory".to_string(),
+                    code: "function sum(uint256[] memory values) external pure returns (uint256);".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasCalldataParameters {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasCalldataParameters { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::linter::SolidFile;
+use crate::rules::best_practises::constant_or_immutable::find_candidates;
+use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-immutable-candidates";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+pub struct GasImmutableCandidates {
+    data: RuleEntry,
+}
+
+impl GasImmutableCandidates {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        var: &VariableDefinition,
+        reason: &str,
+        keyword: &str,
+    ) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(&var.name),
+            message: format!(
+                "State variable \"{}\" {}, declare it as {} to avoid reading storage",
+                var.name.as_string(),
+                reason,
+                keyword
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasImmutableCandidates {
+    // the variables are the ones of `constant-or-immutable`, reported for their gas cost
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        find_candidates(file, context)
+            .iter()
+            .map(|candidate| {
+                self.create_diag(file, &candidate.var, candidate.reason, candidate.keyword)
+            })
+            .collect()
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "State variables that are never modified should be constant, and the ones only written in the constructor immutable, their value being embedded in the bytecode instead of read from storage. The variables are the ones reported by constant-or-immutable, so this rule is not enabled by the presets, to only report them once.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-immutable-candidates\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/immutable_candidates.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasImmutableCandidates".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Owner set in the constructor".to_string(),
+                    code: "address private immutable owner;\n\nconstructor() {\n    owner = msg.sender;\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Owner stored in a storage slot".to_string(),
+                    code: "address private owner;\n\nconstructor() {\n    owner = msg.sender;\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasImmutableCandidates {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasImmutableCandidates { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
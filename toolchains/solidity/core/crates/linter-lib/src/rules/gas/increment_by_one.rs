@@ -0,0 +1,115 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{normalize_member_access, range_from_spanned, text_from_range};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::retrieve_contract_nodes;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-increment-by-one";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+pub struct GasIncrementByOne {
+    data: RuleEntry,
+}
+
+// The postfix increments and decrements of the for loops
+#[derive(Default)]
+struct LoopsVisitor {
+    postfixes: Vec<ExprPostfix>,
+}
+
+impl<'ast> Visit<'ast> for LoopsVisitor {
+    fn visit_stmt_for(&mut self, stmt_for: &'ast StmtFor) {
+        if let Some(post) = &stmt_for.post {
+            if let Expr::Postfix(postfix) = normalize_member_access(post) {
+                self.postfixes.push(postfix);
+            }
+        }
+        visit::visit_stmt_for(self, stmt_for);
+    }
+}
+
+impl GasIncrementByOne {
+    fn create_diag(&self, file: &SolidFile, postfix: &ExprPostfix) -> Option<LintDiag> {
+        let operand = text_from_range(&file.content, &range_from_spanned(&*postfix.expr))?;
+        let op = postfix.op.as_str();
+        let prefix = format!("{}{}", op, operand);
+        // the span of the two characters operator only covers the first one
+        let mut range = range_from_spanned(&*postfix.expr);
+        range.end = range_from_spanned(&postfix.op).start;
+        range.end.character += op.len();
+
+        Some(LintDiag {
+            id: RULE_ID.to_string(),
+            range: range.clone(),
+            message: format!(
+                "Use \"{}\" instead of \"{}{}\" to avoid keeping a copy of the previous value",
+                prefix, operand, op
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: Some(Fix {
+                message: format!("Replace with \"{}\"", prefix),
+                edits: vec![TextEdit::replace(range, prefix)],
+            }),
+            uri: file.path.clone(),
+        })
+    }
+}
+
+impl RuleType for GasIncrementByOne {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let mut visitor = LoopsVisitor::default();
+
+        for contract in retrieve_contract_nodes(&file.data) {
+            visitor.visit_item_contract(&contract);
+        }
+        visitor
+            .postfixes
+            .iter()
+            .filter_map(|postfix| self.create_diag(file, postfix))
+            .collect()
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Loop counters should be updated with a prefix increment or decrement, cheaper than the postfix one which keeps the previous value.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-increment-by-one\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/increment_by_one.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasIncrementByOne".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Prefix increment".to_string(),
+                    code: "for (uint256 i = 0; i < length; ++i) {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Postfix increment".to_string(),
+                    code: "for (uint256 i = 0; i < length; i++) {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasIncrementByOne {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasIncrementByOne { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{normalize_member_access, range_from_spanned, text_from_range};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::retrieve_contract_nodes;
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-length-in-loops";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+pub struct GasLengthInLoops {
+    data: RuleEntry,
+}
+
+// The `.length` accesses of a loop condition
+#[derive(Default)]
+struct LengthVisitor {
+    lengths: Vec<Range>,
+}
+
+impl<'ast> Visit<'ast> for LengthVisitor {
+    fn visit_expr_member(&mut self, member: &'ast ExprMember) {
+        if let Expr::Ident(name) = &*member.member {
+            if name == "length" {
+                self.lengths.push(range_from_spanned(member));
+            }
+        }
+        visit::visit_expr_member(self, member);
+    }
+}
+
+#[derive(Default)]
+struct LoopsVisitor {
+    lengths: Vec<Range>,
+}
+
+impl LoopsVisitor {
+    fn check_cond(&mut self, cond: &Expr) {
+        let mut visitor = LengthVisitor::default();
+        visitor.visit_expr(&normalize_member_access(cond));
+        self.lengths.append(&mut visitor.lengths);
+    }
+}
+
+impl<'ast> Visit<'ast> for LoopsVisitor {
+    fn visit_stmt_for(&mut self, stmt_for: &'ast StmtFor) {
+        if let Some(cond) = &stmt_for.cond {
+            self.check_cond(cond);
+        }
+        visit::visit_stmt_for(self, stmt_for);
+    }
+
+    fn visit_stmt_while(&mut self, stmt_while: &'ast StmtWhile) {
+        self.check_cond(&stmt_while.cond);
+        visit::visit_stmt_while(self, stmt_while);
+    }
+
+    fn visit_stmt_dowhile(&mut self, stmt_dowhile: &'ast StmtDoWhile) {
+        self.check_cond(&stmt_dowhile.cond);
+        visit::visit_stmt_dowhile(self, stmt_dowhile);
+    }
+}
+
+impl GasLengthInLoops {
+    fn create_diag(&self, file: &SolidFile, range: Range) -> LintDiag {
+        let length = text_from_range(&file.content, &range).unwrap_or("length".to_string());
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range,
+            message: format!(
+                "\"{}\" is read at each iteration, cache it in a local variable before the loop",
+                length
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasLengthInLoops {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let mut visitor = LoopsVisitor::default();
+
+        for contract in retrieve_contract_nodes(&file.data) {
+            visitor.visit_item_contract(&contract);
+        }
+        visitor
+            .lengths
+            .into_iter()
+            .map(|range| self.create_diag(file, range))
+            .collect()
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "The length of an array should be cached before a loop rather than read in its condition at each iteration.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-length-in-loops\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/length_in_loops.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasLengthInLoops".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Length cached before the loop".to_string(),
+                    code: "uint256 length = values.length;\nfor (uint256 i = 0; i < length; ++i) {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Length read at each iteration".to_string(),
+                    code: "for (uint256 i = 0; i < values.length; ++i) {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasLengthInLoops {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasLengthInLoops { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
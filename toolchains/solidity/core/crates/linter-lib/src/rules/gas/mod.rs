@@ -0,0 +1,68 @@
+use crate::rules::types::{DataSchemasMap, RuleEntry, RulesMap};
+use std::collections::HashMap;
+
+pub(crate) mod calldata_parameters;
+pub(crate) mod immutable_candidates;
+pub(crate) mod increment_by_one;
+pub(crate) mod length_in_loops;
+pub(crate) mod not_equal_zero;
+pub(crate) mod storage;
+pub(crate) mod struct_packing;
+
+// List all rules
+use crate::rules::gas::calldata_parameters::GasCalldataParameters;
+use crate::rules::gas::immutable_candidates::GasImmutableCandidates;
+use crate::rules::gas::increment_by_one::GasIncrementByOne;
+use crate::rules::gas::length_in_loops::GasLengthInLoops;
+use crate::rules::gas::not_equal_zero::GasNotEqualZero;
+use crate::rules::gas::struct_packing::GasStructPacking;
+
+use crate::rules::RuleBuilder;
+
+pub fn create_default_rules() -> Vec<RuleEntry> {
+    vec![
+        GasCalldataParameters::create_default(),
+        GasLengthInLoops::create_default(),
+        GasIncrementByOne::create_default(),
+        GasStructPacking::create_default(),
+        GasNotEqualZero::create_default(),
+    ]
+}
+
+// gas-immutable-candidates reports the variables of constant-or-immutable, so it is only
+// enabled by the `all` preset and its id
+pub fn create_opt_in_rules() -> Vec<RuleEntry> {
+    vec![GasImmutableCandidates::create_default()]
+}
+
+pub fn create_rules() -> RulesMap {
+    let mut rules: HashMap<String, RuleBuilder> = HashMap::new();
+
+    rules.insert(
+        calldata_parameters::RULE_ID.to_string(),
+        GasCalldataParameters::create,
+    );
+    rules.insert(
+        length_in_loops::RULE_ID.to_string(),
+        GasLengthInLoops::create,
+    );
+    rules.insert(
+        increment_by_one::RULE_ID.to_string(),
+        GasIncrementByOne::create,
+    );
+    rules.insert(
+        immutable_candidates::RULE_ID.to_string(),
+        GasImmutableCandidates::create,
+    );
+    rules.insert(
+        struct_packing::RULE_ID.to_string(),
+        GasStructPacking::create,
+    );
+    rules.insert(not_equal_zero::RULE_ID.to_string(), GasNotEqualZero::create);
+
+    rules
+}
+
+pub fn create_data_schemas() -> DataSchemasMap {
+    HashMap::new()
+}
@@ -0,0 +1,209 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{range_from_spanned, visit_normalized_expr, NormalizedVisit};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+use std::collections::HashSet;
+
+// global
+pub const RULE_ID: &str = "gas-not-equal-zero";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+const UNSIGNED_MEMBERS: [&str; 2] = ["length", "balance"];
+
+pub struct GasNotEqualZero {
+    data: RuleEntry,
+}
+
+// The first operand of a condition, syn_solidity reading `a > 0 && b` as `a > (0 && b)`
+fn first_operand(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Binary(binary) if matches!(binary.op, BinOp::And(..) | BinOp::Or(..)) => {
+            first_operand(&binary.left)
+        }
+        Expr::Ternary(ternary) => first_operand(&ternary.cond),
+        expr => expr,
+    }
+}
+
+fn is_zero(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(Lit::Number(number)) => number.base10_digits() == "0",
+        _ => false,
+    }
+}
+
+// The comparisons of unsigned values with zero in a function
+struct ComparisonsVisitor<'a> {
+    unsigned_vars: &'a HashSet<String>,
+    comparisons: Vec<BinOp>,
+    in_expr: bool,
+}
+
+impl<'a> ComparisonsVisitor<'a> {
+    fn is_unsigned(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Ident(ident) => self.unsigned_vars.contains(&ident.as_string()),
+            Expr::Member(member) => match (&*member.expr, &*member.member) {
+                (Expr::Ident(base), Expr::Ident(name)) if base == "msg" => name == "value",
+                (_, Expr::Ident(name)) => UNSIGNED_MEMBERS.contains(&name.as_string().as_str()),
+                _ => false,
+            },
+            Expr::Call(call) => matches!(&*call.expr, Expr::Type(Type::Uint(..))),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> NormalizedVisit for ComparisonsVisitor<'a> {
+    fn in_normalized_expr(&mut self) -> &mut bool {
+        &mut self.in_expr
+    }
+}
+
+impl<'a, 'ast> Visit<'ast> for ComparisonsVisitor<'a> {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        visit_normalized_expr(self, expr);
+    }
+
+    fn visit_expr_binary(&mut self, binary: &'ast ExprBinary) {
+        let compared = match binary.op {
+            BinOp::Gt(..) => {
+                self.is_unsigned(&binary.left) && is_zero(first_operand(&binary.right))
+            }
+            BinOp::Lt(..) => {
+                is_zero(&binary.left) && self.is_unsigned(first_operand(&binary.right))
+            }
+            _ => false,
+        };
+        if compared {
+            self.comparisons.push(binary.op);
+        }
+        visit::visit_expr_binary(self, binary);
+    }
+}
+
+// The unsigned integers declared in a function, the others shadowing the state variables
+#[derive(Default)]
+struct DeclarationsVisitor {
+    unsigned: Vec<String>,
+    others: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for DeclarationsVisitor {
+    fn visit_variable_declaration(&mut self, var: &'ast VariableDeclaration) {
+        if let Some(name) = &var.name {
+            match var.ty {
+                Type::Uint(..) => self.unsigned.push(name.as_string()),
+                _ => self.others.push(name.as_string()),
+            }
+        }
+    }
+}
+
+impl GasNotEqualZero {
+    fn create_diag(&self, file: &SolidFile, op: &BinOp) -> LintDiag {
+        let range = range_from_spanned(op);
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range.clone(),
+            message: format!(
+                "Compare unsigned values to zero with \"!=\" instead of \"{}\"",
+                op.as_str()
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: Some(Fix {
+                message: "Replace with \"!=\"".to_string(),
+                edits: vec![TextEdit::replace(range, "!=".to_string())],
+            }),
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasNotEqualZero {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retrieve_contract_nodes(&file.data) {
+            let state_vars: Vec<String> = contract
+                .body
+                .iter()
+                .filter_map(|item| match item {
+                    Item::Variable(var) if matches!(var.ty, Type::Uint(..)) => {
+                        Some(var.name.as_string())
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            for function in retrieve_functions_nodes(&contract) {
+                let mut declarations = DeclarationsVisitor::default();
+                declarations.visit_item_function(&function);
+                let unsigned_vars: HashSet<String> = state_vars
+                    .iter()
+                    .filter(|name| !declarations.others.contains(name))
+                    .chain(declarations.unsigned.iter())
+                    .cloned()
+                    .collect();
+
+                let mut visitor = ComparisonsVisitor {
+                    unsigned_vars: &unsigned_vars,
+                    comparisons: Vec::new(),
+                    in_expr: false,
+                };
+                if let FunctionBody::Block(body) = &function.body {
+                    visitor.visit_block(body);
+                }
+                for op in &visitor.comparisons {
+                    res.push(self.create_diag(file, op));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Unsigned values should be compared to zero with `!=` rather than `>`, which costs more gas for the same result.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-not-equal-zero\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/not_equal_zero.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasNotEqualZero".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Inequality with zero".to_string(),
+                    code: "require(amount != 0);".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Strict comparison with zero".to_string(),
+                    code: "require(amount > 0);".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasNotEqualZero {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasNotEqualZero { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
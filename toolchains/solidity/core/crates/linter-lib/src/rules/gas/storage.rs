@@ -0,0 +1,72 @@
+use crate::linter::SolidFile;
+use crate::project::ContractType;
+use crate::rules::types::LintContext;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_enums_contract_nodes, retrieve_enums_file_nodes,
+};
+use osmium_libs_solidity_ast_extractor::Type;
+use std::collections::HashSet;
+
+pub const SLOT_SIZE: usize = 32;
+
+/// The user-defined types whose values can be packed in a storage slot: the enums of the
+/// file and the contracts and interfaces of the project.
+pub struct PackedTypes {
+    enums: HashSet<String>,
+    contracts: HashSet<String>,
+}
+
+impl PackedTypes {
+    pub fn new(file: &SolidFile, context: &LintContext) -> Self {
+        let contracts = retrieve_contract_nodes(&file.data);
+        let mut enums: HashSet<String> = retrieve_enums_file_nodes(&file.data)
+            .iter()
+            .map(|item| item.name.as_string())
+            .collect();
+        for contract in &contracts {
+            enums.extend(
+                retrieve_enums_contract_nodes(contract)
+                    .iter()
+                    .map(|item| item.name.as_string()),
+            );
+        }
+        let contracts = context
+            .project
+            .files()
+            .flat_map(|summary| &summary.contracts)
+            .filter(|contract| contract.kind != ContractType::Library)
+            .map(|contract| contract.name.clone())
+            .chain(
+                contracts
+                    .iter()
+                    .filter(|contract| !contract.is_library())
+                    .map(|contract| contract.name.as_string()),
+            )
+            .collect();
+        PackedTypes { enums, contracts }
+    }
+
+    /// The number of bytes taken in storage by a value of the type, or none if it uses
+    /// whole slots of its own.
+    pub fn size(&self, ty: &Type) -> Option<usize> {
+        match ty {
+            Type::Bool(_) => Some(1),
+            Type::Address(..) => Some(20),
+            Type::FixedBytes(_, size) => Some(size.get() as usize),
+            Type::Int(_, size) | Type::Uint(_, size) => {
+                Some(size.map_or(SLOT_SIZE, |size| size.get() as usize / 8))
+            }
+            Type::Custom(path) => {
+                let name = path.last().as_string();
+                if self.enums.contains(&name) {
+                    Some(1)
+                } else if self.contracts.contains(&name) {
+                    Some(20)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::linter::SolidFile;
+use crate::rules::gas::storage::{PackedTypes, SLOT_SIZE};
+use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_structs_contract_nodes, retrieve_structs_file_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "gas-struct-packing";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::INFO;
+
+pub struct GasStructPacking {
+    data: RuleEntry,
+}
+
+struct Field {
+    name: String,
+    size: Option<usize>,
+}
+
+// Number of slots used by the fields stored in order, the fields that do not fit in the
+// remaining bytes of a slot starting a new one
+fn used_slots(fields: &[&Field]) -> usize {
+    let mut slots = 0;
+    let mut used = SLOT_SIZE;
+
+    for field in fields {
+        match field.size {
+            Some(size) if used + size <= SLOT_SIZE => used += size,
+            Some(size) => {
+                slots += 1;
+                used = size;
+            }
+            None => {
+                slots += 1;
+                used = SLOT_SIZE;
+            }
+        }
+    }
+    slots
+}
+
+// An order of the fields packing them in fewer slots: the packed fields sorted by
+// decreasing size, each one in the first slot with enough room, then the others
+fn packed_order(fields: &[Field]) -> Vec<&Field> {
+    let mut packed: Vec<&Field> = fields.iter().filter(|f| f.size.is_some()).collect();
+    packed.sort_by_key(|field| std::cmp::Reverse(field.size));
+
+    let mut slots: Vec<(usize, Vec<&Field>)> = Vec::new();
+    for field in packed {
+        let size = field.size.unwrap_or(SLOT_SIZE);
+        match slots.iter_mut().find(|(used, _)| used + size <= SLOT_SIZE) {
+            Some((used, slot)) => {
+                *used += size;
+                slot.push(field);
+            }
+            None => slots.push((size, vec![field])),
+        }
+    }
+    slots
+        .into_iter()
+        .flat_map(|(_, slot)| slot)
+        .chain(fields.iter().filter(|f| f.size.is_none()))
+        .collect()
+}
+
+impl GasStructPacking {
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        item: &ItemStruct,
+        saved: usize,
+        order: &[&Field],
+    ) -> LintDiag {
+        let names: Vec<&str> = order.iter().map(|field| field.name.as_str()).collect();
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(&item.name),
+            message: format!(
+                "Struct \"{}\" could use {} fewer storage slot(s) with its fields ordered as: {}",
+                item.name.as_string(),
+                saved,
+                names.join(", ")
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for GasStructPacking {
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let types = PackedTypes::new(file, context);
+        let mut structs = retrieve_structs_file_nodes(&file.data);
+        for contract in retrieve_contract_nodes(&file.data) {
+            structs.append(&mut retrieve_structs_contract_nodes(&contract));
+        }
+
+        for item in &structs {
+            let fields: Vec<Field> = item
+                .fields
+                .iter()
+                .map(|field| Field {
+                    name: field
+                        .name
+                        .as_ref()
+                        .map(|name| name.as_string())
+                        .unwrap_or_default(),
+                    size: types.size(&field.ty),
+                })
+                .collect();
+            let current = used_slots(&fields.iter().collect::<Vec<_>>());
+            let order = packed_order(&fields);
+            let packed = used_slots(&order);
+
+            if packed < current {
+                res.push(self.create_diag(file, item, current - packed, &order));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Struct fields should be ordered so that the ones smaller than 32 bytes share storage slots, reducing the number of slots read and written.".to_string(),
+            category: "gas".to_string(),
+            example_config: "{\"id\": \"gas-struct-packing\", \"severity\": \"INFO\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/gas/struct_packing.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/GasStructPacking".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Address and boolean sharing a slot".to_string(),
+                    code: "struct Deposit {\n    address owner;\n    bool locked;\n    uint256 amount;\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Three slots used where two are enough".to_string(),
+                    code: "struct Deposit {\n    address owner;\n    uint256 amount;\n    bool locked;\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl GasStructPacking {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = GasStructPacking { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::linter::SolidFile;
+use crate::rules::miscellaneous::version::{solidity_pragmas, VersionRange};
+use crate::rules::types::*;
+use crate::types::*;
+
+// global
+pub const RULE_ID: &str = "compiler-version";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::ERROR;
+const DEFAULT_REQUIREMENT: &str = "^0.8.0";
+
+pub struct CompilerVersion {
+    requirement: String,
+    allowed: Option<VersionRange>,
+    data: RuleEntry,
+}
+
+impl CompilerVersion {
+    fn create_diag(&self, file: &SolidFile, range: Range, message: String) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range,
+            message,
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    fn check_version(&self, version: &str) -> Option<String> {
+        let min_version = match VersionRange::parse(version) {
+            Some(range) => range.min_version(),
+            None => return Some(format!("Invalid compiler version \"{}\"", version)),
+        };
+        match (min_version, &self.allowed) {
+            (Some(min_version), Some(allowed)) if allowed.matches(&min_version) => None,
+            // an invalid requirement disables the rule rather than reporting every file
+            (Some(_), None) => None,
+            _ => Some(format!(
+                "Compiler version \"{}\" does not satisfy the \"{}\" requirement",
+                version, self.requirement
+            )),
+        }
+    }
+}
+
+impl RuleType for CompilerVersion {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for (range, version) in solidity_pragmas(file) {
+            if let Some(message) = self.check_version(&version) {
+                res.push(self.create_diag(file, range, message));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Compiler version must satisfy a semver requirement. The lowest version allowed by the pragma is checked against it.".to_string(),
+            category: "miscellaneous".to_string(),
+            example_config: "{\"id\": \"compiler-version\", \"severity\": \"ERROR\", \"data\": \">=0.8.19 <0.9.0\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/miscellaneous/compiler_version.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/CompilerVersion".to_string(),
+            options: vec![Options {
+                description: "Semver requirement of the compiler version".to_string(),
+                default: DEFAULT_REQUIREMENT.to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Version satisfying the default \"^0.8.0\" requirement".to_string(),
+                    code: "pragma solidity ^0.8.4;".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Version below the default \"^0.8.0\" requirement".to_string(),
+                    code: "pragma solidity >=0.7.0 <0.9.0;".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl CompilerVersion {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut requirement = DEFAULT_REQUIREMENT.to_string();
+
+        if let Some(data) = &data.data {
+            if let Ok(val) = serde_json::from_value::<String>(data.clone()) {
+                requirement = val;
+            }
+        }
+        let rule = CompilerVersion {
+            allowed: VersionRange::parse(&requirement),
+            requirement,
+            data,
+        };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_REQUIREMENT.into()),
+        }
+    }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::String
+    }
+}
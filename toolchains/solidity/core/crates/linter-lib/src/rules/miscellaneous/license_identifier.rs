@@ -0,0 +1,225 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+
+// global
+pub const RULE_ID: &str = "license-identifier";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const SPDX_TAG: &str = "SPDX-License-Identifier:";
+
+pub struct LicenseIdentifier {
+    allowed: Vec<String>,
+    data: RuleEntry,
+}
+
+// A license identifier written in the file, with its range from the tag to the expression
+struct SpdxComment {
+    range: Range,
+    expression: String,
+}
+
+fn find_spdx_comments(content: &str) -> Vec<SpdxComment> {
+    let mut comments = Vec::new();
+
+    for (line_idx, line) in (1..).zip(content.lines()) {
+        let start = match line.find(SPDX_TAG) {
+            Some(start) => start,
+            None => continue,
+        };
+        let rest = &line[start + SPDX_TAG.len()..];
+        let rest = rest.split("*/").next().unwrap_or_default();
+        let expression = rest.trim();
+        let end = start + SPDX_TAG.len() + rest.trim_end().len();
+
+        comments.push(SpdxComment {
+            range: Range {
+                start: Position {
+                    line: line_idx,
+                    character: line[..start].chars().count(),
+                },
+                end: Position {
+                    line: line_idx,
+                    character: line[..end].chars().count(),
+                },
+            },
+            expression: expression.to_string(),
+        });
+    }
+    comments
+}
+
+fn is_license_id(token: &str) -> bool {
+    let id = token.strip_suffix('+').unwrap_or(token);
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+}
+
+/// The licenses of a SPDX expression such as `MIT OR (Apache-2.0 WITH LLVM-exception)`,
+/// without the exceptions, or none if the expression is malformed.
+fn parse_expression(expression: &str) -> Option<Vec<String>> {
+    let spaced = expression.replace('(', " ( ").replace(')', " ) ");
+    let mut licenses = Vec::new();
+    let mut depth = 0;
+    // whether a license or a closing parenthesis is expected next, rather than an operator
+    let mut expect_operand = true;
+    let mut after_with = false;
+
+    for token in spaced.split_whitespace() {
+        match token {
+            "(" if expect_operand => depth += 1,
+            ")" if !expect_operand && depth > 0 => depth -= 1,
+            "AND" | "OR" if !expect_operand => expect_operand = true,
+            "WITH" if !expect_operand => {
+                expect_operand = true;
+                after_with = true;
+                continue;
+            }
+            id if expect_operand && is_license_id(id) => {
+                if !after_with {
+                    licenses.push(id.to_string());
+                }
+                expect_operand = false;
+            }
+            _ => return None,
+        }
+        after_with = false;
+    }
+    if expect_operand || depth != 0 {
+        return None;
+    }
+    Some(licenses)
+}
+
+impl LicenseIdentifier {
+    fn create_diag(&self, file: &SolidFile, range: Range, message: String) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range,
+            message,
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    fn check_comment(&self, comment: &SpdxComment) -> Option<String> {
+        let licenses = match parse_expression(&comment.expression) {
+            Some(licenses) => licenses,
+            None => {
+                return Some(format!(
+                    "Invalid SPDX license expression \"{}\"",
+                    comment.expression
+                ))
+            }
+        };
+        if self.allowed.is_empty() {
+            return None;
+        }
+        licenses
+            .iter()
+            .find(|license| !self.allowed.contains(license))
+            .map(|license| {
+                format!(
+                    "License \"{}\" is not allowed, use one of: {}",
+                    license,
+                    self.allowed.join(", ")
+                )
+            })
+    }
+}
+
+impl RuleType for LicenseIdentifier {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let comments = find_spdx_comments(&file.content);
+
+        if comments.is_empty() {
+            // on the first line of code, to stay on the same line when blank lines are added
+            let (line_idx, first_line) = (1..)
+                .zip(file.content.lines())
+                .find(|(_, line)| !line.trim().is_empty())
+                .unwrap_or((1, ""));
+            let range = Range {
+                start: Position {
+                    line: line_idx,
+                    character: 0,
+                },
+                end: Position {
+                    line: line_idx,
+                    character: first_line.chars().count(),
+                },
+            };
+            res.push(self.create_diag(file, range, "Missing SPDX license identifier".to_string()));
+        }
+        for (index, comment) in comments.iter().enumerate() {
+            if index > 0 {
+                res.push(self.create_diag(
+                    file,
+                    comment.range.clone(),
+                    "Multiple SPDX license identifiers".to_string(),
+                ));
+            } else if let Some(message) = self.check_comment(comment) {
+                res.push(self.create_diag(file, comment.range.clone(), message));
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Files must have a single valid SPDX license identifier, whose licenses are among the allowed ones if they are configured.".to_string(),
+            category: "miscellaneous".to_string(),
+            example_config: "{\"id\": \"license-identifier\", \"severity\": \"WARNING\", \"data\": [\"MIT\", \"Apache-2.0\"]}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/miscellaneous/license_identifier.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/LicenseIdentifier".to_string(),
+            options: vec![Options {
+                description: "Allowed license identifiers, any license when empty".to_string(),
+                default: "[]".to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "License identifier".to_string(),
+                    code: "// SPDX-License-Identifier: MIT".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Malformed license expression".to_string(),
+                    code: "// SPDX-License-Identifier: MIT OR".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl LicenseIdentifier {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut allowed: Vec<String> = Vec::new();
+
+        if let Some(data) = &data.data {
+            if let Ok(val) = serde_json::from_value::<Vec<String>>(data.clone()) {
+                allowed = val;
+            }
+        }
+        let rule = LicenseIdentifier { allowed, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::json!([])),
+        }
+    }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Array(Box::new(DataSchema::String))
+    }
+}
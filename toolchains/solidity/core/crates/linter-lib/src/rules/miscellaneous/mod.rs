@@ -1,10 +1,75 @@
-use crate::rules::types::{RuleEntry, RulesMap};
+use crate::rules::types::{DataSchemasMap, RuleEntry, RulesMap};
 use std::collections::HashMap;
 
+pub(crate) mod compiler_version;
+pub(crate) mod license_identifier;
+pub(crate) mod no_floating_pragma;
+pub(crate) mod pragma_required;
+pub(crate) mod version;
+
+// List all rules
+use crate::rules::miscellaneous::compiler_version::CompilerVersion;
+use crate::rules::miscellaneous::license_identifier::LicenseIdentifier;
+use crate::rules::miscellaneous::no_floating_pragma::NoFloatingPragma;
+use crate::rules::miscellaneous::pragma_required::PragmaRequired;
+
+use crate::rules::RuleBuilder;
+
 pub fn create_default_rules() -> Vec<RuleEntry> {
-    vec![]
+    vec![
+        LicenseIdentifier::create_default(),
+        PragmaRequired::create_default(),
+    ]
+}
+
+// The version policy depends on the project, these rules are only enabled by the `all` preset
+// and their id
+pub fn create_opt_in_rules() -> Vec<RuleEntry> {
+    vec![
+        NoFloatingPragma::create_default(),
+        CompilerVersion::create_default(),
+    ]
 }
 
 pub fn create_rules() -> RulesMap {
-    HashMap::new()
+    let mut rules: HashMap<String, RuleBuilder> = HashMap::new();
+
+    rules.insert(
+        license_identifier::RULE_ID.to_string(),
+        LicenseIdentifier::create,
+    );
+    rules.insert(
+        no_floating_pragma::RULE_ID.to_string(),
+        NoFloatingPragma::create,
+    );
+    rules.insert(
+        compiler_version::RULE_ID.to_string(),
+        CompilerVersion::create,
+    );
+    rules.insert(pragma_required::RULE_ID.to_string(), PragmaRequired::create);
+
+    rules
+}
+
+pub fn create_data_schemas() -> DataSchemasMap {
+    let mut schemas = HashMap::new();
+
+    schemas.insert(
+        license_identifier::RULE_ID.to_string(),
+        LicenseIdentifier::data_schema(),
+    );
+    schemas.insert(
+        no_floating_pragma::RULE_ID.to_string(),
+        NoFloatingPragma::data_schema(),
+    );
+    schemas.insert(
+        compiler_version::RULE_ID.to_string(),
+        CompilerVersion::data_schema(),
+    );
+    schemas.insert(
+        pragma_required::RULE_ID.to_string(),
+        PragmaRequired::data_schema(),
+    );
+
+    schemas
 }
@@ -0,0 +1,116 @@
+use crate::linter::SolidFile;
+use crate::rules::miscellaneous::version::{solidity_pragmas, VersionRange};
+use crate::rules::types::*;
+use crate::types::*;
+
+// global
+pub const RULE_ID: &str = "no-floating-pragma";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+pub const DEFAULT_ALLOW_RANGES: bool = false;
+
+pub struct NoFloatingPragma {
+    allow_ranges: bool,
+    data: RuleEntry,
+}
+
+impl NoFloatingPragma {
+    fn create_diag(&self, file: &SolidFile, range: Range, version: &str) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range,
+            message: format!(
+                "Compiler version must be fixed, \"{}\" allows other versions",
+                version
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    fn is_floating(&self, version: &str) -> bool {
+        if version.contains(['^', '~']) {
+            return true;
+        }
+        match VersionRange::parse(version) {
+            Some(range) if self.allow_ranges => !range.is_exact() && !range.is_bounded(),
+            Some(range) => !range.is_exact(),
+            // an invalid version is reported by compiler-version
+            None => false,
+        }
+    }
+}
+
+impl RuleType for NoFloatingPragma {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        solidity_pragmas(file)
+            .into_iter()
+            .filter(|(_, version)| self.is_floating(version))
+            .map(|(range, version)| self.create_diag(file, range, &version))
+            .collect()
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Compiler version must be fixed, so that the contracts are deployed with the compiler they were tested with.".to_string(),
+            category: "miscellaneous".to_string(),
+            example_config: "{\"id\": \"no-floating-pragma\", \"severity\": \"WARNING\", \"data\": {\"allowRanges\": false}}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/miscellaneous/no_floating_pragma.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/NoFloatingPragma".to_string(),
+            options: vec![Options {
+                description: "Allow the ranges with an upper bound, such as \">=0.8.0 <0.8.20\". Carets and tildes are always reported".to_string(),
+                default: DEFAULT_ALLOW_RANGES.to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Fixed compiler version".to_string(),
+                    code: "pragma solidity 0.8.20;".to_string(),
+                }],
+                bad: vec![
+                    Example {
+                        description: "Caret version".to_string(),
+                        code: "pragma solidity ^0.8.0;".to_string(),
+                    },
+                    Example {
+                        description: "Version without upper bound".to_string(),
+                        code: "pragma solidity >=0.8.0;".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl NoFloatingPragma {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut allow_ranges = DEFAULT_ALLOW_RANGES;
+
+        if let Some(data) = &data.data {
+            if let Some(val) = data["allowRanges"].as_bool() {
+                allow_ranges = val;
+            }
+        }
+        let rule = NoFloatingPragma { allow_ranges, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(serde_json::json!({
+                "allowRanges": DEFAULT_ALLOW_RANGES,
+            })),
+        }
+    }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Object(vec![("allowRanges", DataSchema::Boolean)])
+    }
+}
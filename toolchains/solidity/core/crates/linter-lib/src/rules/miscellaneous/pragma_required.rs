@@ -0,0 +1,128 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::{Item, PragmaTokens};
+
+// global
+pub const RULE_ID: &str = "pragma-required";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::ERROR;
+pub const DEFAULT_PRAGMAS: &[&str] = &["solidity"];
+
+pub struct PragmaRequired {
+    pragmas: Vec<String>,
+    data: RuleEntry,
+}
+
+impl PragmaRequired {
+    fn create_diag(&self, file: &SolidFile, pragma: &str) -> LintDiag {
+        let first_line = file.content.lines().next().unwrap_or_default();
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: Range {
+                start: Position {
+                    line: 1,
+                    character: 0,
+                },
+                end: Position {
+                    line: 1,
+                    character: first_line.chars().count(),
+                },
+            },
+            message: format!("Missing \"pragma {}\" directive", pragma),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+// The name of a pragma, such as `solidity` for `pragma solidity ^0.8.0;`
+fn pragma_name(tokens: &PragmaTokens) -> String {
+    match tokens {
+        PragmaTokens::Version(..) => "solidity".to_string(),
+        PragmaTokens::Abicoder(..) => "abicoder".to_string(),
+        PragmaTokens::Experimental(..) => "experimental".to_string(),
+        PragmaTokens::Verbatim(tokens) => tokens
+            .clone()
+            .into_iter()
+            .next()
+            .map(|token| token.to_string())
+            .unwrap_or_default(),
+    }
+}
+
+impl RuleType for PragmaRequired {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let declared: Vec<String> = file
+            .data
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Pragma(pragma) => Some(pragma_name(&pragma.tokens)),
+                _ => None,
+            })
+            .collect();
+
+        self.pragmas
+            .iter()
+            .filter(|pragma| !declared.contains(pragma))
+            .map(|pragma| self.create_diag(file, pragma))
+            .collect()
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Files must declare the required pragmas, the compiler version by default.".to_string(),
+            category: "miscellaneous".to_string(),
+            example_config: "{\"id\": \"pragma-required\", \"severity\": \"ERROR\", \"data\": [\"solidity\", \"abicoder\"]}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/miscellaneous/pragma_required.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/PragmaRequired".to_string(),
+            options: vec![Options {
+                description: "Names of the required pragmas: solidity, abicoder or experimental".to_string(),
+                default: "[\"solidity\"]".to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "File with a compiler version".to_string(),
+                    code: "pragma solidity 0.8.20;\n\ncontract A {}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "File without compiler version".to_string(),
+                    code: "contract A {}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl PragmaRequired {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut pragmas: Vec<String> = DEFAULT_PRAGMAS.iter().map(|p| p.to_string()).collect();
+
+        if let Some(data) = &data.data {
+            if let Ok(val) = serde_json::from_value::<Vec<String>>(data.clone()) {
+                pragmas = val;
+            }
+        }
+        let rule = PragmaRequired { pragmas, data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_PRAGMAS.into()),
+        }
+    }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Array(Box::new(DataSchema::String))
+    }
+}
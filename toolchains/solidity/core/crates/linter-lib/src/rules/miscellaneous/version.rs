@@ -0,0 +1,505 @@
+use crate::linter::SolidFile;
+use crate::rules::utils::{range_from_spanned, text_from_range};
+use crate::types::Range;
+use osmium_libs_solidity_ast_extractor::{Item, PragmaTokens};
+use std::cmp::Ordering;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// The pre-release identifiers, such as `rc.1` in `0.8.0-rc.1`, empty for a release.
+    pub pre: String,
+}
+
+impl Version {
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+            pre: String::new(),
+        }
+    }
+
+    fn is_pre_release(&self) -> bool {
+        !self.pre.is_empty()
+    }
+
+    fn same_release(&self, other: &Version) -> bool {
+        (self.major, self.minor, self.patch) == (other.major, other.minor, other.patch)
+    }
+}
+
+// The numeric identifiers are compared as numbers and are lower than the other ones
+fn compare_pre_release(a: &str, b: &str) -> Ordering {
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+                (Ok(a), Ok(b)) => a.cmp(&b),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => a.cmp(b),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+impl Ord for Version {
+    // a pre-release comes before its release
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => compare_pre_release(&self.pre, &other.pre),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if self.is_pre_release() {
+            write!(f, "-{}", self.pre)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Comparator {
+    op: Op,
+    version: Version,
+}
+
+impl Comparator {
+    fn new(op: Op, version: Version) -> Self {
+        Comparator { op, version }
+    }
+
+    fn matches(&self, version: &Version) -> bool {
+        match self.op {
+            Op::Lt => *version < self.version,
+            Op::Le => *version <= self.version,
+            Op::Gt => *version > self.version,
+            Op::Ge => *version >= self.version,
+            Op::Eq => *version == self.version,
+        }
+    }
+}
+
+// A version with its missing or wildcard components, such as `0.8` or `0.8.x`
+#[derive(Debug, Clone)]
+struct Partial {
+    major: Option<u64>,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    // only kept for a full version
+    pre: String,
+}
+
+impl Partial {
+    fn parse(text: &str) -> Option<Self> {
+        let text = text.strip_prefix(['v', 'V']).unwrap_or(text);
+        // the build metadata is ignored by the comparisons
+        let text = text.split_once('+').map_or(text, |(text, _)| text);
+        let (text, pre) = match text.split_once('-') {
+            Some((_, pre)) if pre.split('.').any(str::is_empty) => return None,
+            Some(parts) => parts,
+            None => (text, ""),
+        };
+        let mut components = [None; 3];
+        let parts: Vec<&str> = text.split('.').collect();
+        if parts.is_empty() || parts.len() > 3 {
+            return None;
+        }
+        for (component, part) in components.iter_mut().zip(&parts) {
+            *component = match *part {
+                "x" | "X" | "*" => None,
+                part => Some(part.parse::<u64>().ok()?),
+            };
+        }
+        // a wildcard makes the following components wildcards too
+        if let Some(wildcard) = components.iter().position(Option::is_none) {
+            components[wildcard..].fill(None);
+        }
+        if components[2].is_none() && !pre.is_empty() {
+            return None;
+        }
+        Some(Partial {
+            major: components[0],
+            minor: components[1],
+            patch: components[2],
+            pre: pre.to_string(),
+        })
+    }
+
+    fn floor(&self) -> Version {
+        Version {
+            pre: self.pre.clone(),
+            ..Version::new(
+                self.major.unwrap_or(0),
+                self.minor.unwrap_or(0),
+                self.patch.unwrap_or(0),
+            )
+        }
+    }
+
+    fn full(&self) -> Option<Version> {
+        Some(Version {
+            pre: self.pre.clone(),
+            ..Version::new(self.major?, self.minor?, self.patch?)
+        })
+    }
+
+    // The first version after the ones matched by the partial version, none for `*`
+    fn ceil(&self) -> Option<Version> {
+        match (self.major, self.minor, self.patch) {
+            (Some(major), None, _) => Some(Version::new(major + 1, 0, 0)),
+            (Some(major), Some(minor), None) => Some(Version::new(major, minor + 1, 0)),
+            (Some(major), Some(minor), Some(patch)) => Some(Version::new(major, minor, patch + 1)),
+            (None, _, _) => None,
+        }
+    }
+}
+
+fn caret(partial: &Partial) -> Vec<Comparator> {
+    let floor = partial.floor();
+    let upper = match (partial.major, partial.minor, partial.patch) {
+        (None, _, _) => return vec![],
+        (Some(major), _, _) if major > 0 => Version::new(major + 1, 0, 0),
+        (Some(_), None, _) => Version::new(1, 0, 0),
+        (Some(_), Some(minor), _) if minor > 0 => Version::new(0, minor + 1, 0),
+        (Some(_), Some(_), None) => Version::new(0, 1, 0),
+        (Some(_), Some(_), Some(patch)) => Version::new(0, 0, patch + 1),
+    };
+    vec![
+        Comparator::new(Op::Ge, floor),
+        Comparator::new(Op::Lt, upper),
+    ]
+}
+
+fn tilde(partial: &Partial) -> Vec<Comparator> {
+    let upper = match (partial.major, partial.minor) {
+        (None, _) => return vec![],
+        (Some(major), None) => Version::new(major + 1, 0, 0),
+        (Some(major), Some(minor)) => Version::new(major, minor + 1, 0),
+    };
+    vec![
+        Comparator::new(Op::Ge, partial.floor()),
+        Comparator::new(Op::Lt, upper),
+    ]
+}
+
+fn x_range(partial: &Partial) -> Vec<Comparator> {
+    if let Some(version) = partial.full() {
+        return vec![Comparator::new(Op::Eq, version)];
+    }
+    match partial.ceil() {
+        Some(ceil) => vec![
+            Comparator::new(Op::Ge, partial.floor()),
+            Comparator::new(Op::Lt, ceil),
+        ],
+        None => vec![],
+    }
+}
+
+fn parse_comparator(text: &str) -> Option<Vec<Comparator>> {
+    let (op, version) = match text.find(|c: char| c.is_ascii_alphanumeric() || c == '*') {
+        Some(index) => text.split_at(index),
+        None => return None,
+    };
+    let partial = Partial::parse(version)?;
+    let full = partial.full();
+
+    Some(match op {
+        "" | "=" => x_range(&partial),
+        "^" => caret(&partial),
+        "~" | "~>" => tilde(&partial),
+        ">=" => vec![Comparator::new(Op::Ge, partial.floor())],
+        "<" => vec![Comparator::new(Op::Lt, partial.floor())],
+        ">" => match (full, partial.ceil()) {
+            (Some(version), _) => vec![Comparator::new(Op::Gt, version)],
+            (None, Some(ceil)) => vec![Comparator::new(Op::Ge, ceil)],
+            // nothing is greater than every version
+            (None, None) => vec![Comparator::new(Op::Lt, Version::new(0, 0, 0))],
+        },
+        "<=" => match (full, partial.ceil()) {
+            (Some(version), _) => vec![Comparator::new(Op::Le, version)],
+            (None, Some(ceil)) => vec![Comparator::new(Op::Lt, ceil)],
+            (None, None) => vec![],
+        },
+        _ => return None,
+    })
+}
+
+// Join the operators written apart from their version, as in `>= 0.8.0`
+fn comparator_tokens(text: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut pending_op = String::new();
+
+    for token in text.split_whitespace() {
+        if token != "-" && token.chars().all(|c| "<>=^~".contains(c)) {
+            pending_op.push_str(token);
+        } else {
+            tokens.push(format!("{}{}", pending_op, token));
+            pending_op.clear();
+        }
+    }
+    if !pending_op.is_empty() {
+        tokens.push(pending_op);
+    }
+    tokens
+}
+
+fn parse_set(text: &str) -> Option<Vec<Comparator>> {
+    let tokens = comparator_tokens(text);
+    if tokens.is_empty() {
+        return None;
+    }
+    // hyphen range, `0.8.0 - 0.8.19`
+    if tokens.len() == 3 && tokens[1] == "-" {
+        let from = Partial::parse(&tokens[0])?;
+        let to = Partial::parse(&tokens[2])?;
+        let mut comparators = vec![Comparator::new(Op::Ge, from.floor())];
+        match (to.full(), to.ceil()) {
+            (Some(version), _) => comparators.push(Comparator::new(Op::Le, version)),
+            (None, Some(ceil)) => comparators.push(Comparator::new(Op::Lt, ceil)),
+            (None, None) => {}
+        }
+        return Some(comparators);
+    }
+
+    let mut comparators = Vec::new();
+    for token in tokens {
+        comparators.extend(parse_comparator(&token)?);
+    }
+    Some(comparators)
+}
+
+/// A range of versions written like in the `pragma solidity` directives, which follow the
+/// npm semver syntax: `^0.8.0`, `>=0.8.0 <0.9.0`, `0.8.0 - 0.8.19` or `0.7.6 || ^0.8.0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionRange {
+    sets: Vec<Vec<Comparator>>,
+}
+
+impl VersionRange {
+    pub fn parse(text: &str) -> Option<Self> {
+        let sets = text
+            .split("||")
+            .map(parse_set)
+            .collect::<Option<Vec<_>>>()?;
+        Some(VersionRange { sets })
+    }
+
+    /// Whether a version is in the range. Like with npm, a pre-release is only in a range
+    /// which has a pre-release of the same version, `0.8.0-rc.2` being in `>=0.8.0-rc.1` but
+    /// not in `^0.7.0`.
+    pub fn matches(&self, version: &Version) -> bool {
+        self.sets.iter().any(|set| {
+            set.iter().all(|comparator| comparator.matches(version))
+                && (!version.is_pre_release()
+                    || set.iter().any(|comparator| {
+                        comparator.version.is_pre_release()
+                            && comparator.version.same_release(version)
+                    }))
+        })
+    }
+
+    /// The lowest version of the range, none if no version matches it.
+    pub fn min_version(&self) -> Option<Version> {
+        self.sets
+            .iter()
+            .filter_map(|set| {
+                let lowest = set
+                    .iter()
+                    .filter_map(|comparator| match comparator.op {
+                        Op::Ge | Op::Eq => Some(comparator.version.clone()),
+                        // the release of a pre-release is greater than it
+                        Op::Gt if comparator.version.is_pre_release() => Some(Version {
+                            pre: String::new(),
+                            ..comparator.version.clone()
+                        }),
+                        Op::Gt => Some(Version::new(
+                            comparator.version.major,
+                            comparator.version.minor,
+                            comparator.version.patch + 1,
+                        )),
+                        Op::Lt | Op::Le => None,
+                    })
+                    .max()
+                    .unwrap_or(Version::new(0, 0, 0));
+                Some(lowest).filter(|lowest| set.iter().all(|c| c.matches(lowest)))
+            })
+            .min()
+    }
+
+    /// Whether the range only matches one version, such as `0.8.20` or `=0.8.20`.
+    pub fn is_exact(&self) -> bool {
+        matches!(self.sets.as_slice(), [set] if matches!(set.as_slice(), [comparator] if comparator.op == Op::Eq))
+    }
+
+    /// Whether the range has an upper bound, unlike `>=0.8.0`.
+    pub fn is_bounded(&self) -> bool {
+        self.sets.iter().all(|set| {
+            set.iter()
+                .any(|comparator| matches!(comparator.op, Op::Lt | Op::Le | Op::Eq))
+        })
+    }
+}
+
+/// The `pragma solidity` directives of a file, with the version range as written.
+pub fn solidity_pragmas(file: &SolidFile) -> Vec<(Range, String)> {
+    file.data
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Pragma(pragma) if matches!(pragma.tokens, PragmaTokens::Version(..)) => {
+                let range = range_from_spanned(pragma);
+                let text = text_from_range(&file.content, &range)?;
+                let version = text
+                    .trim_end_matches(';')
+                    .trim_start_matches("pragma")
+                    .trim_start()
+                    .trim_start_matches("solidity")
+                    .trim()
+                    .to_string();
+                Some((range, version))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(text: &str) -> Version {
+        Partial::parse(text)
+            .and_then(|partial| partial.full())
+            .unwrap()
+    }
+
+    #[test]
+    fn ranges_match_versions() {
+        let cases: &[(&str, &[&str], &[&str])] = &[
+            ("^0.8.0", &["0.8.0", "0.8.25"], &["0.7.6", "0.9.0"]),
+            ("^0.0.3", &["0.0.3"], &["0.0.4", "0.0.2"]),
+            ("^1.2", &["1.2.0", "1.9.9"], &["2.0.0", "1.1.9"]),
+            ("~0.8.4", &["0.8.4", "0.8.99"], &["0.8.3", "0.9.0"]),
+            ("~0.8", &["0.8.0", "0.8.30"], &["0.9.0"]),
+            (">=0.8.0 <0.9.0", &["0.8.0", "0.8.30"], &["0.7.6", "0.9.0"]),
+            (">= 0.8.0 < 0.8.20", &["0.8.19"], &["0.8.20"]),
+            (">0.8", &["0.9.0"], &["0.8.30"]),
+            ("<=0.8", &["0.8.30"], &["0.9.0"]),
+            ("0.8.20", &["0.8.20"], &["0.8.21"]),
+            ("=0.8.x", &["0.8.0", "0.8.30"], &["0.9.0"]),
+            ("0.8.0 - 0.8.19", &["0.8.0", "0.8.19"], &["0.8.20"]),
+            ("0.7.6 || ^0.8.0", &["0.7.6", "0.8.1"], &["0.7.5", "0.9.0"]),
+            ("*", &["0.4.0", "1.0.0"], &[]),
+        ];
+        for (range, matching, not_matching) in cases {
+            let parsed = VersionRange::parse(range).unwrap();
+            for text in *matching {
+                assert!(parsed.matches(&version(text)), "{} in {}", text, range);
+            }
+            for text in *not_matching {
+                assert!(!parsed.matches(&version(text)), "{} not in {}", text, range);
+            }
+        }
+    }
+
+    #[test]
+    fn pre_releases_only_match_their_release() {
+        let cases: &[(&str, &str, bool)] = &[
+            (">=0.8.0-rc.1", "0.8.0-rc.2", true),
+            (">=0.8.0-rc.1", "0.8.0-rc.1", true),
+            (">=0.8.0-rc.2", "0.8.0-rc.1", false),
+            (">=0.8.0-rc.1", "0.8.0", true),
+            (">=0.8.0-rc.1", "0.8.1-rc.1", false),
+            ("^0.7.0", "0.7.5-nightly", false),
+            ("<0.8.0", "0.8.0-rc.1", false),
+            ("0.8.0-beta+commit.123", "0.8.0-beta", true),
+        ];
+        for (range, text, expected) in cases {
+            let parsed = VersionRange::parse(range).unwrap();
+            assert_eq!(
+                parsed.matches(&version(text)),
+                *expected,
+                "{} in {}",
+                text,
+                range
+            );
+        }
+        assert!(version("0.8.0-rc.2") < version("0.8.0-rc.10"));
+        assert!(version("0.8.0-1") < version("0.8.0-alpha"));
+        assert!(version("0.8.0-rc.1") < version("0.8.0"));
+        assert_eq!(version("0.8.0-rc.1").to_string(), "0.8.0-rc.1");
+    }
+
+    #[test]
+    fn invalid_ranges_are_rejected() {
+        for range in [
+            "",
+            "0.8.0.1",
+            "^",
+            "0.a",
+            ">>0.8.0",
+            "0.8-rc",
+            "0.8.0-",
+            "0.8.0-rc..1",
+        ] {
+            assert!(VersionRange::parse(range).is_none(), "{}", range);
+        }
+    }
+
+    #[test]
+    fn range_properties() {
+        let cases: &[(&str, Option<&str>, bool, bool)] = &[
+            ("^0.8.4", Some("0.8.4"), false, true),
+            (">=0.8.0", Some("0.8.0"), false, false),
+            (">0.8.0", Some("0.8.1"), false, false),
+            (">0.8.0-rc.1", Some("0.8.0"), false, false),
+            ("0.8.20", Some("0.8.20"), true, true),
+            ("=0.8.20", Some("0.8.20"), true, true),
+            ("0.7.6 || ^0.8.0", Some("0.7.6"), false, true),
+            (">0.8.0 <0.8.1", None, false, true),
+        ];
+        for (range, min, exact, bounded) in cases {
+            let parsed = VersionRange::parse(range).unwrap();
+            assert_eq!(parsed.min_version(), min.map(version), "{}", range);
+            assert_eq!(parsed.is_exact(), *exact, "{}", range);
+            assert_eq!(parsed.is_bounded(), *bounded, "{}", range);
+        }
+    }
+}
@@ -1,18 +1,23 @@
-use crate::rules::types::{RuleEntry, RuleType, RulesMap};
+use crate::rules::rule_impl::create_preset_rules;
+use crate::rules::types::{DataSchemasMap, RuleDocumentation, RuleEntry, RuleType, RulesMap};
 use std::collections::HashMap;
 
 pub mod factory;
 pub mod rule_impl;
+pub mod schema;
 pub mod types;
 pub mod utils;
+pub mod validation;
 
 // List all rules
 pub mod best_practises;
+pub mod gas;
 pub mod miscellaneous;
 pub mod naming;
 pub mod order;
 pub mod security;
 
+// The gas rules are only enabled by the `gas` and `all` presets
 pub fn create_default_rules() -> Vec<RuleEntry> {
     let mut rules = Vec::new();
 
@@ -25,6 +30,16 @@ pub fn create_default_rules() -> Vec<RuleEntry> {
     rules
 }
 
+/// The default entries of the rules which are only enabled by the `all` preset and by their id.
+pub fn create_opt_in_rules() -> Vec<RuleEntry> {
+    let mut rules = Vec::new();
+
+    rules.append(&mut gas::create_opt_in_rules());
+    rules.append(&mut miscellaneous::create_opt_in_rules());
+
+    rules
+}
+
 type RuleBuilder = fn(RuleEntry) -> Box<dyn RuleType>;
 
 pub fn add_rules(rules: &mut HashMap<String, RuleBuilder>, to_add: HashMap<String, RuleBuilder>) {
@@ -41,6 +56,34 @@ pub fn create_rules() -> RulesMap {
     add_rules(&mut rules, order::create_rules());
     add_rules(&mut rules, miscellaneous::create_rules());
     add_rules(&mut rules, security::create_rules());
+    add_rules(&mut rules, gas::create_rules());
 
     rules
 }
+
+/// The documentation of every rule, built from its default entry.
+pub fn create_documentation() -> Vec<RuleDocumentation> {
+    let rules = create_rules();
+    create_preset_rules("all")
+        .into_iter()
+        .filter_map(|entry| {
+            rules
+                .get(&entry.id)
+                .map(|create| create(entry).get_documentation())
+        })
+        .collect()
+}
+
+// Schemas of the rules taking data, the other rules have no options
+pub fn create_data_schemas() -> DataSchemasMap {
+    let mut schemas = HashMap::new();
+
+    schemas.extend(best_practises::create_data_schemas());
+    schemas.extend(naming::create_data_schemas());
+    schemas.extend(order::create_data_schemas());
+    schemas.extend(miscellaneous::create_data_schemas());
+    schemas.extend(security::create_data_schemas());
+    schemas.extend(gas::create_data_schemas());
+
+    schemas
+}
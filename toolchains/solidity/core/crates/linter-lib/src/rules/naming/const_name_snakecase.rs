@@ -33,6 +33,7 @@ impl ConstNameSnakeCase {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -48,7 +49,7 @@ fn is_snake_case(name: &str) -> bool {
 }
 
 impl RuleType for ConstNameSnakeCase {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
         let contracts = retriever::retrieve_contract_nodes(&file.data);
 
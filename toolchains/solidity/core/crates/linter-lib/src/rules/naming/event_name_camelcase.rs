@@ -33,6 +33,7 @@ impl EventNameCamelCase {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -49,7 +50,7 @@ fn is_camel_case(name: &str) -> bool {
 }
 
 impl RuleType for EventNameCamelCase {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
         let contracts =
             osmium_libs_solidity_ast_extractor::retriever::retrieve_contract_nodes(&file.data);
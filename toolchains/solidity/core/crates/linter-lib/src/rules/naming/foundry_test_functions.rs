@@ -42,13 +42,14 @@ impl FoundryTestFunctions {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for FoundryTestFunctions {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         if !file.path.ends_with(".t.sol") {
             return vec![];
         }
@@ -147,16 +148,9 @@ impl FoundryTestFunctions {
         let mut excluded: Vec<String> = Vec::new();
 
         if let Some(data) = &data.data {
-            let parsed: Result<Vec<String>, serde_json::Error> =
-                serde_json::from_value(data.clone());
-            match parsed {
-                Ok(val) => excluded = val,
-                Err(_) => {
-                    eprintln!("{} rule : bad config data", RULE_ID);
-                }
+            if let Ok(val) = serde_json::from_value::<Vec<String>>(data.clone()) {
+                excluded = val;
             }
-        } else {
-            eprintln!("{} rule : bad config data", RULE_ID);
         }
         let rule = FoundryTestFunctions { excluded, data };
         Box::new(rule)
@@ -169,4 +163,8 @@ impl FoundryTestFunctions {
             data: Some(DEFAULT_SKIP_FUNCTIONS.into()),
         }
     }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Array(Box::new(DataSchema::String))
+    }
 }
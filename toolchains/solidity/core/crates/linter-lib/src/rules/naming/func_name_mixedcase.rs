@@ -33,13 +33,14 @@ impl FuncNameMixedCase {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for FuncNameMixedCase {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
         let contracts =
             osmium_libs_solidity_ast_extractor::retriever::retrieve_contract_nodes(&file.data);
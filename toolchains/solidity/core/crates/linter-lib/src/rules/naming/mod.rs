@@ -9,7 +9,7 @@ use crate::rules::naming::named_parameters_mapping::NamedParametersMapping;
 use crate::rules::naming::private_vars_leading_underscore::PrivateVarsLeadingUnderscore;
 use crate::rules::naming::use_forbidden_name::UseForbiddenName;
 use crate::rules::naming::var_name_mixedcase::VarNameMixedCase;
-use crate::rules::types::{RuleEntry, RulesMap};
+use crate::rules::types::{DataSchemasMap, RuleEntry, RulesMap};
 use crate::rules::RuleBuilder;
 use std::collections::HashMap;
 
@@ -94,3 +94,18 @@ pub fn create_rules() -> RulesMap {
 
     rules
 }
+
+pub fn create_data_schemas() -> DataSchemasMap {
+    let mut schemas = HashMap::new();
+
+    schemas.insert(
+        foundry_test_functions::RULE_ID.to_string(),
+        FoundryTestFunctions::data_schema(),
+    );
+    schemas.insert(
+        private_vars_leading_underscore::RULE_ID.to_string(),
+        PrivateVarsLeadingUnderscore::data_schema(),
+    );
+
+    schemas
+}
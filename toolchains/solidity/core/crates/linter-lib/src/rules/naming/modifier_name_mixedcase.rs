@@ -33,6 +33,7 @@ impl ModifierNameMixedcase {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -64,7 +65,7 @@ fn is_mixed_case(name: &str) -> bool {
 }
 
 impl RuleType for ModifierNameMixedcase {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
         let contracts = retriever::retrieve_contract_nodes(&file.data);
 
@@ -1,8 +1,12 @@
 use osmium_libs_solidity_ast_extractor::Visibility::{Internal, Private};
-use osmium_libs_solidity_ast_extractor::{Item, LineColumn, Spanned};
+use osmium_libs_solidity_ast_extractor::{
+    visit, Expr, ExprMember, Item, ItemContract, ItemFunction, LineColumn, SolIdent, SolPath,
+    Spanned, StmtAssembly, VariableDefinition, Visit,
+};
 
 use crate::linter::SolidFile;
 use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
 use crate::types::*;
 
 // global
@@ -20,12 +24,107 @@ pub struct PrivateVarsLeadingUnderscore {
     strict: bool,
 }
 
+// Collect every identifier of a scope to rename a symbol in it
+#[derive(Default)]
+struct IdentifiersVisitor {
+    idents: Vec<SolIdent>,
+    has_assembly: bool,
+}
+
+impl<'ast> Visit<'ast> for IdentifiersVisitor {
+    fn visit_ident(&mut self, ident: &'ast SolIdent) {
+        self.idents.push(ident.clone());
+    }
+
+    fn visit_path(&mut self, path: &'ast SolPath) {
+        for ident in path.iter() {
+            self.idents.push(ident.clone());
+        }
+    }
+
+    fn visit_expr_member(&mut self, member: &'ast ExprMember) {
+        self.visit_expr(&member.expr);
+        // members of other contracts are not declared in this scope
+        if let Expr::Ident(ident) = &*member.expr {
+            if ident.as_string() == "this" {
+                self.visit_expr(&member.member);
+            }
+        }
+    }
+
+    fn visit_variable_definition(&mut self, var: &'ast VariableDefinition) {
+        visit::visit_variable_definition(self, var);
+        if let Some((_, expr)) = &var.initializer {
+            self.visit_expr(expr);
+        }
+    }
+
+    fn visit_item_function(&mut self, function: &'ast ItemFunction) {
+        for attribute in function.attributes.iter() {
+            if let Some(modifier) = attribute.modifier() {
+                self.visit_path(&modifier.name);
+                for arg in modifier.arguments.iter() {
+                    self.visit_expr(arg);
+                }
+            }
+        }
+        visit::visit_item_function(self, function);
+    }
+
+    fn visit_stmt_asm(&mut self, _: &'ast StmtAssembly) {
+        self.has_assembly = true;
+    }
+}
+
+// Rename every occurrence of an identifier in its scope, unless it is used in inline assembly
+// (which is not parsed) or the new name is already taken
+fn create_rename_fix(name: &SolIdent, scope: &IdentifiersVisitor) -> Option<Fix> {
+    let name = name.as_string();
+    let new_name = match name.strip_prefix('_') {
+        Some(stripped) => stripped.to_string(),
+        None => format!("_{}", name),
+    };
+    if scope.has_assembly
+        || new_name.is_empty()
+        || scope
+            .idents
+            .iter()
+            .any(|ident| ident.as_string() == new_name)
+    {
+        return None;
+    }
+
+    let edits = scope
+        .idents
+        .iter()
+        .filter(|ident| ident.as_string() == name)
+        .map(|ident| TextEdit::replace(range_from_spanned(ident), new_name.clone()))
+        .collect();
+    Some(Fix {
+        message: format!("Rename {} to {}", name, new_name),
+        edits,
+    })
+}
+
+fn contract_scope(contract: &ItemContract) -> IdentifiersVisitor {
+    let mut visitor = IdentifiersVisitor::default();
+    visitor.visit_item_contract(contract);
+    visitor
+}
+
+fn function_scope(function: &ItemFunction) -> IdentifiersVisitor {
+    let mut visitor = IdentifiersVisitor::default();
+    visitor.visit_item_function(function);
+    visitor
+}
+
 impl PrivateVarsLeadingUnderscore {
     fn create_diag(
         &self,
         location: (LineColumn, LineColumn),
         file: &SolidFile,
         message: String,
+        fix: Option<Fix>,
     ) -> LintDiag {
         LintDiag {
             id: RULE_ID.to_string(),
@@ -43,23 +142,26 @@ impl PrivateVarsLeadingUnderscore {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for PrivateVarsLeadingUnderscore {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
         let contracts =
             osmium_libs_solidity_ast_extractor::retriever::retrieve_contract_nodes(&file.data);
 
         for contract in contracts {
+            let scope = contract_scope(&contract);
             let functions =
                 osmium_libs_solidity_ast_extractor::retriever::retrieve_functions_nodes(&contract);
 
             for function in functions {
                 if self.strict {
+                    let function_scope = function_scope(&function);
                     for argument in function.arguments {
                         if let Some(name) = argument.name {
                             let leading_underscore = name.as_string().starts_with('_');
@@ -70,6 +172,7 @@ impl RuleType for PrivateVarsLeadingUnderscore {
                                     (span.start(), span.end()),
                                     file,
                                     MESSAGE_PRIVATE.to_string(),
+                                    create_rename_fix(&name, &function_scope),
                                 ));
                             }
                         }
@@ -86,6 +189,7 @@ impl RuleType for PrivateVarsLeadingUnderscore {
                                         (span.start(), span.end()),
                                         file,
                                         MESSAGE_PRIVATE.to_string(),
+                                        create_rename_fix(&name, &function_scope),
                                     ));
                                 }
                             }
@@ -93,10 +197,13 @@ impl RuleType for PrivateVarsLeadingUnderscore {
                     }
                 }
 
-                let is_private = match function.attributes.visibility() {
+                let visibility = function.attributes.visibility();
+                let is_private = match visibility {
                     Some(val) => matches!(val, Private(_) | Internal(_)),
                     None => true,
                 };
+                // the other items may be used out of the contract, only private ones are renamed
+                let fix_scope = matches!(visibility, Some(Private(_))).then_some(&scope);
 
                 if let Some(name) = function.name {
                     let leading_underscore = name.as_string().starts_with('_');
@@ -107,6 +214,7 @@ impl RuleType for PrivateVarsLeadingUnderscore {
                             (span.start(), span.end()),
                             file,
                             MESSAGE_PRIVATE.to_string(),
+                            fix_scope.and_then(|scope| create_rename_fix(&name, scope)),
                         ));
                     }
                     if leading_underscore && !is_private {
@@ -115,6 +223,7 @@ impl RuleType for PrivateVarsLeadingUnderscore {
                             (span.start(), span.end()),
                             file,
                             MESSAGE_PUBLIC.to_string(),
+                            fix_scope.and_then(|scope| create_rename_fix(&name, scope)),
                         ));
                     }
                 }
@@ -122,10 +231,12 @@ impl RuleType for PrivateVarsLeadingUnderscore {
 
             for node_var in contract.body.iter() {
                 if let Item::Variable(var) = node_var {
-                    let is_private = match var.attributes.visibility() {
+                    let visibility = var.attributes.visibility();
+                    let is_private = match visibility {
                         Some(val) => matches!(val, Private(_) | Internal(_)),
                         None => true,
                     };
+                    let fix_scope = matches!(visibility, Some(Private(_))).then_some(&scope);
 
                     let leading_underscore = var.name.as_string().starts_with('_');
 
@@ -135,6 +246,7 @@ impl RuleType for PrivateVarsLeadingUnderscore {
                             (span.start(), span.end()),
                             file,
                             MESSAGE_PRIVATE.to_string(),
+                            fix_scope.and_then(|scope| create_rename_fix(&var.name, scope)),
                         ));
                     }
                     if leading_underscore && !is_private {
@@ -143,6 +255,7 @@ impl RuleType for PrivateVarsLeadingUnderscore {
                             (span.start(), span.end()),
                             file,
                             MESSAGE_PUBLIC.to_string(),
+                            fix_scope.and_then(|scope| create_rename_fix(&var.name, scope)),
                         ));
                     }
                 }
@@ -203,11 +316,7 @@ impl PrivateVarsLeadingUnderscore {
         if let Some(data) = &data.data {
             if !data["strict"].is_null() && data["strict"].as_bool().is_some() {
                 strict = data["strict"].as_bool().unwrap();
-            } else {
-                eprintln!("{} rule : bad config data", RULE_ID);
             }
-        } else {
-            eprintln!("{} rule : bad config data", RULE_ID);
         }
         let rule = PrivateVarsLeadingUnderscore { strict, data };
         Box::new(rule)
@@ -222,4 +331,8 @@ impl PrivateVarsLeadingUnderscore {
             })),
         }
     }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Object(vec![("strict", DataSchema::Boolean)])
+    }
 }
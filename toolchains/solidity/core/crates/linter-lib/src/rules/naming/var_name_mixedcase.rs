@@ -33,13 +33,14 @@ impl VarNameMixedCase {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for VarNameMixedCase {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
 
         let variables_definition =
@@ -58,8 +59,7 @@ impl RuleType for VarNameMixedCase {
                 &file.data,
             );
         for variable in variables_declaration {
-            if variable.name.is_some() {
-                let name = variable.name.unwrap();
+            if let Some(name) = variable.name {
                 if name.to_string()[1..].find('_').is_some() {
                     let span = name.span();
                     res.push(self.create_diag((span.start(), span.end()), file));
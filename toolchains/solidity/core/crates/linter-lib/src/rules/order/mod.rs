@@ -1,4 +1,4 @@
-use crate::rules::types::{RuleEntry, RulesMap};
+use crate::rules::types::{DataSchemasMap, RuleEntry, RulesMap};
 use std::collections::HashMap;
 
 #[macro_use]
@@ -32,3 +32,7 @@ pub fn create_rules() -> RulesMap {
 
     rules
 }
+
+pub fn create_data_schemas() -> DataSchemasMap {
+    HashMap::new()
+}
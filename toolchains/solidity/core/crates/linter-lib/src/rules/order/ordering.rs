@@ -297,6 +297,7 @@ impl OrderingVisitor {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
@@ -510,7 +511,7 @@ pub struct Ordering {
 }
 
 impl RuleType for Ordering {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut visitor = OrderingVisitor::new(file.clone(), self.data.clone());
         visitor.visit_file(&file.data);
         visitor.reports
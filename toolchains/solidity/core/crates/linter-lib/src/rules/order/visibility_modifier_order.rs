@@ -1,10 +1,10 @@
 use osmium_libs_solidity_ast_extractor::retriever::{
     retrieve_contract_nodes, retrieve_functions_nodes,
 };
-use osmium_libs_solidity_ast_extractor::Spanned;
 
 use crate::linter::SolidFile;
 use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
 use crate::types::*;
 
 // global
@@ -19,17 +19,18 @@ pub struct VisibilityModiferOrder {
 }
 
 impl RuleType for VisibilityModiferOrder {
-    fn diagnose(&self, _file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, _file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
 
         let reports = check_visibility_modifier_order(_file);
-        for report in reports {
+        for (report, fix) in reports {
             res.push(LintDiag {
                 id: RULE_ID.to_string(),
                 range: report,
                 severity: self.data.severity,
                 code: None,
                 source: None,
+                fix: Some(fix),
                 message: DEFAULT_MESSAGE.to_string(),
                 uri: _file.path.clone(),
             });
@@ -55,7 +56,7 @@ impl RuleType for VisibilityModiferOrder {
     }
 }
 
-fn check_visibility_modifier_order(file: &SolidFile) -> Vec<Range> {
+fn check_visibility_modifier_order(file: &SolidFile) -> Vec<(Range, Fix)> {
     let mut reports = Vec::new();
 
     let contracts = retrieve_contract_nodes(&file.data);
@@ -63,23 +64,33 @@ fn check_visibility_modifier_order(file: &SolidFile) -> Vec<Range> {
         let functions = retrieve_functions_nodes(&contract);
         for function in functions {
             let mut is_attributes = false;
-            function.attributes.iter().for_each(|attributes| {
-                if attributes.modifier().is_some() || attributes.mutability().is_some() {
+            let attributes = &function.attributes.0;
+            for (idx, attribute) in attributes.iter().enumerate() {
+                if attribute.modifier().is_some() || attribute.mutability().is_some() {
                     is_attributes = true;
                 }
-                if attributes.visibility().is_some() && is_attributes {
-                    reports.push(Range {
-                        start: Position {
-                            line: attributes.span().start().line,
-                            character: attributes.span().start().column,
-                        },
-                        end: Position {
-                            line: attributes.span().end().line,
-                            character: attributes.span().end().column,
-                        },
-                    });
+                if let Some(visibility) = attribute.visibility() {
+                    if is_attributes {
+                        let range = range_from_spanned(attribute);
+                        // move the visibility in front of the first attribute,
+                        // removing it along with the whitespace preceding it
+                        let fix = Fix {
+                            message: format!("Move {} before the other modifiers", visibility),
+                            edits: vec![
+                                TextEdit::insert(
+                                    range_from_spanned(&attributes[0]).start,
+                                    format!("{} ", visibility),
+                                ),
+                                TextEdit::delete(Range {
+                                    start: range_from_spanned(&attributes[idx - 1]).end,
+                                    end: range.end.clone(),
+                                }),
+                            ],
+                        };
+                        reports.push((range, fix));
+                    }
                 }
-            });
+            }
         }
     }
     reports
@@ -1,11 +1,17 @@
 use crate::errors::SolidHunterError;
-use crate::rules::create_default_rules;
 use crate::rules::types::*;
+use crate::rules::validation::validate_rules;
+use crate::rules::{
+    best_practises, create_default_rules, create_opt_in_rules, gas, miscellaneous, security,
+};
+
+pub const PRESETS: [&str; 4] = ["recommended", "all", "security", "gas"];
 
 pub fn create_rules_file(path: &str) {
     let rules = Rules {
         name: "solidhunter".to_string(),
         rules: create_default_rules(),
+        ..Default::default()
     };
     let serialized = serde_json::to_string_pretty(&rules).unwrap();
 
@@ -20,13 +26,59 @@ pub fn parse_rules(path: &str) -> Result<Rules, SolidHunterError> {
         )));
     }
     let file = std::fs::read_to_string(path)?;
-    let parsed: Rules = serde_json::from_str(&file)?;
-
-    Ok(parsed)
+    parse_rules_content(&file)
 }
 
 pub fn parse_rules_content(content: &str) -> Result<Rules, SolidHunterError> {
-    let parsed: Rules = serde_json::from_str(content)?;
+    let value: serde_json::Value = serde_json::from_str(content)?;
+    let errors = validate_rules(&value);
+    if !errors.is_empty() {
+        return Err(SolidHunterError::ConfigError(errors));
+    }
+    let parsed: Rules = serde_json::from_value(value)?;
 
     Ok(parsed)
 }
+
+pub fn create_preset_rules(preset: &str) -> Vec<RuleEntry> {
+    match preset {
+        "all" => {
+            let mut rules = create_default_rules();
+            merge_rules(&mut rules, &gas::create_default_rules());
+            merge_rules(&mut rules, &create_opt_in_rules());
+            rules
+        }
+        "recommended" => {
+            let mut rules = best_practises::create_default_rules();
+            rules.append(&mut miscellaneous::create_default_rules());
+            rules.append(&mut security::create_default_rules());
+            rules
+        }
+        "security" => security::create_default_rules(),
+        "gas" => gas::create_default_rules(),
+        _ => Vec::new(),
+    }
+}
+
+// Add the rules to the entries, replacing the ones with the same id
+pub fn merge_rules(entries: &mut Vec<RuleEntry>, rules: &[RuleEntry]) {
+    for rule in rules {
+        match entries.iter_mut().find(|entry| entry.id == rule.id) {
+            Some(entry) => *entry = rule.clone(),
+            None => entries.push(rule.clone()),
+        }
+    }
+}
+
+pub fn remove_rules(entries: &mut Vec<RuleEntry>, disabled: &[String]) {
+    entries.retain(|entry| !disabled.contains(&entry.id));
+}
+
+// Apply a config on top of the entries of its parent: its presets, then its own rules
+pub fn apply_rules(entries: &mut Vec<RuleEntry>, config: &Rules) {
+    for preset in &config.extends {
+        merge_rules(entries, &create_preset_rules(preset));
+    }
+    merge_rules(entries, &config.rules);
+    remove_rules(entries, &config.disabled);
+}
@@ -0,0 +1,151 @@
+use crate::rules::rule_impl::{create_preset_rules, PRESETS};
+use crate::rules::types::{DataSchema, RuleEntry};
+use crate::rules::validation::SEVERITIES;
+use crate::rules::{create_data_schemas, create_rules};
+use crate::types::Severity;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+const SCHEMA_DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+
+fn data_to_json_schema(schema: &DataSchema, default: Option<&Value>) -> Value {
+    let mut json_schema = match schema {
+        DataSchema::Integer => json!({ "type": "integer", "minimum": 0 }),
+        DataSchema::Boolean => json!({ "type": "boolean" }),
+        DataSchema::String => json!({ "type": "string" }),
+        DataSchema::Enum(values) => json!({ "type": "string", "enum": values }),
+        DataSchema::Array(items) => json!({
+            "type": "array",
+            "items": data_to_json_schema(items, None),
+        }),
+        DataSchema::Object(properties) => {
+            let properties: Map<String, Value> = properties
+                .iter()
+                .map(|(key, schema)| {
+                    let default = default.and_then(|default| default.get(*key));
+                    (key.to_string(), data_to_json_schema(schema, default))
+                })
+                .collect();
+            json!({
+                "type": "object",
+                "properties": properties,
+                "additionalProperties": false,
+            })
+        }
+    };
+
+    if let Some(default) = default.filter(|default| !default.is_null()) {
+        json_schema["default"] = default.clone();
+    }
+    json_schema
+}
+
+// Every rule, with the default entry of the rules enabled by a preset.
+// A rule without default entry gets the severity of its documentation.
+fn rule_definitions() -> Vec<Value> {
+    let builders = create_rules();
+    let schemas = create_data_schemas();
+    let mut defaults: HashMap<String, RuleEntry> = create_preset_rules("all")
+        .into_iter()
+        .map(|entry| (entry.id.clone(), entry))
+        .collect();
+    let mut ids: Vec<&String> = builders.keys().collect();
+    ids.sort();
+
+    ids.into_iter()
+        .map(|id| {
+            let build = builders[id];
+            let entry = defaults.remove(id).unwrap_or_else(|| {
+                let placeholder = RuleEntry {
+                    id: id.clone(),
+                    severity: Severity::WARNING,
+                    data: None,
+                };
+                RuleEntry {
+                    severity: build(placeholder.clone()).get_documentation().severity,
+                    ..placeholder
+                }
+            });
+            let documentation = build(entry.clone()).get_documentation();
+            let data = match schemas.get(&entry.id) {
+                Some(schema) => data_to_json_schema(schema, entry.data.as_ref()),
+                // rules without options ignore their data
+                None => json!({}),
+            };
+
+            json!({
+                "type": "object",
+                "description": documentation.description,
+                "properties": {
+                    "id": { "const": entry.id },
+                    "severity": {
+                        "$ref": "#/definitions/severity",
+                        "default": entry.severity,
+                    },
+                    "data": data,
+                },
+                "required": ["id", "severity"],
+                "additionalProperties": false,
+            })
+        })
+        .collect()
+}
+
+/// JSON Schema of the configuration file, with the rules and the options they take.
+pub fn create_config_schema() -> Value {
+    let mut rule_ids: Vec<String> = create_rules().into_keys().collect();
+    rule_ids.sort();
+
+    json!({
+        "$schema": SCHEMA_DRAFT,
+        "title": "Solidhunter configuration",
+        "type": "object",
+        "properties": {
+            "name": { "type": "string" },
+            "extends": {
+                "description": "Presets whose rules are added before the ones of this config",
+                "oneOf": [
+                    { "$ref": "#/definitions/preset" },
+                    { "type": "array", "items": { "$ref": "#/definitions/preset" } },
+                ],
+            },
+            "rules": { "$ref": "#/definitions/rules" },
+            "disabled": { "$ref": "#/definitions/disabled" },
+            "overrides": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "files": {
+                            "description": "Glob patterns of the files the override applies to, relative to the config file",
+                            "type": "array",
+                            "items": { "type": "string" },
+                        },
+                        "rules": { "$ref": "#/definitions/rules" },
+                        "disabled": { "$ref": "#/definitions/disabled" },
+                    },
+                    "required": ["files"],
+                    "additionalProperties": false,
+                },
+            },
+        },
+        "additionalProperties": false,
+        "definitions": {
+            "preset": { "type": "string", "enum": PRESETS },
+            "ruleId": { "type": "string", "enum": rule_ids },
+            "severity": {
+                "oneOf": [
+                    { "type": "string", "enum": SEVERITIES },
+                    { "type": "integer", "minimum": 1, "maximum": 4 },
+                ],
+            },
+            "rule": { "oneOf": rule_definitions() },
+            "rules": { "type": "array", "items": { "$ref": "#/definitions/rule" } },
+            "disabled": {
+                "description": "Ids of rules enabled by a preset or a parent config to turn off",
+                "type": "array",
+                "items": { "$ref": "#/definitions/ruleId" },
+            },
+        },
+    })
+}
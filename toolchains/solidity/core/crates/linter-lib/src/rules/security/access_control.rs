@@ -0,0 +1,161 @@
+use crate::linter::SolidFile;
+use crate::project::absolute_path;
+use crate::rules::types::LintContext;
+use crate::rules::utils::{normalize_member_access, visit_normalized_expr, NormalizedVisit};
+use osmium_libs_solidity_ast_extractor::retriever::retrieve_functions_nodes;
+use osmium_libs_solidity_ast_extractor::*;
+
+// Functions whose arguments are conditions reverting the call
+const CHECK_FUNCTIONS: [&str; 2] = ["require", "assert"];
+
+/// The modifiers restricting a function to its privileged callers by default.
+pub const DEFAULT_GUARD_MODIFIERS: &[&str] = &["onlyOwner", "onlyRole", "onlyAdmin"];
+
+/// Whether the function has one of the modifiers.
+pub fn has_guard_modifier(function: &ItemFunction, guard_modifiers: &[String]) -> bool {
+    function.attributes.iter().any(|attribute| match attribute {
+        FunctionAttribute::Modifier(modifier) => {
+            guard_modifiers.contains(&modifier.name.last().as_string())
+        }
+        _ => false,
+    })
+}
+
+/// The contract and its ancestors declared in the same file, whose bodies can be looked at.
+pub fn file_lineage<'a>(
+    file: &SolidFile,
+    context: &LintContext,
+    contracts: &'a [ItemContract],
+    contract: &'a ItemContract,
+) -> Vec<&'a ItemContract> {
+    let path = absolute_path(&file.path);
+    let mut lineage = vec![contract];
+    for ancestor in context
+        .project
+        .ancestors(&file.path, &contract.name.as_string())
+    {
+        if ancestor.file != path {
+            continue;
+        }
+        if let Some(item) = contracts.iter().find(|item| item.name == ancestor.name) {
+            lineage.push(item);
+        }
+    }
+    lineage
+}
+
+/// The configured guard modifiers, and the modifiers of the contracts whose body checks
+/// `msg.sender`.
+pub fn guard_modifiers(configured: &[String], lineage: &[&ItemContract]) -> Vec<String> {
+    let mut modifiers = configured.to_vec();
+    for contract in lineage {
+        for modifier in retrieve_functions_nodes(contract) {
+            let name = match (&modifier.kind, &modifier.name) {
+                (FunctionKind::Modifier(_), Some(name)) => name.as_string(),
+                _ => continue,
+            };
+            let mut checks = SenderCheckVisitor::default();
+            checks.visit_item_function(&modifier);
+            if checks.found && !modifiers.contains(&name) {
+                modifiers.push(name);
+            }
+        }
+    }
+    modifiers
+}
+
+pub fn is_public(function: &ItemFunction) -> bool {
+    function.attributes.has_external() || function.attributes.has_public()
+}
+
+/// The function called, without the call options of `target.call{value: 1}`.
+pub fn callee(call: &ExprCall) -> &Expr {
+    match &*call.expr {
+        Expr::CallOptions(options) => &options.expr,
+        callee => callee,
+    }
+}
+
+/// The address a call is made on, `target` for `target.delegatecall(data)`, and the
+/// name of the member function called.
+pub fn member_call(call: &ExprCall) -> Option<(&Expr, String)> {
+    match callee(call) {
+        Expr::Member(member) => match &*member.member {
+            Expr::Ident(name) => Some((&member.expr, name.as_string())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Every call made by the visited nodes, with the member accesses normalized.
+#[derive(Default)]
+pub struct CallsVisitor {
+    pub calls: Vec<ExprCall>,
+    in_expr: bool,
+}
+
+impl NormalizedVisit for CallsVisitor {
+    fn in_normalized_expr(&mut self) -> &mut bool {
+        &mut self.in_expr
+    }
+}
+
+impl<'ast> Visit<'ast> for CallsVisitor {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        visit_normalized_expr(self, expr);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        self.calls.push(call.clone());
+        visit::visit_expr_call(self, call);
+    }
+}
+
+fn is_sender(member: &ExprMember) -> bool {
+    matches!(
+        (&*member.expr, &*member.member),
+        (Expr::Ident(base), Expr::Ident(name)) if base == "msg" && name == "sender"
+    )
+}
+
+#[derive(Default)]
+struct SenderVisitor {
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for SenderVisitor {
+    fn visit_expr_member(&mut self, member: &'ast ExprMember) {
+        self.found |= is_sender(member);
+        visit::visit_expr_member(self, member);
+    }
+}
+
+fn uses_sender(expr: &Expr) -> bool {
+    let mut visitor = SenderVisitor::default();
+    visitor.visit_expr(&normalize_member_access(expr));
+    visitor.found
+}
+
+/// Whether the visited nodes check `msg.sender`, in the condition of an `if` or the
+/// arguments of a `require` or an `assert`.
+#[derive(Default)]
+pub struct SenderCheckVisitor {
+    pub found: bool,
+}
+
+impl<'ast> Visit<'ast> for SenderCheckVisitor {
+    fn visit_stmt_if(&mut self, stmt_if: &'ast StmtIf) {
+        self.found |= uses_sender(&stmt_if.cond);
+        visit::visit_stmt_if(self, stmt_if);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let Expr::Ident(name) = &*call.expr {
+            if CHECK_FUNCTIONS.contains(&name.as_string().as_str()) {
+                self.found |= uses_sender(&Expr::Call(call.clone()));
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::linter::SolidFile;
+use crate::rules::security::access_control::{
+    file_lineage, guard_modifiers, has_guard_modifier, is_public, member_call, CallsVisitor,
+    SenderCheckVisitor, DEFAULT_GUARD_MODIFIERS,
+};
+use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "controlled-delegatecall";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::ERROR;
+
+pub struct ControlledDelegatecall {
+    guard_modifiers: Vec<String>,
+    data: RuleEntry,
+}
+
+// The variable an address comes from, `target` for `payable(address(target))`
+fn address_source(expr: &Expr) -> Option<&SolIdent> {
+    match expr {
+        Expr::Ident(ident) => Some(ident),
+        Expr::Payable(payable) => match &payable.args.list {
+            ArgListImpl::Unnamed(args) if args.len() == 1 => address_source(&args[0]),
+            _ => None,
+        },
+        // a cast such as `address(target)`
+        Expr::Call(call) => match (&*call.expr, &call.args.list) {
+            (Expr::Type(_), ArgListImpl::Unnamed(args)) if args.len() == 1 => {
+                address_source(&args[0])
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl ControlledDelegatecall {
+    fn create_diag(&self, file: &SolidFile, call: &ExprCall, param: &SolIdent) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(call),
+            message: format!(
+                "Delegatecall to the address of parameter \"{}\", which any caller controls",
+                param.as_string()
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for ControlledDelegatecall {
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let contracts = retrieve_contract_nodes(&file.data);
+
+        for contract in &contracts {
+            // the modifiers checking the sender may be inherited from the contracts of the file
+            let lineage = file_lineage(file, context, &contracts, contract);
+            let guard_modifiers = guard_modifiers(&self.guard_modifiers, &lineage);
+            for function in retrieve_functions_nodes(contract) {
+                if !matches!(function.kind, FunctionKind::Function(_))
+                    || !is_public(&function)
+                    || has_guard_modifier(&function, &guard_modifiers)
+                {
+                    continue;
+                }
+                let mut checks = SenderCheckVisitor::default();
+                checks.visit_item_function(&function);
+                if checks.found {
+                    continue;
+                }
+                let params: Vec<String> = function
+                    .arguments
+                    .iter()
+                    .filter_map(|param| param.name.as_ref().map(|name| name.as_string()))
+                    .collect();
+
+                let mut calls = CallsVisitor::default();
+                calls.visit_item_function(&function);
+                for call in &calls.calls {
+                    let target = match member_call(call) {
+                        Some((target, method)) if method == "delegatecall" => target,
+                        _ => continue,
+                    };
+                    if let Some(source) = address_source(target) {
+                        if params.contains(&source.as_string()) {
+                            res.push(self.create_diag(file, call, source));
+                        }
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Public and external functions must not delegatecall an address given as parameter, which lets any caller run arbitrary code in the context of the contract, unless they have a guard modifier or check `msg.sender`.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"controlled-delegatecall\", \"severity\": \"ERROR\", \"data\": [\"onlyOwner\", \"onlyGovernance\"]}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/controlled_delegatecall.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/ControlledDelegatecall".to_string(),
+            options: vec![Options {
+                description: "Names of the modifiers protecting a function, besides the ones checking `msg.sender`".to_string(),
+                default: "[\"onlyOwner\", \"onlyRole\", \"onlyAdmin\"]".to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Delegatecall to a trusted implementation".to_string(),
+                    code: "function execute(bytes calldata data) external {\n    (bool success, ) = implementation.delegatecall(data);\n    require(success);\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Delegatecall to any address".to_string(),
+                    code: "function execute(address target, bytes calldata data) external {\n    (bool success, ) = target.delegatecall(data);\n    require(success);\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl ControlledDelegatecall {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut guard_modifiers: Vec<String> = DEFAULT_GUARD_MODIFIERS
+            .iter()
+            .map(|modifier| modifier.to_string())
+            .collect();
+
+        if let Some(data) = &data.data {
+            if let Ok(val) = serde_json::from_value::<Vec<String>>(data.clone()) {
+                guard_modifiers = val;
+            }
+        }
+        let rule = ControlledDelegatecall {
+            guard_modifiers,
+            data,
+        };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_GUARD_MODIFIERS.into()),
+        }
+    }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Array(Box::new(DataSchema::String))
+    }
+}
@@ -42,13 +42,14 @@ impl FuncVisibility {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for FuncVisibility {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
         let contracts =
             osmium_libs_solidity_ast_extractor::retriever::retrieve_contract_nodes(&file.data);
@@ -114,11 +115,7 @@ impl FuncVisibility {
                 && data["ignoreConstructors"].as_bool().is_some()
             {
                 ignore_constructors = data["ignoreConstructors"].as_bool().unwrap();
-            } else {
-                eprintln!("{} rule : bad config data", RULE_ID);
             }
-        } else {
-            eprintln!("{} rule : bad config data", RULE_ID);
         }
         let rule = FuncVisibility {
             ignore_constructors,
@@ -136,4 +133,8 @@ impl FuncVisibility {
             })),
         }
     }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Object(vec![("ignoreConstructors", DataSchema::Boolean)])
+    }
 }
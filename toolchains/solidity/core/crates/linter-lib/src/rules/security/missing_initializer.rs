@@ -0,0 +1,143 @@
+use crate::linter::SolidFile;
+use crate::rules::security::access_control::{has_guard_modifier, is_public};
+use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "missing-initializer";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::ERROR;
+const DEFAULT_INITIALIZER_MODIFIERS: &[&str] = &["initializer", "reinitializer"];
+const INITIALIZE_PREFIX: &str = "initialize";
+
+pub struct MissingInitializer {
+    initializer_modifiers: Vec<String>,
+    data: RuleEntry,
+}
+
+// Names of the base contracts of upgradeable contracts, such as `Initializable` or
+// `UUPSUpgradeable`
+fn is_upgradeable_base(name: &str) -> bool {
+    name.contains("Initializable") || name.ends_with("Upgradeable")
+}
+
+impl MissingInitializer {
+    fn create_diag(&self, file: &SolidFile, contract: &ItemContract, name: &SolIdent) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: range_from_spanned(name),
+            message: format!(
+                "Function \"{}\" of upgradeable contract \"{}\" can be called more than once, it is missing an initializer modifier",
+                name.as_string(),
+                contract.name.as_string()
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for MissingInitializer {
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retrieve_contract_nodes(&file.data) {
+            let name = contract.name.as_string();
+            // the bases written in the file and the ones of the ancestors found in the project
+            let mut bases: Vec<String> = contract
+                .inheritance
+                .iter()
+                .flat_map(|inheritance| inheritance.inheritance.iter())
+                .map(|base| base.name.last().as_string())
+                .collect();
+            for ancestor in context.project.ancestors(&file.path, &name) {
+                bases.push(ancestor.name.clone());
+                bases.extend(ancestor.bases.iter().cloned());
+            }
+            if !bases.iter().any(|base| is_upgradeable_base(base)) {
+                continue;
+            }
+
+            for function in retrieve_functions_nodes(&contract) {
+                let name = match (&function.kind, &function.name) {
+                    (FunctionKind::Function(_), Some(name)) => name,
+                    _ => continue,
+                };
+                if name.as_string().starts_with(INITIALIZE_PREFIX)
+                    && is_public(&function)
+                    && !has_guard_modifier(&function, &self.initializer_modifiers)
+                {
+                    res.push(self.create_diag(file, &contract, name));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "The `initialize` functions of upgradeable contracts, inheriting from `Initializable` or an `Upgradeable` contract, must have an initializer modifier so that they can only be called once.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"missing-initializer\", \"severity\": \"ERROR\", \"data\": [\"initializer\", \"reinitializer\"]}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/missing_initializer.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/MissingInitializer".to_string(),
+            options: vec![Options {
+                description: "Names of the initializer modifiers".to_string(),
+                default: "[\"initializer\", \"reinitializer\"]".to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Initializer modifier".to_string(),
+                    code: "function initialize(address owner) external initializer {\n    __Ownable_init(owner);\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Initialize function callable again".to_string(),
+                    code: "function initialize(address owner) external {\n    __Ownable_init(owner);\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl MissingInitializer {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut initializer_modifiers: Vec<String> = DEFAULT_INITIALIZER_MODIFIERS
+            .iter()
+            .map(|modifier| modifier.to_string())
+            .collect();
+
+        if let Some(data) = &data.data {
+            if let Ok(val) = serde_json::from_value::<Vec<String>>(data.clone()) {
+                initializer_modifiers = val;
+            }
+        }
+        let rule = MissingInitializer {
+            initializer_modifiers,
+            data,
+        };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_INITIALIZER_MODIFIERS.into()),
+        }
+    }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Array(Box::new(DataSchema::String))
+    }
+}
@@ -1,19 +1,30 @@
-use crate::rules::types::{RuleEntry, RulesMap};
+use crate::rules::types::{DataSchemasMap, RuleEntry, RulesMap};
 use std::collections::HashMap;
 
 #[macro_use]
 pub(crate) mod no_inline_assembly;
+pub(crate) mod access_control;
 pub(crate) mod avoid_tx_origin;
+pub(crate) mod controlled_delegatecall;
 pub(crate) mod func_visibility;
+pub(crate) mod missing_initializer;
 pub(crate) mod not_rely_on_time;
+pub(crate) mod reentrancy;
 pub(crate) mod state_visibility;
+pub(crate) mod unchecked_low_level_call;
+pub(crate) mod unprotected_critical_call;
 
 // List all rules
 use crate::rules::security::avoid_tx_origin::AvoidTxOrigin;
+use crate::rules::security::controlled_delegatecall::ControlledDelegatecall;
 use crate::rules::security::func_visibility::FuncVisibility;
+use crate::rules::security::missing_initializer::MissingInitializer;
 use crate::rules::security::no_inline_assembly::NoInlineAssembly;
 use crate::rules::security::not_rely_on_time::NotRelyOnTime;
+use crate::rules::security::reentrancy::Reentrancy;
 use crate::rules::security::state_visibility::StateVisibility;
+use crate::rules::security::unchecked_low_level_call::UncheckedLowLevelCall;
+use crate::rules::security::unprotected_critical_call::UnprotectedCriticalCall;
 
 use crate::rules::RuleBuilder;
 
@@ -24,6 +35,11 @@ pub fn create_default_rules() -> Vec<RuleEntry> {
         NotRelyOnTime::create_default(),
         FuncVisibility::create_default(),
         AvoidTxOrigin::create_default(),
+        Reentrancy::create_default(),
+        UncheckedLowLevelCall::create_default(),
+        UnprotectedCriticalCall::create_default(),
+        MissingInitializer::create_default(),
+        ControlledDelegatecall::create_default(),
     ]
 }
 
@@ -41,6 +57,47 @@ pub fn create_rules() -> RulesMap {
     );
     rules.insert(avoid_tx_origin::RULE_ID.to_string(), AvoidTxOrigin::create);
     rules.insert(not_rely_on_time::RULE_ID.to_string(), NotRelyOnTime::create);
+    rules.insert(reentrancy::RULE_ID.to_string(), Reentrancy::create);
+    rules.insert(
+        unchecked_low_level_call::RULE_ID.to_string(),
+        UncheckedLowLevelCall::create,
+    );
+    rules.insert(
+        unprotected_critical_call::RULE_ID.to_string(),
+        UnprotectedCriticalCall::create,
+    );
+    rules.insert(
+        missing_initializer::RULE_ID.to_string(),
+        MissingInitializer::create,
+    );
+    rules.insert(
+        controlled_delegatecall::RULE_ID.to_string(),
+        ControlledDelegatecall::create,
+    );
 
     rules
 }
+
+pub fn create_data_schemas() -> DataSchemasMap {
+    let mut schemas = HashMap::new();
+
+    schemas.insert(
+        func_visibility::RULE_ID.to_string(),
+        FuncVisibility::data_schema(),
+    );
+    schemas.insert(reentrancy::RULE_ID.to_string(), Reentrancy::data_schema());
+    schemas.insert(
+        unprotected_critical_call::RULE_ID.to_string(),
+        UnprotectedCriticalCall::data_schema(),
+    );
+    schemas.insert(
+        missing_initializer::RULE_ID.to_string(),
+        MissingInitializer::data_schema(),
+    );
+    schemas.insert(
+        controlled_delegatecall::RULE_ID.to_string(),
+        ControlledDelegatecall::data_schema(),
+    );
+
+    schemas
+}
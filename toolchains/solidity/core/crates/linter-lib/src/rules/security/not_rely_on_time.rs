@@ -32,17 +32,17 @@ impl NotRelyOnTime {
             severity: self.data.severity,
             code: None,
             source: None,
+            fix: None,
             uri: file.path.clone(),
         }
     }
 }
 
 impl RuleType for NotRelyOnTime {
-    fn diagnose(&self, file: &SolidFile, _files: &[SolidFile]) -> Vec<LintDiag> {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
         let mut res = Vec::new();
-        let mut i = 1;
 
-        for line in file.content.lines() {
+        for (i, line) in (1..).zip(file.content.lines()) {
             if let Some(index) = line.find("now") {
                 res.push(self.create_diag(
                     (
@@ -73,7 +73,6 @@ impl RuleType for NotRelyOnTime {
                     file,
                 ));
             }
-            i += 1;
         }
         res
     }
@@ -0,0 +1,469 @@
+use crate::linter::SolidFile;
+use crate::project::{absolute_path, ContractType};
+use crate::rules::types::*;
+use crate::rules::utils::{is_assignment, normalize_member_access, range_from_spanned, root_ident};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+use std::collections::{HashMap, HashSet};
+
+// global
+pub const RULE_ID: &str = "reentrancy";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+pub const DEFAULT_GUARD_MODIFIERS: &[&str] = &["nonReentrant"];
+const LOW_LEVEL_CALLS: [&str; 3] = ["call", "transfer", "send"];
+
+pub struct Reentrancy {
+    guard_modifiers: Vec<String>,
+    data: RuleEntry,
+}
+
+// The variables declared in a function, with their type
+#[derive(Default)]
+struct LocalsVisitor {
+    locals: Vec<VariableDeclaration>,
+}
+
+impl<'ast> Visit<'ast> for LocalsVisitor {
+    fn visit_variable_declaration(&mut self, var: &'ast VariableDeclaration) {
+        self.locals.push(var.clone());
+    }
+}
+
+// A state variable written after an external call
+struct StateWrite {
+    range: Range,
+    name: String,
+    call_line: usize,
+}
+
+// Walks a function body in execution order, remembering whether an external call may
+// already have been made on the way to each state variable write
+struct FunctionWalker<'a> {
+    state_vars: &'a HashSet<String>,
+    // the state and local variables whose type is a contract or an interface
+    contract_vars: HashSet<String>,
+    contract_names: &'a HashSet<String>,
+    // line of the first external call made before the current statement
+    call_line: Option<usize>,
+    writes: Vec<StateWrite>,
+}
+
+impl<'a> FunctionWalker<'a> {
+    fn is_external_call(&self, call: &ExprCall) -> bool {
+        let callee = match &*call.expr {
+            Expr::CallOptions(options) => &*options.expr,
+            callee => callee,
+        };
+        let member = match callee {
+            Expr::Member(member) => member,
+            _ => return false,
+        };
+        if let Expr::Ident(name) = &*member.member {
+            if LOW_LEVEL_CALLS.contains(&name.as_string().as_str()) {
+                return true;
+            }
+        }
+        match &*member.expr {
+            Expr::Ident(ident) => self.contract_vars.contains(&ident.as_string()),
+            // a call on a casted address, `IERC20(token).transfer(...)`
+            Expr::Call(cast) => match &*cast.expr {
+                Expr::Ident(ident) => self.contract_names.contains(&ident.as_string()),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn record_write(&mut self, target: &Expr, range: Range) {
+        if let Expr::Tuple(tuple) = target {
+            for elem in tuple.elems.iter() {
+                self.record_write(elem, range_from_spanned(elem));
+            }
+            return;
+        }
+        let (ident, call_line) = match (root_ident(target), self.call_line) {
+            (Some(ident), Some(call_line)) => (ident, call_line),
+            _ => return,
+        };
+        let name = ident.as_string();
+        if self.state_vars.contains(&name) && !self.writes.iter().any(|w| w.range == range) {
+            self.writes.push(StateWrite {
+                range,
+                name,
+                call_line,
+            });
+        }
+    }
+
+    // Walk the expressions evaluated to find the written variable, such as an index
+    fn walk_target(&mut self, target: &Expr) {
+        match target {
+            Expr::Index(index) => {
+                self.walk_target(&index.expr);
+                if let Some(start) = &index.start {
+                    self.walk_expr(start);
+                }
+                if let Some(end) = &index.end {
+                    self.walk_expr(end);
+                }
+            }
+            Expr::Member(member) => self.walk_target(&member.expr),
+            Expr::Tuple(tuple) => tuple.elems.iter().for_each(|elem| self.walk_target(elem)),
+            _ => {}
+        }
+    }
+
+    fn walk_args(&mut self, args: &ArgList) {
+        match &args.list {
+            ArgListImpl::Unnamed(args) => args.iter().for_each(|arg| self.walk_expr(arg)),
+            ArgListImpl::Named(args) => args.list.iter().for_each(|arg| self.walk_expr(&arg.arg)),
+        }
+    }
+
+    fn walk_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Binary(binary) if is_assignment(&binary.op) => {
+                self.walk_expr(&binary.right);
+                self.walk_target(&binary.left);
+                self.record_write(&binary.left, range_from_spanned(&*binary.left));
+            }
+            Expr::Binary(binary) => {
+                self.walk_expr(&binary.left);
+                self.walk_expr(&binary.right);
+            }
+            Expr::Call(call) => {
+                self.walk_expr(&call.expr);
+                self.walk_args(&call.args);
+                if let Expr::Member(member) = &*call.expr {
+                    if let Expr::Ident(method) = &*member.member {
+                        if method == "push" || method == "pop" {
+                            self.record_write(&member.expr, range_from_spanned(call));
+                        }
+                    }
+                }
+                if self.is_external_call(call) && self.call_line.is_none() {
+                    self.call_line = Some(call.span().start().line);
+                }
+            }
+            Expr::CallOptions(options) => {
+                self.walk_expr(&options.expr);
+                options
+                    .args
+                    .list
+                    .iter()
+                    .for_each(|arg| self.walk_expr(&arg.arg));
+            }
+            Expr::Unary(unary) => {
+                self.walk_expr(&unary.expr);
+                if matches!(unary.op, UnOp::Increment(..) | UnOp::Decrement(..)) {
+                    self.record_write(&unary.expr, range_from_spanned(unary));
+                }
+            }
+            Expr::Postfix(postfix) => {
+                self.walk_expr(&postfix.expr);
+                self.record_write(&postfix.expr, range_from_spanned(postfix));
+            }
+            Expr::Delete(delete) => {
+                self.walk_target(&delete.expr);
+                self.record_write(&delete.expr, range_from_spanned(delete));
+            }
+            Expr::Index(index) => {
+                self.walk_expr(&index.expr);
+                if let Some(start) = &index.start {
+                    self.walk_expr(start);
+                }
+                if let Some(end) = &index.end {
+                    self.walk_expr(end);
+                }
+            }
+            Expr::Member(member) => self.walk_expr(&member.expr),
+            Expr::Ternary(ternary) => {
+                self.walk_expr(&ternary.cond);
+                self.walk_expr(&ternary.if_true);
+                self.walk_expr(&ternary.if_false);
+            }
+            Expr::Tuple(tuple) => tuple.elems.iter().for_each(|elem| self.walk_expr(elem)),
+            Expr::Array(array) => array.elems.iter().for_each(|elem| self.walk_expr(elem)),
+            Expr::Payable(payable) => self.walk_args(&payable.args),
+            _ => {}
+        }
+    }
+
+    fn walk_root(&mut self, expr: &Expr) {
+        self.walk_expr(&normalize_member_access(expr));
+    }
+
+    // Walk a loop body twice, a call at the end of an iteration preceding the writes at
+    // the start of the next one
+    fn walk_loop(&mut self, mut walk: impl FnMut(&mut Self)) {
+        walk(self);
+        walk(self);
+    }
+
+    fn walk_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Block(block) => self.walk_block(block),
+            Stmt::UncheckedBlock(unchecked) => self.walk_block(&unchecked.block),
+            Stmt::Expr(stmt) => self.walk_root(&stmt.expr),
+            Stmt::VarDecl(var_decl) => {
+                if let Some((_, expr)) = &var_decl.assignment {
+                    self.walk_root(expr);
+                }
+            }
+            Stmt::If(stmt_if) => {
+                self.walk_root(&stmt_if.cond);
+                let before = self.call_line;
+                self.walk_stmt(&stmt_if.then_branch);
+                let after_then = self.call_line;
+                self.call_line = before;
+                if let Some((_, else_branch)) = &stmt_if.else_branch {
+                    self.walk_stmt(else_branch);
+                }
+                self.call_line = self.call_line.or(after_then);
+            }
+            Stmt::For(stmt_for) => {
+                match &stmt_for.init {
+                    ForInitStmt::VarDecl(var_decl) => {
+                        if let Some((_, expr)) = &var_decl.assignment {
+                            self.walk_root(expr);
+                        }
+                    }
+                    ForInitStmt::Expr(stmt) => self.walk_root(&stmt.expr),
+                    ForInitStmt::Empty(_) => {}
+                }
+                self.walk_loop(|walker| {
+                    if let Some(cond) = &stmt_for.cond {
+                        walker.walk_root(cond);
+                    }
+                    walker.walk_stmt(&stmt_for.body);
+                    if let Some(post) = &stmt_for.post {
+                        walker.walk_root(post);
+                    }
+                });
+            }
+            Stmt::While(stmt_while) => self.walk_loop(|walker| {
+                walker.walk_root(&stmt_while.cond);
+                walker.walk_stmt(&stmt_while.body);
+            }),
+            Stmt::DoWhile(do_while) => self.walk_loop(|walker| {
+                walker.walk_stmt(&do_while.body);
+                walker.walk_root(&do_while.cond);
+            }),
+            Stmt::Try(stmt_try) => {
+                self.walk_root(&stmt_try.expr);
+                // the tried expression is an external call, even on an unknown type
+                let before = self.call_line.or(Some(stmt_try.span().start().line));
+                self.call_line = before;
+                self.walk_block(&stmt_try.block);
+                let mut after = self.call_line;
+                for catch in &stmt_try.catch {
+                    self.call_line = before;
+                    self.walk_block(&catch.block);
+                    after = after.or(self.call_line);
+                }
+                self.call_line = after;
+            }
+            Stmt::Return(stmt_return) => {
+                if let Some(expr) = &stmt_return.expr {
+                    self.walk_root(expr);
+                }
+            }
+            Stmt::Emit(emit) => self.walk_root(&emit.expr),
+            Stmt::Revert(revert) => self.walk_root(&revert.expr),
+            _ => {}
+        }
+    }
+
+    fn walk_block(&mut self, block: &Block) {
+        for stmt in &block.stmts {
+            self.walk_stmt(stmt);
+        }
+    }
+}
+
+fn custom_type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Custom(path) => Some(path.last().as_string()),
+        _ => None,
+    }
+}
+
+impl Reentrancy {
+    fn create_diag(&self, file: &SolidFile, write: StateWrite) -> LintDiag {
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: write.range,
+            message: format!(
+                "Possible reentrancy: state variable \"{}\" is written after the external call on line {}",
+                write.name, write.call_line
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+
+    fn is_guarded(&self, function: &ItemFunction) -> bool {
+        function.attributes.iter().any(|attribute| match attribute {
+            FunctionAttribute::Modifier(modifier) => self
+                .guard_modifiers
+                .contains(&modifier.name.last().as_string()),
+            _ => false,
+        })
+    }
+}
+
+impl RuleType for Reentrancy {
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let path = absolute_path(&file.path);
+        let contracts = retrieve_contract_nodes(&file.data);
+
+        let contract_names: HashSet<String> = context
+            .project
+            .files()
+            .flat_map(|summary| &summary.contracts)
+            .filter(|contract| contract.kind != ContractType::Library)
+            .map(|contract| contract.name.clone())
+            .collect();
+        let state_vars_of: HashMap<String, Vec<VariableDefinition>> = contracts
+            .iter()
+            .map(|contract| {
+                let vars = contract
+                    .body
+                    .iter()
+                    .filter_map(|item| match item {
+                        Item::Variable(var) => Some(var.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                (contract.name.as_string(), vars)
+            })
+            .collect();
+
+        for contract in &contracts {
+            let name = contract.name.as_string();
+            // the state variables inherited from the contracts of the same file
+            let mut state_defs: Vec<&VariableDefinition> = state_vars_of[&name].iter().collect();
+            for ancestor in context.project.ancestors(&file.path, &name) {
+                if ancestor.file == path {
+                    state_defs.extend(state_vars_of.get(&ancestor.name).into_iter().flatten());
+                }
+            }
+
+            for function in retrieve_functions_nodes(contract) {
+                let body = match (&function.kind, &function.body) {
+                    (FunctionKind::Constructor(_) | FunctionKind::Modifier(_), _) => continue,
+                    (_, FunctionBody::Block(body)) => body,
+                    (_, FunctionBody::Empty(_)) => continue,
+                };
+                if self.is_guarded(&function) {
+                    continue;
+                }
+
+                let mut locals = LocalsVisitor::default();
+                locals.visit_item_function(&function);
+                let local_names: HashSet<String> = locals
+                    .locals
+                    .iter()
+                    .filter_map(|var| var.name.as_ref().map(|name| name.as_string()))
+                    .collect();
+                let state_vars: HashSet<String> = state_defs
+                    .iter()
+                    .map(|var| var.name.as_string())
+                    .filter(|name| !local_names.contains(name))
+                    .collect();
+                let typed_vars = state_defs
+                    .iter()
+                    .map(|var| (var.name.as_string(), custom_type_name(&var.ty)))
+                    .chain(locals.locals.iter().filter_map(|var| {
+                        var.name
+                            .as_ref()
+                            .map(|name| (name.as_string(), custom_type_name(&var.ty)))
+                    }));
+                let contract_vars = typed_vars
+                    .filter(|(_, ty)| ty.as_ref().is_some_and(|ty| contract_names.contains(ty)))
+                    .map(|(name, _)| name)
+                    .collect();
+
+                let mut walker = FunctionWalker {
+                    state_vars: &state_vars,
+                    contract_vars,
+                    contract_names: &contract_names,
+                    call_line: None,
+                    writes: Vec::new(),
+                };
+                walker.walk_block(body);
+                for write in walker.writes {
+                    res.push(self.create_diag(file, write));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "State variables must not be written after an external call (checks-effects-interactions pattern), unless the function is guarded against reentrancy. The external calls are the `call`, `transfer` and `send` members and the calls on variables whose type is a contract of the project.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"reentrancy\", \"severity\": \"WARNING\", \"data\": [\"nonReentrant\", \"lock\"]}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/reentrancy.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/Reentrancy".to_string(),
+            options: vec![Options {
+                description: "Modifiers guarding a function against reentrancy".to_string(),
+                default: "[\"nonReentrant\"]".to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "State updated before the external call".to_string(),
+                    code: "balances[msg.sender] = 0;\n(bool success, ) = msg.sender.call{value: amount}(\"\");".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "State updated after the external call".to_string(),
+                    code: "(bool success, ) = msg.sender.call{value: amount}(\"\");\nbalances[msg.sender] = 0;".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl Reentrancy {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut guard_modifiers: Vec<String> = DEFAULT_GUARD_MODIFIERS
+            .iter()
+            .map(|modifier| modifier.to_string())
+            .collect();
+
+        if let Some(data) = &data.data {
+            if let Ok(val) = serde_json::from_value::<Vec<String>>(data.clone()) {
+                guard_modifiers = val;
+            }
+        }
+        let rule = Reentrancy {
+            guard_modifiers,
+            data,
+        };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_GUARD_MODIFIERS.into()),
+        }
+    }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Array(Box::new(DataSchema::String))
+    }
+}
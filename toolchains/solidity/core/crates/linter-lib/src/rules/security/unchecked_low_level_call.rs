@@ -0,0 +1,243 @@
+use crate::linter::SolidFile;
+use crate::rules::types::*;
+use crate::rules::utils::{normalize_member_access, range_from_spanned, ReferencesVisitor};
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+
+// global
+pub const RULE_ID: &str = "unchecked-low-level-call";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::WARNING;
+const LOW_LEVEL_CALLS: [&str; 4] = ["call", "delegatecall", "staticcall", "send"];
+
+pub struct UncheckedLowLevelCall {
+    data: RuleEntry,
+}
+
+// The name of the low-level function called by `expr`, such as `call` for
+// `target.call{value: 1}("")`
+fn low_level_call(expr: &Expr) -> Option<String> {
+    let call = match expr {
+        Expr::Call(call) => call,
+        _ => return None,
+    };
+    let callee = match &*call.expr {
+        Expr::CallOptions(options) => &*options.expr,
+        callee => callee,
+    };
+    match callee {
+        Expr::Member(member) => match &*member.member {
+            Expr::Ident(name) if LOW_LEVEL_CALLS.contains(&name.as_string().as_str()) => {
+                Some(name.as_string())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// A low-level call whose result is ignored, or the name of the variable it is bound to
+enum CallResult {
+    Ignored,
+    Bound(String),
+}
+
+struct UncheckedCall {
+    range: Range,
+    name: String,
+    result: CallResult,
+}
+
+#[derive(Default)]
+struct CallsVisitor {
+    calls: Vec<UncheckedCall>,
+    // the names used in a `require` or `assert`, an `if` condition or a `return`
+    checks: ReferencesVisitor,
+}
+
+impl CallsVisitor {
+    fn push(&mut self, expr: &Expr, result: CallResult) {
+        let expr = normalize_member_access(expr);
+        if let Some(name) = low_level_call(&expr) {
+            self.calls.push(UncheckedCall {
+                range: range_from_spanned(&expr),
+                name,
+                result,
+            });
+        }
+    }
+
+    // A call assigned to existing variables, as in `(success, ) = target.call("")`
+    fn push_assignment(&mut self, binary: &ExprBinary) {
+        // the success flag is the first element of the tuple
+        let first = match &*binary.left {
+            Expr::Tuple(tuple) => tuple.elems.first(),
+            target => Some(target),
+        };
+        match first {
+            Some(Expr::Ident(name)) => {
+                self.push(&binary.right, CallResult::Bound(name.as_string()))
+            }
+            // the result is stored, as in `results[i] = target.call("")`
+            Some(_) => {}
+            None => self.push(&binary.right, CallResult::Ignored),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for CallsVisitor {
+    fn visit_stmt(&mut self, stmt: &'ast Stmt) {
+        match stmt {
+            Stmt::Expr(stmt) => match normalize_member_access(&stmt.expr) {
+                Expr::Binary(binary) if matches!(binary.op, BinOp::Assign(..)) => {
+                    self.push_assignment(&binary)
+                }
+                expr => self.push(&expr, CallResult::Ignored),
+            },
+            Stmt::VarDecl(var_decl) => {
+                if let Some((_, expr)) = &var_decl.assignment {
+                    // the success flag is the first element of the tuple
+                    let first = match &var_decl.declaration {
+                        VarDeclDecl::VarDecl(var) => Some(var),
+                        VarDeclDecl::Tuple(tuple) => tuple.vars.first().and_then(Option::as_ref),
+                    };
+                    let result = match first {
+                        Some(VariableDeclaration {
+                            name: Some(name), ..
+                        }) => CallResult::Bound(name.as_string()),
+                        // `(success, ) = ...` is parsed as a tuple of unnamed declarations of
+                        // the type `success`, it assigns the existing variable
+                        Some(VariableDeclaration {
+                            name: None,
+                            ty: Type::Custom(path),
+                            ..
+                        }) => CallResult::Bound(path.first().as_string()),
+                        _ => CallResult::Ignored,
+                    };
+                    self.push(expr, result);
+                }
+            }
+            Stmt::If(stmt_if) => self.checks.visit_expr(&stmt_if.cond),
+            Stmt::Return(stmt_return) => {
+                if let Some(expr) = &stmt_return.expr {
+                    self.checks.visit_expr(expr);
+                }
+            }
+            _ => {}
+        }
+        visit::visit_stmt(self, stmt);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let Expr::Ident(name) = &*call.expr {
+            if name == "require" || name == "assert" {
+                self.checks.visit_expr_call(call);
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+}
+
+impl UncheckedLowLevelCall {
+    fn create_diag(&self, file: &SolidFile, call: UncheckedCall) -> LintDiag {
+        let message = match call.result {
+            CallResult::Ignored => format!(
+                "Return value of low-level call \"{}\" is not checked",
+                call.name
+            ),
+            CallResult::Bound(var) => format!(
+                "Return value of low-level call \"{}\" is assigned to \"{}\" but never checked",
+                call.name, var
+            ),
+        };
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range: call.range,
+            message,
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for UncheckedLowLevelCall {
+    fn diagnose(&self, file: &SolidFile, _context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+
+        for contract in retrieve_contract_nodes(&file.data) {
+            for function in retrieve_functions_nodes(&contract) {
+                let mut visitor = CallsVisitor::default();
+                visitor.visit_item_function(&function);
+
+                let CallsVisitor { calls, checks } = visitor;
+                for call in calls {
+                    let checked = match &call.result {
+                        CallResult::Bound(var) => checks.idents.contains(var),
+                        CallResult::Ignored => false,
+                    };
+                    if !checked {
+                        res.push(self.create_diag(file, call));
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "The boolean returned by the low-level calls `call`, `delegatecall`, `staticcall` and `send` must be checked, as they do not revert when the call fails. A result is checked when its variable is used in a `require` or `assert`, an `if` condition or a `return`.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"unchecked-low-level-call\", \"severity\": \"WARNING\"}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/unchecked_low_level_call.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/UncheckedLowLevelCall".to_string(),
+            options: vec![],
+            examples: Examples {
+                good: vec![
+                    Example {
+                        description: "Result checked with require".to_string(),
+                        code: "(bool success, ) = to.call{value: amount}(\"\");\nrequire(success);".to_string(),
+                    },
+                    Example {
+                        description: "Result checked with if".to_string(),
+                        code: "if (!payable(to).send(amount)) {\n    revert TransferFailed();\n}".to_string(),
+                    },
+                ],
+                bad: vec![
+                    Example {
+                        description: "Result ignored".to_string(),
+                        code: "payable(to).send(amount);".to_string(),
+                    },
+                    Example {
+                        description: "Success flag not bound".to_string(),
+                        code: "(, bytes memory data) = target.delegatecall(payload);".to_string(),
+                    },
+                ],
+            },
+        }
+    }
+}
+
+impl UncheckedLowLevelCall {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let rule = UncheckedLowLevelCall { data };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::linter::SolidFile;
+use crate::rules::security::access_control::{
+    callee, file_lineage, guard_modifiers, has_guard_modifier, is_public, member_call,
+    CallsVisitor, SenderCheckVisitor, DEFAULT_GUARD_MODIFIERS,
+};
+use crate::rules::types::*;
+use crate::rules::utils::range_from_spanned;
+use crate::types::*;
+use osmium_libs_solidity_ast_extractor::retriever::{
+    retrieve_contract_nodes, retrieve_functions_nodes,
+};
+use osmium_libs_solidity_ast_extractor::*;
+use std::collections::{HashMap, HashSet};
+
+// global
+pub const RULE_ID: &str = "unprotected-critical-call";
+
+// specific
+const DEFAULT_SEVERITY: Severity = Severity::ERROR;
+const SELFDESTRUCT_FUNCTIONS: [&str; 2] = ["selfdestruct", "suicide"];
+
+pub struct UnprotectedCriticalCall {
+    guard_modifiers: Vec<String>,
+    data: RuleEntry,
+}
+
+// What a function does that matters to find the unprotected critical calls
+struct FunctionSummary {
+    critical: Vec<&'static str>,
+    // the internal functions called
+    callees: Vec<String>,
+    checks_sender: bool,
+    guarded: bool,
+}
+
+impl UnprotectedCriticalCall {
+    fn summarize(&self, function: &ItemFunction, guard_modifiers: &[String]) -> FunctionSummary {
+        let mut calls = CallsVisitor::default();
+        calls.visit_item_function(function);
+        let mut checks = SenderCheckVisitor::default();
+        checks.visit_item_function(function);
+
+        let mut critical = Vec::new();
+        let mut callees = Vec::new();
+        for call in &calls.calls {
+            match (callee(call), member_call(call)) {
+                (Expr::Ident(name), _) => {
+                    let name = name.as_string();
+                    if SELFDESTRUCT_FUNCTIONS.contains(&name.as_str()) {
+                        critical.push("selfdestruct");
+                    } else {
+                        callees.push(name);
+                    }
+                }
+                (_, Some((_, method))) if method == "delegatecall" => critical.push("delegatecall"),
+                _ => {}
+            }
+        }
+        FunctionSummary {
+            critical,
+            callees,
+            checks_sender: checks.found,
+            guarded: has_guard_modifier(function, guard_modifiers),
+        }
+    }
+
+    // The critical calls reachable from a function through the internal calls, none if
+    // one of the functions reached checks the sender
+    fn reachable_critical(
+        &self,
+        name: &str,
+        summaries: &HashMap<String, Vec<FunctionSummary>>,
+        entry: &FunctionSummary,
+    ) -> Vec<&'static str> {
+        let mut critical: Vec<&'static str> = Vec::new();
+        let mut visited: HashSet<&str> = HashSet::from([name]);
+        let mut queue: Vec<&FunctionSummary> = vec![entry];
+
+        while let Some(summary) = queue.pop() {
+            if summary.checks_sender {
+                return Vec::new();
+            }
+            for kind in &summary.critical {
+                if !critical.contains(kind) {
+                    critical.push(kind);
+                }
+            }
+            for callee in &summary.callees {
+                if !visited.insert(callee) {
+                    continue;
+                }
+                let overloads = summaries.get(callee).into_iter().flatten();
+                queue.extend(overloads.filter(|summary| !summary.guarded));
+            }
+        }
+        critical
+    }
+
+    fn create_diag(
+        &self,
+        file: &SolidFile,
+        function: &ItemFunction,
+        critical: &[&str],
+    ) -> LintDiag {
+        let (range, name) = match &function.name {
+            Some(name) => (range_from_spanned(name), name.as_string()),
+            None => (
+                range_from_spanned(&function.kind),
+                function.kind.as_str().to_string(),
+            ),
+        };
+        LintDiag {
+            id: RULE_ID.to_string(),
+            range,
+            message: format!(
+                "Function \"{}\" can reach {} without an access control modifier or a msg.sender check",
+                name,
+                critical.join(" and ")
+            ),
+            severity: self.data.severity,
+            code: None,
+            source: None,
+            fix: None,
+            uri: file.path.clone(),
+        }
+    }
+}
+
+impl RuleType for UnprotectedCriticalCall {
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag> {
+        let mut res = Vec::new();
+        let contracts = retrieve_contract_nodes(&file.data);
+
+        for contract in &contracts {
+            // the internal functions and modifiers inherited from the contracts of the same file
+            let lineage = file_lineage(file, context, &contracts, contract);
+            let guard_modifiers = guard_modifiers(&self.guard_modifiers, &lineage);
+            let functions: Vec<ItemFunction> = lineage
+                .iter()
+                .flat_map(|item| retrieve_functions_nodes(item))
+                .collect();
+
+            let mut summaries: HashMap<String, Vec<FunctionSummary>> = HashMap::new();
+            for function in &functions {
+                if let (Some(name), FunctionKind::Function(_)) = (&function.name, &function.kind) {
+                    summaries
+                        .entry(name.as_string())
+                        .or_default()
+                        .push(self.summarize(function, &guard_modifiers));
+                }
+            }
+
+            for function in retrieve_functions_nodes(contract) {
+                let entry_point = matches!(
+                    function.kind,
+                    FunctionKind::Function(_)
+                        | FunctionKind::Fallback(_)
+                        | FunctionKind::Receive(_)
+                );
+                if !entry_point || !is_public(&function) {
+                    continue;
+                }
+                let summary = self.summarize(&function, &guard_modifiers);
+                if summary.guarded {
+                    continue;
+                }
+                let name = function
+                    .name
+                    .as_ref()
+                    .map(|name| name.as_string())
+                    .unwrap_or_default();
+                let critical = self.reachable_critical(&name, &summaries, &summary);
+                if !critical.is_empty() {
+                    res.push(self.create_diag(file, &function, &critical));
+                }
+            }
+        }
+        res
+    }
+
+    fn get_documentation(&self) -> RuleDocumentation {
+        RuleDocumentation {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            description: "Public and external functions must not reach `selfdestruct` or `delegatecall`, directly or through internal functions, without a guard modifier or a check of `msg.sender`.".to_string(),
+            category: "security".to_string(),
+            example_config: "{\"id\": \"unprotected-critical-call\", \"severity\": \"ERROR\", \"data\": [\"onlyOwner\", \"onlyGovernance\"]}".to_string(),
+            source_link: "https://github.com/astrodevs-labs/osmium/blob/main/toolchains/solidity/core/crates/linter-lib/src/rules/security/unprotected_critical_call.rs".to_string(),
+            test_link: "https://github.com/astrodevs-labs/osmium/tree/main/toolchains/solidity/core/crates/linter-lib/testdata/UnprotectedCriticalCall".to_string(),
+            options: vec![Options {
+                description: "Names of the modifiers protecting a function, besides the ones checking `msg.sender`".to_string(),
+                default: "[\"onlyOwner\", \"onlyRole\", \"onlyAdmin\"]".to_string(),
+            }],
+            examples: Examples {
+                good: vec![Example {
+                    description: "Function restricted to the owner".to_string(),
+                    code: "function kill() external onlyOwner {\n    selfdestruct(payable(msg.sender));\n}".to_string(),
+                }],
+                bad: vec![Example {
+                    description: "Anyone can destroy the contract".to_string(),
+                    code: "function kill() external {\n    selfdestruct(payable(msg.sender));\n}".to_string(),
+                }],
+            },
+        }
+    }
+}
+
+impl UnprotectedCriticalCall {
+    pub(crate) fn create(data: RuleEntry) -> Box<dyn RuleType> {
+        let mut guard_modifiers: Vec<String> = DEFAULT_GUARD_MODIFIERS
+            .iter()
+            .map(|modifier| modifier.to_string())
+            .collect();
+
+        if let Some(data) = &data.data {
+            if let Ok(val) = serde_json::from_value::<Vec<String>>(data.clone()) {
+                guard_modifiers = val;
+            }
+        }
+        let rule = UnprotectedCriticalCall {
+            guard_modifiers,
+            data,
+        };
+        Box::new(rule)
+    }
+
+    pub(crate) fn create_default() -> RuleEntry {
+        RuleEntry {
+            id: RULE_ID.to_string(),
+            severity: DEFAULT_SEVERITY,
+            data: Some(DEFAULT_GUARD_MODIFIERS.into()),
+        }
+    }
+
+    pub(crate) fn data_schema() -> DataSchema {
+        DataSchema::Array(Box::new(DataSchema::String))
+    }
+}
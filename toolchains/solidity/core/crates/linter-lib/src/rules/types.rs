@@ -1,8 +1,10 @@
 use crate::linter::SolidFile;
+use crate::project::ProjectGraph;
 use crate::types::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Options {
@@ -43,15 +45,88 @@ pub struct RuleEntry {
     pub data: Option<Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RulesOverride {
+    /// Glob patterns of the files the override applies to, relative to the config file.
+    /// A pattern without `/` is matched against the file name.
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub rules: Vec<RuleEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Rules {
+    #[serde(default)]
     pub name: String,
+    /// Presets whose rules are added before the ones of this config.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "one_or_many"
+    )]
+    pub extends: Vec<String>,
+    #[serde(default)]
     pub rules: Vec<RuleEntry>,
+    /// Ids of rules enabled by a preset or a parent config to turn off.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<RulesOverride>,
+}
+
+/// Where to disable a rule for a file: the config closest to the file, and the last of its
+/// overrides matching the file and enabling the rule, whose `disabled` applies after it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleLocation {
+    pub config_path: PathBuf,
+    pub override_index: Option<usize>,
+}
+
+// Accept `"extends": "recommended"` as well as a list of presets
+fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => vec![value],
+        OneOrMany::Many(values) => values,
+    })
+}
+
+/// Shape of the `data` of a rule entry, checked when the configuration is validated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataSchema {
+    /// A positive integer.
+    Integer,
+    Boolean,
+    String,
+    /// One of the given strings.
+    Enum(Vec<&'static str>),
+    Array(Box<DataSchema>),
+    /// An object whose properties are all optional.
+    Object(Vec<(&'static str, DataSchema)>),
+}
+
+/// What a rule can look at besides the file it diagnoses.
+pub struct LintContext<'a> {
+    /// The imports and contracts of every file known to the linter.
+    pub project: &'a ProjectGraph,
 }
 
 pub trait RuleType: Send + Sync + 'static {
-    fn diagnose(&self, file: &SolidFile, files: &[SolidFile]) -> Vec<LintDiag>;
+    fn diagnose(&self, file: &SolidFile, context: &LintContext) -> Vec<LintDiag>;
     fn get_documentation(&self) -> RuleDocumentation;
 }
 
 pub type RulesMap = HashMap<String, fn(RuleEntry) -> Box<dyn RuleType>>;
+
+pub type DataSchemasMap = HashMap<String, DataSchema>;
@@ -1,4 +1,12 @@
-use osmium_libs_solidity_ast_extractor::LineColumn;
+use crate::types::{offset_from_position, Position, Range};
+use osmium_libs_solidity_ast_extractor::{
+    visit, ArgList, ArgListImpl, BinOp, Expr, ExprArray, ExprBinary, ExprCall, ExprCallOptions,
+    ExprDelete, ExprIndex, ExprMember, ExprPayable, ExprPostfix, ExprTernary, ExprTuple, ExprUnary,
+    FunctionAttribute, ItemContract, ItemFunction, LineColumn, NamedArg, NamedArgList, SolIdent,
+    SolPath, Spanned, StmtAssembly, UnOp, UsingDirective, UsingList, UsingType, VariableAttribute,
+    VariableDefinition, Visit,
+};
+use std::collections::HashSet;
 
 pub fn absolute_index_from_location(location: LineColumn, content: &str) -> usize {
     let mut index = 0;
@@ -19,3 +27,438 @@ pub fn absolute_index_from_location(location: LineColumn, content: &str) -> usiz
     }
     index
 }
+
+pub fn range_from_spanned<T: Spanned + ?Sized>(node: &T) -> Range {
+    let span = node.span();
+    Range {
+        start: Position {
+            line: span.start().line,
+            character: span.start().column,
+        },
+        end: Position {
+            line: span.end().line,
+            character: span.end().column,
+        },
+    }
+}
+
+// Extract the text covered by a range from the file content
+pub fn text_from_range(content: &str, range: &Range) -> Option<String> {
+    let start = offset_from_position(content, &range.start)?;
+    let end = offset_from_position(content, &range.end)?;
+    content.get(start..end).map(|text| text.to_string())
+}
+
+fn normalize_args(args: &ArgList) -> ArgList {
+    let list = match &args.list {
+        ArgListImpl::Unnamed(args) => {
+            ArgListImpl::Unnamed(args.iter().map(normalize_member_access).collect())
+        }
+        ArgListImpl::Named(args) => ArgListImpl::Named(normalize_named_args(args)),
+    };
+    ArgList {
+        list,
+        ..args.clone()
+    }
+}
+
+fn normalize_named_args(args: &NamedArgList) -> NamedArgList {
+    NamedArgList {
+        list: args
+            .list
+            .iter()
+            .map(|arg| NamedArg {
+                arg: normalize_member_access(&arg.arg),
+                ..arg.clone()
+            })
+            .collect(),
+        ..args.clone()
+    }
+}
+
+fn normalize_boxed(expr: &Expr) -> Box<Expr> {
+    Box::new(normalize_member_access(expr))
+}
+
+// Apply the member access of `access` to the leftmost operand of `rest`
+fn attach_member(base: Expr, access: &ExprMember, rest: &Expr) -> Expr {
+    let attach = |expr: &Expr| Box::new(attach_member(base.clone(), access, expr));
+    match rest {
+        Expr::Member(member) => {
+            let inner = attach_member(base.clone(), access, &member.expr);
+            attach_member(inner, member, &member.member)
+        }
+        Expr::Call(call) => Expr::Call(ExprCall {
+            expr: attach(&call.expr),
+            args: normalize_args(&call.args),
+        }),
+        Expr::CallOptions(options) => Expr::CallOptions(ExprCallOptions {
+            expr: attach(&options.expr),
+            args: normalize_named_args(&options.args),
+        }),
+        Expr::Index(index) => Expr::Index(ExprIndex {
+            expr: attach(&index.expr),
+            start: index.start.as_deref().map(normalize_boxed),
+            end: index.end.as_deref().map(normalize_boxed),
+            ..index.clone()
+        }),
+        Expr::Binary(binary) => Expr::Binary(ExprBinary {
+            left: attach(&binary.left),
+            op: binary.op,
+            right: normalize_boxed(&binary.right),
+        }),
+        Expr::Postfix(postfix) => Expr::Postfix(ExprPostfix {
+            expr: attach(&postfix.expr),
+            op: postfix.op,
+        }),
+        Expr::Ternary(ternary) => Expr::Ternary(ExprTernary {
+            cond: attach(&ternary.cond),
+            if_true: normalize_boxed(&ternary.if_true),
+            if_false: normalize_boxed(&ternary.if_false),
+            ..ternary.clone()
+        }),
+        member => Expr::Member(ExprMember {
+            expr: Box::new(base),
+            dot_token: access.dot_token,
+            member: normalize_boxed(member),
+        }),
+    }
+}
+
+/// syn_solidity parses the member of `a.b` as a whole expression, so that `a.b(c) == d` is
+/// read as `a.(b(c) == d)`. Rebuild the expression with the member accesses applying to
+/// the identifier after the dot only, as in `((a.b)(c)) == d`.
+pub fn normalize_member_access(expr: &Expr) -> Expr {
+    match expr {
+        Expr::Member(member) => attach_member(
+            normalize_member_access(&member.expr),
+            member,
+            &member.member,
+        ),
+        Expr::Array(array) => Expr::Array(ExprArray {
+            elems: array.elems.iter().map(normalize_member_access).collect(),
+            ..array.clone()
+        }),
+        Expr::Binary(binary) => Expr::Binary(ExprBinary {
+            left: normalize_boxed(&binary.left),
+            op: binary.op,
+            right: normalize_boxed(&binary.right),
+        }),
+        Expr::Call(call) => Expr::Call(ExprCall {
+            expr: normalize_boxed(&call.expr),
+            args: normalize_args(&call.args),
+        }),
+        Expr::CallOptions(options) => Expr::CallOptions(ExprCallOptions {
+            expr: normalize_boxed(&options.expr),
+            args: normalize_named_args(&options.args),
+        }),
+        Expr::Delete(delete) => Expr::Delete(ExprDelete {
+            expr: normalize_boxed(&delete.expr),
+            ..delete.clone()
+        }),
+        Expr::Index(index) => Expr::Index(ExprIndex {
+            expr: normalize_boxed(&index.expr),
+            start: index.start.as_deref().map(normalize_boxed),
+            end: index.end.as_deref().map(normalize_boxed),
+            ..index.clone()
+        }),
+        Expr::Payable(payable) => Expr::Payable(ExprPayable {
+            args: normalize_args(&payable.args),
+            ..payable.clone()
+        }),
+        Expr::Postfix(postfix) => Expr::Postfix(ExprPostfix {
+            expr: normalize_boxed(&postfix.expr),
+            op: postfix.op,
+        }),
+        Expr::Ternary(ternary) => Expr::Ternary(ExprTernary {
+            cond: normalize_boxed(&ternary.cond),
+            if_true: normalize_boxed(&ternary.if_true),
+            if_false: normalize_boxed(&ternary.if_false),
+            ..ternary.clone()
+        }),
+        Expr::Tuple(tuple) => Expr::Tuple(ExprTuple {
+            elems: tuple.elems.iter().map(normalize_member_access).collect(),
+            ..tuple.clone()
+        }),
+        Expr::Unary(unary) => Expr::Unary(ExprUnary {
+            op: unary.op,
+            expr: normalize_boxed(&unary.expr),
+        }),
+        expr => expr.clone(),
+    }
+}
+
+/// Visitors of expressions with their member accesses normalized, see
+/// `normalize_member_access`.
+pub trait NormalizedVisit: for<'ast> Visit<'ast> {
+    /// Whether the expression visited is part of one already normalized.
+    fn in_normalized_expr(&mut self) -> &mut bool;
+}
+
+/// Visit the expression normalized, only normalizing the outermost expressions.
+pub fn visit_normalized_expr<V: NormalizedVisit>(visitor: &mut V, expr: &Expr) {
+    if *visitor.in_normalized_expr() {
+        return visit::visit_expr(visitor, expr);
+    }
+    *visitor.in_normalized_expr() = true;
+    visit::visit_expr(visitor, &normalize_member_access(expr));
+    *visitor.in_normalized_expr() = false;
+}
+
+// The variable at the root of an assignment target, `balances` for `balances[a].b`
+pub fn root_ident(expr: &Expr) -> Option<&SolIdent> {
+    match expr {
+        Expr::Ident(ident) => Some(ident),
+        Expr::Index(index) => root_ident(&index.expr),
+        Expr::Member(member) => root_ident(&member.expr),
+        _ => None,
+    }
+}
+
+pub fn is_assignment(op: &BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::Assign(..)
+            | BinOp::AddAssign(..)
+            | BinOp::SubAssign(..)
+            | BinOp::MulAssign(..)
+            | BinOp::DivAssign(..)
+            | BinOp::RemAssign(..)
+            | BinOp::BitAndAssign(..)
+            | BinOp::BitOrAssign(..)
+            | BinOp::BitXorAssign(..)
+            | BinOp::SarAssign(..)
+            | BinOp::ShlAssign(..)
+            | BinOp::ShrAssign(..)
+    )
+}
+
+/// Collects the variables written by the visited nodes: the roots of the assigned,
+/// incremented, decremented and deleted expressions, and of the arrays pushed or popped.
+#[derive(Default)]
+pub struct WritesVisitor {
+    pub writes: Vec<SolIdent>,
+    // assembly blocks aren't parsed, the variables they write with `sstore` or `.slot` are
+    // unknown
+    pub has_assembly: bool,
+    in_expr: bool,
+}
+
+impl WritesVisitor {
+    fn record(&mut self, target: &Expr) {
+        if let Expr::Tuple(tuple) = target {
+            tuple.elems.iter().for_each(|elem| self.record(elem));
+        } else if let Some(ident) = root_ident(target) {
+            self.writes.push(ident.clone());
+        }
+    }
+
+    pub fn is_written(&self, name: &str) -> bool {
+        self.writes.iter().any(|ident| ident == name)
+    }
+}
+
+impl NormalizedVisit for WritesVisitor {
+    fn in_normalized_expr(&mut self) -> &mut bool {
+        &mut self.in_expr
+    }
+}
+
+impl<'ast> Visit<'ast> for WritesVisitor {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        visit_normalized_expr(self, expr);
+    }
+
+    fn visit_expr_binary(&mut self, binary: &'ast ExprBinary) {
+        if is_assignment(&binary.op) {
+            self.record(&binary.left);
+        }
+        visit::visit_expr_binary(self, binary);
+    }
+
+    fn visit_expr_unary(&mut self, unary: &'ast ExprUnary) {
+        if matches!(unary.op, UnOp::Increment(..) | UnOp::Decrement(..)) {
+            self.record(&unary.expr);
+        }
+        visit::visit_expr_unary(self, unary);
+    }
+
+    fn visit_expr_postfix(&mut self, postfix: &'ast ExprPostfix) {
+        self.record(&postfix.expr);
+        visit::visit_expr_postfix(self, postfix);
+    }
+
+    fn visit_expr_delete(&mut self, delete: &'ast ExprDelete) {
+        self.record(&delete.expr);
+        visit::visit_expr_delete(self, delete);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let Expr::Member(member) = &*call.expr {
+            if let Expr::Ident(method) = &*member.member {
+                if method == "push" || method == "pop" {
+                    self.record(&member.expr);
+                }
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_stmt_asm(&mut self, _asm: &'ast StmtAssembly) {
+        self.has_assembly = true;
+    }
+}
+
+/// Collects the names referenced by the visited nodes, declarations excluded: the
+/// identifiers of the expressions and the first segments of the paths, such as types,
+/// base contracts and modifiers, and apart the members accessed.
+#[derive(Default)]
+pub struct ReferencesVisitor {
+    pub idents: HashSet<String>,
+    pub members: HashSet<String>,
+    // assembly blocks aren't parsed, the names they reference are unknown
+    pub has_assembly: bool,
+    in_expr: bool,
+}
+
+impl ReferencesVisitor {
+    /// Whether the name is referenced, as an identifier or a member.
+    pub fn is_referenced(&self, name: &str) -> bool {
+        self.idents.contains(name) || self.members.contains(name)
+    }
+}
+
+impl NormalizedVisit for ReferencesVisitor {
+    fn in_normalized_expr(&mut self) -> &mut bool {
+        &mut self.in_expr
+    }
+}
+
+impl<'ast> Visit<'ast> for ReferencesVisitor {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        // the identifiers of the expressions are dispatched to `visit_ident`, like the
+        // declared names
+        if let Expr::Ident(ident) = expr {
+            self.idents.insert(ident.as_string());
+            return;
+        }
+        visit_normalized_expr(self, expr);
+    }
+
+    fn visit_expr_member(&mut self, member: &'ast ExprMember) {
+        self.visit_expr(&member.expr);
+        match &*member.member {
+            Expr::Ident(name) => {
+                self.members.insert(name.as_string());
+            }
+            expr => self.visit_expr(expr),
+        }
+    }
+
+    fn visit_path(&mut self, path: &'ast SolPath) {
+        self.idents.insert(path.first().as_string());
+        self.members
+            .extend(path.iter().skip(1).map(|segment| segment.as_string()));
+    }
+
+    fn visit_stmt_asm(&mut self, _asm: &'ast StmtAssembly) {
+        self.has_assembly = true;
+    }
+
+    fn visit_item_contract(&mut self, contract: &'ast ItemContract) {
+        for base in contract.inheritance.iter().flat_map(|i| &i.inheritance) {
+            self.visit_path(&base.name);
+            base.arguments.iter().for_each(|arg| self.visit_expr(arg));
+        }
+        visit::visit_item_contract(self, contract);
+    }
+
+    fn visit_item_function(&mut self, function: &'ast ItemFunction) {
+        for attribute in function.attributes.iter() {
+            match attribute {
+                FunctionAttribute::Modifier(modifier) => {
+                    self.visit_path(&modifier.name);
+                    modifier
+                        .arguments
+                        .iter()
+                        .for_each(|arg| self.visit_expr(arg));
+                }
+                FunctionAttribute::Override(r#override) => {
+                    r#override
+                        .paths
+                        .iter()
+                        .for_each(|path| self.visit_path(path));
+                }
+                _ => {}
+            }
+        }
+        visit::visit_item_function(self, function);
+    }
+
+    fn visit_variable_definition(&mut self, var: &'ast VariableDefinition) {
+        visit::visit_variable_definition(self, var);
+        if let Some((_, initializer)) = &var.initializer {
+            self.visit_expr(initializer);
+        }
+    }
+
+    fn visit_using_directive(&mut self, using: &'ast UsingDirective) {
+        match &using.list {
+            UsingList::Single(path) => self.visit_path(path),
+            UsingList::Multiple(_, items) => {
+                items.iter().for_each(|item| self.visit_path(&item.path))
+            }
+        }
+        if let UsingType::Type(ty) = &using.ty {
+            self.visit_type(ty);
+        }
+    }
+}
+
+// Whether the expression can be evaluated at compile time, as a constant initializer
+pub fn is_constant_expr(expr: &Expr, constants: &[String]) -> bool {
+    match expr {
+        Expr::Lit(_) | Expr::LitDenominated(_) => true,
+        Expr::Ident(ident) => constants.contains(&ident.as_string()),
+        Expr::Binary(binary) => {
+            is_constant_expr(&binary.left, constants) && is_constant_expr(&binary.right, constants)
+        }
+        Expr::Unary(unary) => is_constant_expr(&unary.expr, constants),
+        Expr::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .all(|elem| is_constant_expr(elem, constants)),
+        // casts such as `address(0)` and hashes of constants
+        Expr::Call(call) => {
+            let callee = match &*call.expr {
+                Expr::Type(_) => true,
+                Expr::Ident(name) => name == "keccak256",
+                _ => false,
+            };
+            callee
+                && match &call.args.list {
+                    ArgListImpl::Unnamed(args) => {
+                        args.iter().all(|arg| is_constant_expr(arg, constants))
+                    }
+                    ArgListImpl::Named(_) => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+pub fn is_constant(var: &VariableDefinition) -> bool {
+    var.attributes
+        .0
+        .iter()
+        .any(|attribute| matches!(attribute, VariableAttribute::Constant(_)))
+}
+
+pub fn is_constant_or_immutable(var: &VariableDefinition) -> bool {
+    var.attributes.0.iter().any(|attribute| {
+        matches!(
+            attribute,
+            VariableAttribute::Constant(_) | VariableAttribute::Immutable(_)
+        )
+    })
+}
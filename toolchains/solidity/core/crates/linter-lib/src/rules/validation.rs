@@ -0,0 +1,285 @@
+use crate::errors::ConfigError;
+use crate::rules::rule_impl::PRESETS;
+use crate::rules::types::{DataSchema, DataSchemasMap};
+use crate::rules::{create_data_schemas, create_rules};
+use crate::types::Severity;
+use serde_json::{Map, Value};
+
+pub(crate) const SEVERITIES: [&str; 4] = ["ERROR", "WARNING", "INFO", "HINT"];
+const CONFIG_KEYS: [&str; 5] = ["name", "extends", "rules", "disabled", "overrides"];
+const RULE_KEYS: [&str; 3] = ["id", "severity", "data"];
+const OVERRIDE_KEYS: [&str; 3] = ["files", "rules", "disabled"];
+
+// Minimum similarities for a value to be suggested in place of an unknown one: the
+// Jaro-Winkler one favours typos, the edit distance one rules out values only sharing
+// a prefix, unless the value is a truncation of the candidate
+const SUGGESTION_THRESHOLD: f64 = 0.8;
+const SUGGESTION_EDIT_THRESHOLD: f64 = 0.6;
+
+pub fn closest_match<'a>(value: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let value = value.to_lowercase();
+    candidates
+        .iter()
+        .filter_map(|candidate| {
+            let candidate_lower = candidate.to_lowercase();
+            let similarity = strsim::jaro_winkler(&value, &candidate_lower);
+            let edit_similarity = strsim::normalized_levenshtein(&value, &candidate_lower);
+            let is_close =
+                edit_similarity >= SUGGESTION_EDIT_THRESHOLD || candidate_lower.starts_with(&value);
+            if similarity >= SUGGESTION_THRESHOLD && is_close {
+                Some((similarity, *candidate))
+            } else {
+                None
+            }
+        })
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, candidate)| candidate)
+}
+
+struct Validator {
+    rule_ids: Vec<String>,
+    schemas: DataSchemasMap,
+    errors: Vec<ConfigError>,
+}
+
+impl Validator {
+    fn error(&mut self, path: &str, message: String) {
+        self.errors.push(ConfigError {
+            path: path.to_string(),
+            message,
+            suggestion: None,
+        });
+    }
+
+    fn unknown(&mut self, path: &str, message: String, value: &str, candidates: &[&str]) {
+        self.errors.push(ConfigError {
+            path: path.to_string(),
+            message,
+            suggestion: closest_match(value, candidates).map(str::to_string),
+        });
+    }
+
+    fn check_keys(&mut self, object: &Map<String, Value>, path: &str, keys: &[&str]) {
+        for key in object.keys() {
+            if !keys.contains(&key.as_str()) {
+                let message = format!("unknown property \"{}\"", key);
+                self.unknown(&format!("{}.{}", path, key), message, key, keys);
+            }
+        }
+    }
+
+    fn check_rule_id(&mut self, id: &str, path: &str) {
+        if !self.rule_ids.iter().any(|rule_id| rule_id == id) {
+            let candidates: Vec<&str> = self.rule_ids.iter().map(String::as_str).collect();
+            let suggestion = closest_match(id, &candidates).map(str::to_string);
+            self.errors.push(ConfigError {
+                path: path.to_string(),
+                message: format!("unknown rule \"{}\"", id),
+                suggestion,
+            });
+        }
+    }
+
+    fn check_string_array(&mut self, value: &Value, path: &str) -> Vec<(String, String)> {
+        let mut strings = Vec::new();
+        match value.as_array() {
+            Some(values) => {
+                for (idx, value) in values.iter().enumerate() {
+                    let path = format!("{}[{}]", path, idx);
+                    match value.as_str() {
+                        Some(string) => strings.push((string.to_string(), path)),
+                        None => self.error(&path, "expected a string".to_string()),
+                    }
+                }
+            }
+            None => self.error(path, "expected an array of strings".to_string()),
+        }
+        strings
+    }
+
+    fn check_data(&mut self, data: &Value, schema: &DataSchema, path: &str) {
+        match schema {
+            DataSchema::Integer => {
+                if data.as_u64().is_none() {
+                    self.error(path, "expected a positive integer".to_string());
+                }
+            }
+            DataSchema::Boolean => {
+                if !data.is_boolean() {
+                    self.error(path, "expected a boolean".to_string());
+                }
+            }
+            DataSchema::String => {
+                if !data.is_string() {
+                    self.error(path, "expected a string".to_string());
+                }
+            }
+            DataSchema::Enum(values) => match data.as_str() {
+                Some(value) if values.contains(&value) => {}
+                Some(value) => {
+                    let message = format!("expected one of: {}", values.join(", "));
+                    self.unknown(path, message, value, values);
+                }
+                None => self.error(path, format!("expected one of: {}", values.join(", "))),
+            },
+            DataSchema::Array(items) => match data.as_array() {
+                Some(values) => {
+                    for (idx, value) in values.iter().enumerate() {
+                        self.check_data(value, items, &format!("{}[{}]", path, idx));
+                    }
+                }
+                None => self.error(path, "expected an array".to_string()),
+            },
+            DataSchema::Object(properties) => match data.as_object() {
+                Some(object) => {
+                    let keys: Vec<&str> = properties.iter().map(|(key, _)| *key).collect();
+                    self.check_keys(object, path, &keys);
+                    for (key, schema) in properties {
+                        if let Some(value) = object.get(*key) {
+                            self.check_data(value, schema, &format!("{}.{}", path, key));
+                        }
+                    }
+                }
+                None => self.error(path, "expected an object".to_string()),
+            },
+        }
+    }
+
+    fn check_severity(&mut self, severity: &Value, path: &str) {
+        match severity {
+            // the number of the severity is accepted too, from 1 for ERROR to 4 for HINT
+            Value::Number(number) if number.as_u64().and_then(Severity::from_number).is_some() => {}
+            Value::String(name) if SEVERITIES.contains(&name.as_str()) => {}
+            Value::String(name) => {
+                let message = format!("expected one of: {}", SEVERITIES.join(", "));
+                self.unknown(path, message, name, &SEVERITIES);
+            }
+            _ => self.error(
+                path,
+                format!(
+                    "expected one of: {}, or a number from 1 to 4",
+                    SEVERITIES.join(", ")
+                ),
+            ),
+        }
+    }
+
+    fn check_rule(&mut self, rule: &Value, path: &str) {
+        let rule = match rule.as_object() {
+            Some(rule) => rule,
+            None => return self.error(path, "expected a rule object".to_string()),
+        };
+        self.check_keys(rule, path, &RULE_KEYS);
+
+        let id = match rule.get("id").map(|id| id.as_str()) {
+            Some(Some(id)) => id,
+            Some(None) => return self.error(&format!("{}.id", path), "expected a string".into()),
+            None => return self.error(path, "missing property \"id\"".to_string()),
+        };
+        self.check_rule_id(id, &format!("{}.id", path));
+
+        match rule.get("severity") {
+            Some(severity) => self.check_severity(severity, &format!("{}.severity", path)),
+            None => self.error(path, "missing property \"severity\"".to_string()),
+        }
+
+        if let Some(data) = rule.get("data").filter(|data| !data.is_null()) {
+            if let Some(schema) = self.schemas.get(id).cloned() {
+                self.check_data(data, &schema, &format!("{}.data", path));
+            }
+        }
+    }
+
+    fn check_rules(&mut self, object: &Map<String, Value>, path: &str) {
+        if let Some(rules) = object.get("rules") {
+            match rules.as_array() {
+                Some(rules) => {
+                    for (idx, rule) in rules.iter().enumerate() {
+                        self.check_rule(rule, &format!("{}.rules[{}]", path, idx));
+                    }
+                }
+                None => self.error(&format!("{}.rules", path), "expected an array".into()),
+            }
+        }
+        if let Some(disabled) = object.get("disabled") {
+            for (id, path) in self.check_string_array(disabled, &format!("{}.disabled", path)) {
+                self.check_rule_id(&id, &path);
+            }
+        }
+    }
+
+    fn check_extends(&mut self, extends: &Value) {
+        let presets = match extends {
+            Value::String(preset) => vec![(preset.clone(), "$.extends".to_string())],
+            _ => self.check_string_array(extends, "$.extends"),
+        };
+        for (preset, path) in presets {
+            if !PRESETS.contains(&preset.as_str()) {
+                let message = format!("unknown preset \"{}\"", preset);
+                self.unknown(&path, message, &preset, &PRESETS);
+            }
+        }
+    }
+
+    fn check_override(&mut self, rules_override: &Value, path: &str) {
+        let object = match rules_override.as_object() {
+            Some(object) => object,
+            None => return self.error(path, "expected an override object".to_string()),
+        };
+        self.check_keys(object, path, &OVERRIDE_KEYS);
+
+        match object.get("files") {
+            Some(files) => {
+                for (pattern, path) in self.check_string_array(files, &format!("{}.files", path)) {
+                    if let Err(e) = glob::Pattern::new(&pattern) {
+                        self.error(&path, format!("invalid glob pattern: {}", e));
+                    }
+                }
+            }
+            None => self.error(path, "missing property \"files\"".to_string()),
+        }
+        self.check_rules(object, path);
+    }
+
+    fn check_config(&mut self, config: &Value) {
+        let object = match config.as_object() {
+            Some(object) => object,
+            None => return self.error("$", "expected a configuration object".to_string()),
+        };
+        self.check_keys(object, "$", &CONFIG_KEYS);
+
+        if let Some(name) = object.get("name") {
+            if !name.is_string() {
+                self.error("$.name", "expected a string".to_string());
+            }
+        }
+        if let Some(extends) = object.get("extends") {
+            self.check_extends(extends);
+        }
+        self.check_rules(object, "$");
+        if let Some(overrides) = object.get("overrides") {
+            match overrides.as_array() {
+                Some(overrides) => {
+                    for (idx, rules_override) in overrides.iter().enumerate() {
+                        self.check_override(rules_override, &format!("$.overrides[{}]", idx));
+                    }
+                }
+                None => self.error("$.overrides", "expected an array".to_string()),
+            }
+        }
+    }
+}
+
+/// Check a configuration against the known presets, rules and the schema of their data.
+pub fn validate_rules(config: &Value) -> Vec<ConfigError> {
+    let mut rule_ids: Vec<String> = create_rules().into_keys().collect();
+    rule_ids.sort();
+    let mut validator = Validator {
+        rule_ids,
+        schemas: create_data_schemas(),
+        errors: Vec::new(),
+    };
+
+    validator.check_config(config);
+    validator.errors
+}
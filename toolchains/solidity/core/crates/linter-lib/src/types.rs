@@ -12,6 +12,8 @@ mod file_diags;
 pub use file_diags::FileDiags;
 mod ignore;
 pub use ignore::*;
+mod fix;
+pub use fix::*;
 
 pub type LintResult = Result<FileDiags, SolidHunterError>;
 
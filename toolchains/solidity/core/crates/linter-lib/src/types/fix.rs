@@ -0,0 +1,149 @@
+use super::{LintDiag, Position, Range};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TextEdit {
+    /// The range of the text to replace. Empty ranges are insertions.
+    pub range: Range,
+
+    /// The replacement text. Empty text is a deletion.
+    #[serde(rename = "newText")]
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn replace(range: Range, new_text: String) -> Self {
+        TextEdit { range, new_text }
+    }
+
+    pub fn insert(position: Position, new_text: String) -> Self {
+        TextEdit {
+            range: Range {
+                start: position.clone(),
+                end: position,
+            },
+            new_text,
+        }
+    }
+
+    pub fn delete(range: Range) -> Self {
+        TextEdit {
+            range,
+            new_text: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Fix {
+    /// A human-readable description of what the fix does.
+    pub message: String,
+
+    /// The edits to apply together. A fix is never partially applied.
+    pub edits: Vec<TextEdit>,
+}
+
+#[derive(Clone, Debug)]
+pub struct FixResult {
+    /// The content with every applied fix.
+    pub content: String,
+
+    /// The diagnostics whose fix was applied.
+    pub applied: Vec<LintDiag>,
+
+    /// The diagnostics whose fix was skipped because it overlapped another fix.
+    pub skipped: Vec<LintDiag>,
+}
+
+// Convert a diagnostic position (1-based line, 0-based character) to a byte offset in the content
+pub fn offset_from_position(content: &str, position: &Position) -> Option<usize> {
+    if position.line == 0 {
+        return None;
+    }
+    let mut line_start = 0;
+    for _ in 1..position.line {
+        line_start += content[line_start..].find('\n')? + 1;
+    }
+    let line_end = content[line_start..]
+        .find('\n')
+        .map_or(content.len(), |idx| line_start + idx);
+    let line = &content[line_start..line_end];
+
+    if position.character == line.chars().count() {
+        return Some(line_end);
+    }
+    line.char_indices()
+        .nth(position.character)
+        .map(|(idx, _)| line_start + idx)
+}
+
+fn edits_offsets(content: &str, fix: &Fix) -> Option<Vec<(usize, usize)>> {
+    fix.edits
+        .iter()
+        .map(|edit| {
+            let start = offset_from_position(content, &edit.range.start)?;
+            let end = offset_from_position(content, &edit.range.end)?;
+            if start > end {
+                return None;
+            }
+            Some((start, end))
+        })
+        .collect()
+}
+
+fn is_overlapping(a: &(usize, usize), b: &(usize, usize)) -> bool {
+    // two insertions at the same place would be applied in an arbitrary order
+    a.0 == b.0 || (a.0 < b.1 && b.0 < a.1)
+}
+
+pub fn apply_fixes(content: &str, diags: &[LintDiag]) -> FixResult {
+    let mut candidates = Vec::new();
+    let mut skipped = Vec::new();
+
+    for diag in diags {
+        if let Some(fix) = &diag.fix {
+            match edits_offsets(content, fix) {
+                Some(offsets) if !offsets.is_empty() => candidates.push((diag, fix, offsets)),
+                _ => skipped.push(diag.clone()),
+            }
+        }
+    }
+    candidates.sort_by_key(|(_, _, offsets)| offsets.iter().map(|o| o.0).min());
+
+    let mut accepted: Vec<(usize, usize, &str)> = Vec::new();
+    let mut applied = Vec::new();
+    for (diag, fix, offsets) in candidates {
+        let mut conflicts = false;
+        for (idx, offset) in offsets.iter().enumerate() {
+            let overlaps_self = offsets[..idx].iter().any(|o| is_overlapping(o, offset));
+            let overlaps_accepted = accepted
+                .iter()
+                .any(|(start, end, _)| is_overlapping(&(*start, *end), offset));
+            if overlaps_self || overlaps_accepted {
+                conflicts = true;
+                break;
+            }
+        }
+        if conflicts {
+            skipped.push(diag.clone());
+            continue;
+        }
+        for (offset, edit) in offsets.iter().zip(&fix.edits) {
+            accepted.push((offset.0, offset.1, edit.new_text.as_str()));
+        }
+        applied.push(diag.clone());
+    }
+
+    // apply from the end of the file so earlier offsets stay valid
+    accepted.sort_by_key(|edit| std::cmp::Reverse(edit.0));
+    let mut fixed = content.to_string();
+    for (start, end, text) in accepted {
+        fixed.replace_range(start..end, text);
+    }
+
+    FixResult {
+        content: fixed,
+        applied,
+        skipped,
+    }
+}
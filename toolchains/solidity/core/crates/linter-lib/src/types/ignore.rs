@@ -7,12 +7,12 @@ macro_rules! define_ignore_enum {
             $($variant),*
         }
 
-        impl ToString for $name {
-            fn to_string(&self) -> String {
-                match self {
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let value = match self {
                     $(Self::$variant => $str),*
-                }
-                .to_string()
+                };
+                write!(f, "{}", value)
             }
         }
 
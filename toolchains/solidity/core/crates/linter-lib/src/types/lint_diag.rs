@@ -1,4 +1,4 @@
-use super::{Range, Severity};
+use super::{Fix, Range, Severity};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -25,6 +25,10 @@ pub struct LintDiag {
     pub id: String,
 
     pub uri: Uri,
+
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    /// Edits resolving the diagnostic, when the rule can fix it automatically.
+    pub fix: Option<Fix>,
 }
 
 impl fmt::Display for LintDiag {
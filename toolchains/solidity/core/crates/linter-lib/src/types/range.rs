@@ -1,7 +1,7 @@
 use super::Position;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Range {
     pub start: Position,
     pub end: Position,
@@ -1,8 +1,11 @@
 use colored::Colorize;
-use serde::{Deserialize, Serialize};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 
-#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Debug)]
+/// A severity is written with its name, or with its number as in the Language Server
+/// Protocol, from 1 for `ERROR` to 4 for `HINT`.
+#[derive(PartialEq, Eq, Clone, Copy, Serialize, Debug)]
 pub enum Severity {
     /// Reports an error.
     ERROR = 1,
@@ -25,3 +28,59 @@ impl fmt::Display for Severity {
         write!(f, "{}", severity)
     }
 }
+
+impl Severity {
+    /// The severity of a number, as in the Language Server Protocol.
+    pub fn from_number(number: u64) -> Option<Severity> {
+        match number {
+            1 => Some(Severity::ERROR),
+            2 => Some(Severity::WARNING),
+            3 => Some(Severity::INFO),
+            4 => Some(Severity::HINT),
+            _ => None,
+        }
+    }
+
+    /// Whether this severity is as severe as `threshold` or more (`ERROR` being the most severe).
+    pub fn is_at_least(self, threshold: Severity) -> bool {
+        (self as u8) <= (threshold as u8)
+    }
+}
+
+struct SeverityVisitor;
+
+impl Visitor<'_> for SeverityVisitor {
+    type Value = Severity;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("one of ERROR, WARNING, INFO, HINT, or a number from 1 to 4")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Severity, E> {
+        match value {
+            "ERROR" => Ok(Severity::ERROR),
+            "WARNING" => Ok(Severity::WARNING),
+            "INFO" => Ok(Severity::INFO),
+            "HINT" => Ok(Severity::HINT),
+            _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Severity, E> {
+        Severity::from_number(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Severity, E> {
+        u64::try_from(value)
+            .ok()
+            .and_then(Severity::from_number)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for Severity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SeverityVisitor)
+    }
+}
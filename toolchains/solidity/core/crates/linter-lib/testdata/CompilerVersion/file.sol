@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: MIT
+pragma solidity >=0.7.0 <0.9.0;
+pragma solidity ^0.8.19;
+pragma solidity 0.8.x;
+
+contract Test {
+    uint256 public value;
+}
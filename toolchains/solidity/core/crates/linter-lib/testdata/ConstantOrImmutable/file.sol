@@ -0,0 +1,66 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    uint256 private constant FEE = 100;
+    address private immutable admin;
+
+    uint256 private fee = FEE * 2;
+    string private name = "test";
+    bytes32 private salt = keccak256("salt");
+    uint256 private deployedAt = block.timestamp;
+    uint256 private scaled = admin == address(0) ? 1 : 2;
+    address owner;
+    uint256 private total;
+    uint256 private counter = 1;
+    uint256 private limit = 10;
+    uint256[] private values;
+
+    modifier bump() {
+        limit += 1;
+        _;
+    }
+
+    constructor() {
+        admin = msg.sender;
+        owner = msg.sender;
+    }
+
+    function deposit(uint256 amount) external bump {
+        total += amount;
+        counter++;
+        values.push(amount);
+    }
+
+    function shadow(uint256 fee) external pure returns (uint256) {
+        fee = 1;
+        return fee;
+    }
+}
+
+contract Base {
+    uint256 internal rate = 1;
+    uint256 internal cap = 10;
+    uint256 private base;
+
+    constructor() {
+        base = 1;
+    }
+}
+
+contract Derived is Base {
+    function setRate(uint256 _rate) external {
+        rate = _rate;
+    }
+}
+
+contract Assembly {
+    uint256 private slotted = 1;
+    uint256 private stored = 2;
+
+    function write(uint256 value) external {
+        assembly {
+            sstore(slotted.slot, value)
+            sstore(1, value)
+        }
+    }
+}
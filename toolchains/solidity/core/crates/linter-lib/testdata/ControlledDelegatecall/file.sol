@@ -0,0 +1,59 @@
+pragma solidity 0.8.19;
+
+contract Test {
+    address public owner;
+    address public implementation;
+
+    modifier onlyOwner() {
+        require(msg.sender == owner);
+        _;
+    }
+
+    function execute(address target, bytes calldata data) external {
+        (bool success, ) = target.delegatecall(data);
+        require(success);
+    }
+
+    function executeCast(address target, bytes calldata data) public {
+        (bool success, ) = payable(address(target)).delegatecall(data);
+        require(success);
+    }
+
+    function executeOwner(address target, bytes calldata data) external onlyOwner {
+        target.delegatecall(data);
+    }
+
+    function executeChecked(address target, bytes calldata data) external {
+        require(msg.sender == owner);
+        target.delegatecall(data);
+    }
+
+    function executeImplementation(bytes calldata data) external {
+        implementation.delegatecall(data);
+    }
+
+    function executeCall(address target, bytes calldata data) external {
+        target.call(data);
+    }
+
+    function _execute(address target, bytes calldata data) internal {
+        target.delegatecall(data);
+    }
+}
+
+contract Proxy is Test {
+    modifier onlyImplementation() {
+        require(msg.sender == implementation);
+        _;
+    }
+
+    function executeGuarded(address target, bytes calldata data) external onlyImplementation {
+        target.delegatecall(data);
+    }
+}
+
+contract ProxyChild is Proxy {
+    function executeChild(address target, bytes calldata data) external onlyImplementation {
+        target.delegatecall(data);
+    }
+}
@@ -0,0 +1,5 @@
+pragma solidity 0.8.0;
+
+import {Ownable} from "@oz/Ownable.sol";
+
+contract Token is Ownable {}
@@ -0,0 +1,6 @@
+pragma solidity 0.8.0;
+
+import {Test} from "forge-std/Test.sol";
+import {Token} from "../src/Token.sol";
+
+contract TokenTest is Test {}
@@ -0,0 +1,36 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    uint256[] private values;
+
+    function sum(uint256[] memory amounts) external pure returns (uint256 total) {
+        for (uint256 i; i < amounts.length; ++i) {
+            total += amounts[i];
+        }
+    }
+
+    function store(string memory name, bytes memory data) external {
+        values.push(data.length);
+        emit Stored(name);
+    }
+
+    function normalize(uint256[] memory amounts) external pure returns (uint256[] memory) {
+        amounts[0] = 0;
+        return amounts;
+    }
+
+    function sort(uint256[] memory amounts) public pure returns (uint256[] memory) {
+        return amounts;
+    }
+
+    function forward(bytes calldata data) external {
+        emit Forwarded(data);
+    }
+
+    event Stored(string name);
+    event Forwarded(bytes data);
+}
+
+interface ITest {
+    function sum(uint256[] memory amounts) external pure returns (uint256);
+}
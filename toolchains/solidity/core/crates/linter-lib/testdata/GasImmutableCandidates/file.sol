@@ -0,0 +1,47 @@
+pragma solidity ^0.8.0;
+
+interface IToken {
+    function transfer(address to, uint256 amount) external returns (bool);
+}
+
+contract Test {
+    uint256 private constant FEE = 100;
+    address private immutable admin;
+
+    address private owner;
+    IToken private token;
+    uint256 private fee = FEE * 2;
+    bytes32 private salt = keccak256("salt");
+    uint256 private deployedAt = block.timestamp;
+    uint256 private total;
+    string private name;
+    uint256 private counter = 1;
+
+    constructor(IToken _token, string memory _name) {
+        admin = msg.sender;
+        owner = msg.sender;
+        token = _token;
+        name = _name;
+    }
+
+    function deposit(uint256 amount) external {
+        total += amount;
+        counter++;
+    }
+}
+
+contract Base {
+    uint256 internal rate;
+    uint256 private cap;
+
+    constructor() {
+        rate = 1;
+        cap = 10;
+    }
+}
+
+contract Derived is Base {
+    function setRate(uint256 _rate) external {
+        rate = _rate;
+    }
+}
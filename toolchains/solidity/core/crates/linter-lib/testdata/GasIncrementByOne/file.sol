@@ -0,0 +1,18 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    function sum(uint256[] calldata amounts) external pure returns (uint256 total) {
+        for (uint256 i = 0; i < amounts.length; i++) {
+            total += amounts[i];
+        }
+        for (uint256 i = amounts.length; i > 0; i--) {
+            total += amounts[i - 1];
+        }
+        for (uint256 i = 0; i < amounts.length; ++i) {
+            total += amounts[i];
+        }
+        for (uint256 i = 0; i < amounts.length; i += 2) {
+            total += amounts[i];
+        }
+    }
+}
@@ -0,0 +1,24 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    uint256[] private values;
+
+    function sum() external view returns (uint256 total) {
+        for (uint256 i = 0; i < values.length; ++i) {
+            total += values[i];
+        }
+    }
+
+    function count(uint256[] calldata amounts) external pure returns (uint256 i) {
+        while (i < amounts.length && amounts[i] != 0) {
+            ++i;
+        }
+    }
+
+    function cached(uint256[] calldata amounts) external pure returns (uint256 total) {
+        uint256 length = amounts.length;
+        for (uint256 i = 0; i < length; ++i) {
+            total += amounts[i];
+        }
+    }
+}
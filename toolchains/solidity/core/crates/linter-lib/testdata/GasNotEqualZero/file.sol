@@ -0,0 +1,25 @@
+pragma solidity ^0.8.0;
+
+contract Test {
+    uint256 private total;
+    int256 private delta;
+    uint256[] private values;
+
+    function check(uint256 amount, int256 offset) external payable {
+        require(amount > 0);
+        require(total > 0 && values.length > 0, "empty");
+        require(msg.value > 0);
+        require(0 < amount);
+        require(offset > 0);
+        require(delta > 0);
+        require(amount > 1);
+        if (address(this).balance > 0) {
+            total = 0;
+        }
+    }
+
+    function shadow() external view {
+        int256 total = delta;
+        require(total > 0);
+    }
+}
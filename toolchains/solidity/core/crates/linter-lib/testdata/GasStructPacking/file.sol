@@ -0,0 +1,35 @@
+pragma solidity ^0.8.0;
+
+interface IToken {}
+
+enum Status {
+    Open,
+    Closed
+}
+
+struct Deposit {
+    address owner;
+    uint256 amount;
+    bool locked;
+}
+
+struct Packed {
+    address owner;
+    bool locked;
+    uint256 amount;
+}
+
+contract Test {
+    struct Order {
+        uint128 price;
+        bytes data;
+        IToken token;
+        uint64 createdAt;
+        Status status;
+        uint128 quantity;
+    }
+
+    struct Single {
+        uint256 amount;
+    }
+}
@@ -0,0 +1,8 @@
+pragma solidity 0.8.0;
+
+contract Test {
+    function awesome(uint256 value) public view returns (address) {
+        require(value > 0);
+        return tx.origin;
+    }
+}
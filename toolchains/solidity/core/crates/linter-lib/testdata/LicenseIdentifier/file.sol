@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity 0.8.20;
+
+contract Test {
+    uint256 public value;
+}
+
+// SPDX-License-Identifier: MIT
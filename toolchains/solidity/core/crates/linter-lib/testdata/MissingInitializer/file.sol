@@ -0,0 +1,39 @@
+pragma solidity 0.8.19;
+
+abstract contract Initializable {
+    modifier initializer() {
+        _;
+    }
+
+    modifier reinitializer(uint8 version) {
+        _;
+    }
+}
+
+contract Vault is Initializable {
+    address public owner;
+
+    function initialize(address _owner) external {
+        owner = _owner;
+    }
+
+    function initializeV2(address _owner) public reinitializer(2) {
+        owner = _owner;
+    }
+
+    function _initialize(address _owner) internal {
+        owner = _owner;
+    }
+}
+
+contract Token is OwnableUpgradeable {
+    function initialize() public initializer {}
+}
+
+contract Pool is Vault {
+    function initializePool() external {}
+}
+
+contract NotUpgradeable {
+    function initialize() external {}
+}
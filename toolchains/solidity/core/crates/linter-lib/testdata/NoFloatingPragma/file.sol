@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+pragma solidity >=0.8.0 <0.9.0;
+pragma solidity 0.8.20;
+pragma abicoder v2;
+
+contract Test {
+    uint256 public value;
+}
@@ -0,0 +1,12 @@
+pragma solidity ^0.8.0;
+
+import {add} from "./add.sol";
+
+struct Point {
+    uint256 x;
+    uint256 y;
+}
+
+error Unused();
+
+contract D {}
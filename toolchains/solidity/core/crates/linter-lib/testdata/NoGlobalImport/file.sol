@@ -10,8 +10,12 @@ import * from "C.sol"; //flag
 
 import "./D.sol"; //flag
 
-contract Test {
+contract Test is D {
     function test() public pure returns (uint256) {
         return add(1, 1);
     }
+
+    function origin() public pure returns (Point memory) {
+        return Point(0, 0);
+    }
 }
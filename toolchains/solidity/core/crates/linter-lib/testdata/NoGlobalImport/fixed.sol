@@ -0,0 +1,21 @@
+pragma solidity ^0.8.0;
+
+import {add} from "./add.sol"; //pas flag
+
+import "./A.sol" as A; //pas flag
+
+import * as B from "./B.sol"; //pas flag
+
+import * from "C.sol"; //flag
+
+import {D, Point} from "./D.sol"; //flag
+
+contract Test is D {
+    function test() public pure returns (uint256) {
+        return add(1, 1);
+    }
+
+    function origin() public pure returns (Point memory) {
+        return Point(0, 0);
+    }
+}
@@ -0,0 +1,26 @@
+pragma solidity 0.8.19;
+
+error Zero();
+error Unused();
+
+interface IVault {
+    error InterfaceError();
+}
+
+contract Test {
+    error Unauthorized(address caller);
+    error NeverReverted();
+    error Selector();
+
+    address public owner;
+
+    function withdraw(uint256 amount) external view returns (bytes4) {
+        if (msg.sender != owner) {
+            revert Unauthorized(msg.sender);
+        }
+        if (amount == 0) {
+            revert Zero();
+        }
+        return Selector.selector;
+    }
+}
@@ -0,0 +1,26 @@
+pragma solidity 0.8.19;
+
+event Global(uint256 value);
+event Unused();
+
+interface IVault {
+    event InterfaceEvent();
+}
+
+contract Test {
+    event Deposit(address from, uint256 amount);
+    event NeverEmitted();
+
+    function deposit() external payable {
+        emit Deposit(msg.sender, msg.value);
+        emit Global(msg.value);
+    }
+}
+
+contract Other {
+    event OtherEvent();
+
+    function run() external {
+        emit Test.Deposit(msg.sender, 1);
+    }
+}
@@ -0,0 +1,64 @@
+pragma solidity 0.8.19;
+
+library Helpers {
+    function double(uint256 value) internal pure returns (uint256) {
+        return value * 2;
+    }
+
+    function triple(uint256 value) internal pure returns (uint256) {
+        return value * 3;
+    }
+
+    function _half(uint256 value) private pure returns (uint256) {
+        return value / 2;
+    }
+}
+
+contract Base {
+    uint256 internal total;
+
+    modifier checked() {
+        _check();
+        _;
+    }
+
+    function _check() internal view {
+        require(total > 0);
+    }
+
+    function _hook() internal virtual {}
+
+    function _unusedInternal() internal {}
+
+    function _add(uint256 value) private {
+        total += value;
+    }
+
+    function _unusedPrivate() private {}
+
+    function add(uint256 value) external checked {
+        _add(value);
+        _hook();
+    }
+}
+
+contract Test is Base {
+    using Helpers for uint256;
+
+    function _hook() internal override {
+        total = total.double();
+    }
+
+    function _compute(uint256 value) private pure returns (uint256) {
+        return value;
+    }
+
+    function pointer() external pure returns (uint256) {
+        function(uint256) internal pure returns (uint256) f = _compute;
+        return f(1);
+    }
+
+    function _recursive(uint256 value) private returns (uint256) {
+        return value == 0 ? 0 : _recursive(value - 1);
+    }
+}
@@ -0,0 +1,30 @@
+pragma solidity 0.8.19;
+
+import {Ownable, Context} from "./Ownable.sol";
+import {IERC20 as Token, SafeERC20} from "./SafeERC20.sol";
+import {Math} from "./Math.sol";
+import {Strings} from "./Strings.sol";
+import {Errors} from "./Errors.sol";
+import {Unused} from "./Unused.sol";
+import {Position} from "./Position.sol";
+import "./Constants.sol" as Constants;
+import * as Helpers from "./Helpers.sol";
+import "./Global.sol";
+
+contract Test is Ownable {
+    using SafeERC20 for Token;
+    using Strings for uint256;
+
+    Position.Info public info;
+
+    function max(uint256 a, uint256 b) external pure returns (uint256) {
+        if (a == 0) {
+            revert Errors.Zero();
+        }
+        return Math.max(a, b);
+    }
+
+    function fee() external pure returns (uint256) {
+        return Constants.FEE;
+    }
+}
@@ -0,0 +1,56 @@
+pragma solidity 0.8.19;
+
+contract Test {
+    uint256 public total;
+    mapping(address => uint256) public balances;
+
+    event Deposit(address from, uint256 amount);
+
+    modifier onlyAbove(uint256 amount) {
+        require(amount > 0);
+        _;
+    }
+
+    function deposit(address from, uint256 amount, bytes memory data) external onlyAbove(amount) {
+        uint256 previous = balances[from];
+        uint256 fee = 1;
+        balances[from] = amount;
+        emit Deposit({from: from, amount: amount});
+    }
+
+    function add(uint256 value) external returns (uint256 result) {
+        uint256 unused;
+        total += value;
+        result = total;
+    }
+
+    function unnamed(uint256) external pure returns (uint256) {
+        return 1;
+    }
+
+    function hook(address from, uint256 amount) internal virtual {}
+
+    function interfaceLike(address to) external virtual;
+
+    function loop(uint256[] memory values) external {
+        for (uint256 i = 0; i < values.length; i++) {
+            total = values.length;
+        }
+        (uint256 a, uint256 b) = (1, 2);
+        total = a;
+    }
+
+    function member(uint256 length) external view returns (uint256) {
+        return address(this).balance;
+    }
+
+    function withAssembly(uint256 value) external pure returns (uint256 result) {
+        assembly {
+            result := value
+        }
+    }
+}
+
+function free(uint256 x, uint256 y) pure returns (uint256) {
+    return x;
+}
@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: MIT
+pragma abicoder v2;
+
+contract Test {
+    uint256 public value;
+}
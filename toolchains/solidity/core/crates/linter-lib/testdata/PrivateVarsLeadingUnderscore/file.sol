@@ -28,4 +28,7 @@ contract Test {
     function _thisIsInternal() internal returns (uint256 _bar) {}
     function _thisIsPrivate() private returns (uint256 _bar) {}
     function _thisIsPrivate() returns (uint256 _bar) {}
-}
\ No newline at end of file
+
+    uint256 private thisIsPrivateVariable;
+    uint256 public _thisIsPublicVariable;
+}
@@ -0,0 +1,7 @@
+pragma solidity 0.8.0;
+
+import {Ownable} from "./access/Ownable.sol";
+
+interface IToken {}
+
+contract Token is Ownable, IToken {}
@@ -0,0 +1,5 @@
+pragma solidity 0.8.0;
+
+import {Base} from "base/Base.sol";
+
+abstract contract Ownable is Base {}
@@ -0,0 +1,60 @@
+pragma solidity ^0.8.0;
+
+interface IToken {
+    function transfer(address to, uint256 amount) external returns (bool);
+}
+
+contract Guard {
+    modifier nonReentrant() {
+        _;
+    }
+}
+
+contract Vault is Guard {
+    mapping(address => uint256) public balances;
+    uint256 public total;
+    IToken public token;
+
+    function withdraw(uint256 amount) external {
+        (bool success, ) = msg.sender.call{value: amount}("");
+        require(success);
+        balances[msg.sender] -= amount;
+        total = total - amount;
+    }
+
+    function withdrawSafe(uint256 amount) external {
+        balances[msg.sender] -= amount;
+        total -= amount;
+        payable(msg.sender).transfer(amount);
+    }
+
+    function withdrawGuarded(uint256 amount) external nonReentrant {
+        (bool success, ) = msg.sender.call{value: amount}("");
+        require(success);
+        balances[msg.sender] = 0;
+    }
+
+    function pay(address to, uint256 amount) external {
+        token.transfer(to, amount);
+        total++;
+    }
+
+    function payCast(address to, uint256 amount) external {
+        if (amount > 0) {
+            IToken(to).transfer(msg.sender, amount);
+        }
+        delete balances[msg.sender];
+    }
+
+    function payAll(address[] calldata users) external {
+        for (uint256 i = 0; i < users.length; i++) {
+            total += 1;
+            payable(users[i]).send(1);
+        }
+    }
+
+    function shadowed(uint256 total) external {
+        payable(msg.sender).transfer(1);
+        total = 2;
+    }
+}
@@ -0,0 +1,58 @@
+pragma solidity ^0.8.0;
+
+contract Wallet {
+    error TransferFailed();
+
+    address public implementation;
+
+    function pay(address to, uint256 amount) external {
+        payable(to).send(amount);
+        to.call{value: amount}("");
+    }
+
+    function forward(bytes calldata payload) external returns (bytes memory) {
+        (, bytes memory data) = implementation.delegatecall(payload);
+        (bool unused, ) = implementation.staticcall(payload);
+        return data;
+    }
+
+    function payChecked(address to, uint256 amount) external {
+        (bool success, ) = to.call{value: amount}("");
+        require(success, "Transfer failed");
+        if (!payable(to).send(amount)) {
+            revert TransferFailed();
+        }
+    }
+
+    function tryPay(address to, uint256 amount) external returns (bool) {
+        bool sent = payable(to).send(amount);
+        if (sent) {
+            return true;
+        }
+        return payable(msg.sender).send(amount);
+    }
+
+    function assignChecked(address to, uint256 amount) external {
+        bool ok;
+        (ok, ) = to.call{value: amount}("");
+        assert(ok);
+    }
+
+    function assignUnchecked(address to, uint256 amount) external {
+        bool ok;
+        bytes memory data;
+        (ok, data) = to.call{value: amount}("");
+        ok = payable(to).send(amount);
+    }
+
+    function usedButUnchecked(address to, uint256 amount) external returns (uint256) {
+        (bool success, ) = to.call{value: amount}("");
+        uint256 flag = success ? 1 : 0;
+        return flag;
+    }
+
+    function returnChecked(address to, uint256 amount) external returns (bool) {
+        (bool success, ) = to.call{value: amount}("");
+        return success;
+    }
+}
@@ -0,0 +1,103 @@
+pragma solidity 0.8.19;
+
+contract Base {
+    address internal owner;
+
+    modifier onlyOwner() {
+        require(msg.sender == owner);
+        _;
+    }
+
+    function _destroy() internal {
+        selfdestruct(payable(owner));
+    }
+}
+
+contract Test is Base {
+    address public implementation;
+
+    function kill() external {
+        selfdestruct(payable(msg.sender));
+    }
+
+    function killOwner() external onlyOwner {
+        selfdestruct(payable(msg.sender));
+    }
+
+    function killGovernance() external onlyGovernance {
+        _destroy();
+    }
+
+    function killChecked() external {
+        require(msg.sender == owner, "not owner");
+        _destroy();
+    }
+
+    function killIf() public {
+        if (msg.sender != owner) {
+            revert();
+        }
+        _destroy();
+    }
+
+    function shutdown() public {
+        _shutdown();
+    }
+
+    function _shutdown() internal {
+        _destroy();
+    }
+
+    function upgrade(bytes memory data) external {
+        (bool success, ) = implementation.delegatecall(data);
+        require(success);
+    }
+
+    function _checkedUpgrade(bytes memory data) internal {
+        require(msg.sender == owner);
+        implementation.delegatecall(data);
+    }
+
+    function checkedUpgrade(bytes memory data) external {
+        _checkedUpgrade(data);
+    }
+
+    fallback() external {
+        implementation.delegatecall(msg.data);
+    }
+
+    function _internalKill() internal {
+        selfdestruct(payable(owner));
+    }
+
+    modifier onlyGovernance() {
+        _;
+    }
+}
+
+contract Guarded is Base {
+    modifier onlyGuardian() {
+        if (msg.sender != owner) {
+            revert();
+        }
+        _;
+    }
+
+    modifier whenActive() {
+        _;
+    }
+
+    function killGuardian() external onlyGuardian {
+        _destroy();
+    }
+
+    function killActive() external whenActive {
+        _destroy();
+    }
+}
+
+contract GuardedChild is Guarded {
+    function killChild() external onlyGuardian {
+        selfdestruct(payable(msg.sender));
+    }
+}
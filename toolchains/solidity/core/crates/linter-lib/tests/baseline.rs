@@ -0,0 +1,107 @@
+use solidhunter_lib::baseline::{fingerprint, Baseline};
+use solidhunter_lib::linter::SolidLinter;
+use solidhunter_lib::types::{LintDiag, LintResult, Position, Range, Severity};
+
+const SOURCE: &str = "pragma solidity ^0.8.0;
+
+contract Foo {
+    function owner() public view returns (address) {
+        return tx.origin;
+    }
+}
+";
+
+fn lint(content: &str) -> Vec<LintResult> {
+    let mut linter = SolidLinter::new_fileless();
+    vec![linter.parse_content("Foo.sol", content)]
+}
+
+fn diags_count(results: &[LintResult]) -> usize {
+    results.iter().flatten().map(|file| file.diags.len()).sum()
+}
+
+#[test]
+fn baseline_hides_recorded_findings_after_code_moves() {
+    let baseline = Baseline::from_results(&lint(SOURCE));
+    assert!(!baseline.is_empty());
+
+    // reindented and moved down, but still the same findings
+    let moved = format!("\n\n{}", SOURCE.replace("    ", "\t"));
+    let mut results = lint(&moved);
+    let total = diags_count(&results);
+
+    assert_eq!(baseline.filter(&mut results), total);
+    assert_eq!(diags_count(&results), 0);
+}
+
+#[test]
+fn baseline_reports_new_findings() {
+    let baseline = Baseline::from_results(&lint(SOURCE));
+    let changed = SOURCE.replace(
+        "}\n}",
+        "}\n\n    function sender() public view returns (address) {\n        return tx.origin;\n    }\n}",
+    );
+    let mut results = lint(&changed);
+    baseline.filter(&mut results);
+
+    let remaining: Vec<_> = results
+        .iter()
+        .flatten()
+        .flat_map(|file| &file.diags)
+        .collect();
+    assert!(!remaining.is_empty());
+    assert!(remaining.iter().all(|diag| diag.range.start.line > 6));
+}
+
+#[test]
+fn prune_removes_fixed_findings() {
+    let mut baseline = Baseline::from_results(&lint(SOURCE));
+    let fixed = SOURCE.replace("tx.origin", "msg.sender");
+    let results = lint(&fixed);
+
+    let pruned = baseline.prune(&results, &["Foo.sol".to_string()]);
+    assert!(pruned > 0);
+    assert_eq!(baseline.len(), diags_count(&results));
+}
+
+#[test]
+fn fingerprint_only_covers_the_message_and_first_line() {
+    let diag = LintDiag {
+        id: "function-max-lines".to_string(),
+        range: Range {
+            start: Position {
+                line: 4,
+                character: 4,
+            },
+            end: Position {
+                line: 6,
+                character: 5,
+            },
+        },
+        severity: Severity::WARNING,
+        code: None,
+        source: None,
+        message: "Function body is too long".to_string(),
+        uri: "Foo.sol".to_string(),
+        fix: None,
+    };
+    let changed_body = SOURCE.replace("tx.origin", "msg.sender");
+    let changed_line = SOURCE.replace("owner()", "admin()");
+
+    assert_eq!(
+        fingerprint(&diag, SOURCE),
+        fingerprint(&diag, &changed_body)
+    );
+    assert_ne!(
+        fingerprint(&diag, SOURCE),
+        fingerprint(&diag, &changed_line)
+    );
+    let other_message = LintDiag {
+        message: "Function has too many statements".to_string(),
+        ..diag.clone()
+    };
+    assert_ne!(
+        fingerprint(&diag, SOURCE),
+        fingerprint(&other_message, SOURCE)
+    );
+}
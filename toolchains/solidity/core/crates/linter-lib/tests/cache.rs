@@ -0,0 +1,149 @@
+use solidhunter_lib::cache::LintCache;
+use solidhunter_lib::linter::SolidLinter;
+use std::path::{Path, PathBuf};
+
+fn cache_path(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("solidhunter-cache-{}", name));
+    let _ = std::fs::remove_file(&path);
+    path.to_str().unwrap().to_string()
+}
+
+#[test]
+fn cache_is_keyed_on_content_and_config() {
+    let path = cache_path("keys");
+    let mut cache = LintCache::load(&path);
+    cache.insert("file.sol", "contract Foo {}", "config", "context", &[]);
+    cache.save().unwrap();
+
+    let cache = LintCache::load(&path);
+    assert!(cache
+        .get("file.sol", "contract Foo {}", "config", "context")
+        .is_some());
+    assert!(cache
+        .get("file.sol", "contract Bar {}", "config", "context")
+        .is_none());
+    assert!(cache
+        .get("other.sol", "contract Foo {}", "config", "context")
+        .is_none());
+    assert!(cache
+        .get("file.sol", "contract Foo {}", "other-config", "context")
+        .is_none());
+    assert!(cache
+        .get("file.sol", "contract Foo {}", "config", "other-context")
+        .is_none());
+}
+
+#[test]
+fn cached_folder_gives_same_diagnostics() {
+    let base = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join("ReasonString");
+    let path = cache_path("folder");
+    let lint = || {
+        let mut linter = SolidLinter::new();
+        linter
+            .initialize_rules(base.join(".solidhunter.json").to_str().unwrap())
+            .unwrap();
+        linter.initialize_cache(&path);
+        let results = linter.parse_path(base.to_str().unwrap());
+        linter.save_cache().unwrap();
+        results
+            .into_iter()
+            .map(|result| serde_json::to_string(&result.unwrap().diags).unwrap())
+            .collect::<Vec<String>>()
+    };
+
+    let first = lint();
+    assert!(std::path::Path::new(&path).exists());
+    assert_eq!(first, lint());
+}
+
+const BASE: &str =
+    "pragma solidity 0.8.19;\n\ncontract Base {\n    uint256 internal value = 1;\n}\n";
+const NOT_DERIVED: &str =
+    "pragma solidity 0.8.19;\n\nimport \"./Base.sol\";\n\ncontract Derived {}\n";
+const DERIVED: &str =
+    "pragma solidity 0.8.19;\n\nimport \"./Base.sol\";\n\ncontract Derived is Base {}\n";
+
+// A folder linted with the cache, giving the ids of the diagnostics of each file
+fn lint_cached_folder(folder: &Path, cache: &str) -> Vec<Vec<String>> {
+    let mut linter = SolidLinter::new();
+    linter
+        .initialize_rules(folder.join(".solidhunter.json").to_str().unwrap())
+        .unwrap();
+    linter.initialize_cache(cache);
+    let results = linter.parse_path(folder.to_str().unwrap());
+    linter.save_cache().unwrap();
+    results
+        .into_iter()
+        .map(|result| {
+            let diags = result.unwrap().diags;
+            diags.into_iter().map(|diag| diag.id).collect()
+        })
+        .collect()
+}
+
+fn create_folder(name: &str, derived: &str) -> PathBuf {
+    let folder = std::env::temp_dir().join(format!("solidhunter-cache-{}-folder", name));
+    let _ = std::fs::remove_dir_all(&folder);
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(
+        folder.join(".solidhunter.json"),
+        "{\"name\": \"solidhunter\", \"rules\": [{\"id\": \"constant-or-immutable\", \"severity\": \"WARNING\"}]}",
+    )
+    .unwrap();
+    std::fs::write(folder.join("Base.sol"), BASE).unwrap();
+    std::fs::write(folder.join("Derived.sol"), derived).unwrap();
+    folder
+}
+
+#[test]
+fn unchanged_files_are_cache_hits() {
+    let folder = create_folder("hit", NOT_DERIVED);
+    let path = cache_path("hit");
+    assert_eq!(
+        lint_cached_folder(&folder, &path),
+        vec![vec!["constant-or-immutable".to_string()], vec![]]
+    );
+
+    // the cached diagnostics are returned as they are, without linting the files again
+    let mut cache: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    for entry in cache["files"].as_object_mut().unwrap().values_mut() {
+        entry["diags"] = serde_json::json!([]);
+    }
+    std::fs::write(&path, cache.to_string()).unwrap();
+    assert_eq!(
+        lint_cached_folder(&folder, &path),
+        vec![Vec::<String>::new(), vec![]]
+    );
+}
+
+#[test]
+fn dependency_changes_invalidate_the_cache() {
+    let folder = create_folder("dependency", NOT_DERIVED);
+    let path = cache_path("dependency");
+    assert_eq!(
+        lint_cached_folder(&folder, &path),
+        vec![vec!["constant-or-immutable".to_string()], vec![]]
+    );
+
+    // a derived contract may assign the variable, while Base.sol did not change
+    std::fs::write(folder.join("Derived.sol"), DERIVED).unwrap();
+    assert_eq!(
+        lint_cached_folder(&folder, &path),
+        vec![Vec::<String>::new(), vec![]]
+    );
+
+    let mut linter = SolidLinter::new();
+    linter
+        .initialize_rules(folder.join(".solidhunter.json").to_str().unwrap())
+        .unwrap();
+    linter.initialize_cache(&path);
+    std::fs::write(folder.join("Derived.sol"), NOT_DERIVED).unwrap();
+    let _ = linter.parse_file(folder.join("Derived.sol").to_str().unwrap().to_string());
+    let base = linter
+        .parse_file(folder.join("Base.sol").to_str().unwrap().to_string())
+        .unwrap();
+    assert_eq!(base.diags.len(), 1);
+}
@@ -0,0 +1,171 @@
+use solidhunter_lib::errors::{ConfigError, SolidHunterError};
+use solidhunter_lib::linter::SolidLinter;
+use solidhunter_lib::rules::rule_impl::{create_preset_rules, parse_rules_content};
+use solidhunter_lib::rules::schema::create_config_schema;
+use solidhunter_lib::rules::validation::validate_rules;
+use solidhunter_lib::rules::{create_default_rules, create_rules};
+use solidhunter_lib::types::Severity;
+
+fn validate(config: &str) -> Vec<ConfigError> {
+    validate_rules(&serde_json::from_str(config).unwrap())
+}
+
+#[test]
+fn unknown_rule_suggests_closest_id() {
+    let errors = validate(
+        r#"{"name": "solidhunter", "rules": [{"id": "reason-strng", "severity": "WARNING"}]}"#,
+    );
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "$.rules[0].id");
+    assert_eq!(errors[0].suggestion.as_deref(), Some("reason-string"));
+}
+
+#[test]
+fn data_is_checked_against_rule_schema() {
+    let errors = validate(
+        r#"{
+            "name": "solidhunter",
+            "rules": [
+                {"id": "reason-string", "severity": "WARNING", "data": "32"},
+                {"id": "func-visibility", "severity": "WARNING", "data": {"ignoreConstructor": true}},
+                {"id": "explicit-types", "severity": "WARNING", "data": "explicit"}
+            ]
+        }"#,
+    );
+    let paths: Vec<&str> = errors.iter().map(|error| error.path.as_str()).collect();
+
+    assert_eq!(
+        paths,
+        vec!["$.rules[0].data", "$.rules[1].data.ignoreConstructor"]
+    );
+    assert_eq!(errors[1].suggestion.as_deref(), Some("ignoreConstructors"));
+}
+
+#[test]
+fn presets_severities_and_overrides_are_checked() {
+    let errors = validate(
+        r#"{
+            "name": "solidhunter",
+            "extends": ["recomended"],
+            "rules": [{"id": "no-console", "severity": "WARN"}],
+            "disabled": ["no-consol"],
+            "overrides": [{"files": ["[*.t.sol"]}]
+        }"#,
+    );
+    let found: Vec<(&str, Option<&str>)> = errors
+        .iter()
+        .map(|error| (error.path.as_str(), error.suggestion.as_deref()))
+        .collect();
+
+    assert_eq!(
+        found,
+        vec![
+            ("$.extends[0]", Some("recommended")),
+            ("$.rules[0].severity", Some("WARNING")),
+            ("$.disabled[0]", Some("no-console")),
+            ("$.overrides[0].files[0]", None),
+        ]
+    );
+}
+
+#[test]
+fn invalid_config_is_an_error() {
+    let mut linter = SolidLinter::new();
+    let res = linter.initialize_rules_content(
+        r#"{"name": "solidhunter", "rules": [{"id": "unknown-rule", "severity": "ERROR"}]}"#,
+    );
+
+    match res {
+        Err(SolidHunterError::ConfigError(errors)) => assert_eq!(errors.len(), 1),
+        _ => panic!("Expected a configuration error"),
+    }
+}
+
+#[test]
+fn schema_lists_rules_with_data_defaults() {
+    let schema = create_config_schema();
+    let rule_ids = schema["definitions"]["ruleId"]["enum"].as_array().unwrap();
+    let rules = schema["definitions"]["rule"]["oneOf"].as_array().unwrap();
+
+    assert!(rule_ids.contains(&"reason-string".into()));
+    assert_eq!(rules.len(), rule_ids.len());
+
+    let reason_string = rules
+        .iter()
+        .find(|rule| rule["properties"]["id"]["const"] == "reason-string")
+        .unwrap();
+    assert_eq!(reason_string["properties"]["data"]["type"], "integer");
+    assert_eq!(reason_string["properties"]["data"]["default"], 32);
+}
+
+#[test]
+fn gas_rules_are_only_enabled_by_presets() {
+    let ids = |rules: Vec<solidhunter_lib::rules::types::RuleEntry>| -> Vec<String> {
+        rules.into_iter().map(|rule| rule.id).collect()
+    };
+    let defaults = ids(create_default_rules());
+    let gas = ids(create_preset_rules("gas"));
+    let all = ids(create_preset_rules("all"));
+
+    assert!(!gas.is_empty());
+    assert!(gas.iter().all(|id| !defaults.contains(id)));
+    assert!(gas.iter().all(|id| all.contains(id)));
+    // the variables of gas-immutable-candidates are reported by constant-or-immutable
+    assert!(defaults.contains(&"constant-or-immutable".to_string()));
+    assert!(!gas.contains(&"gas-immutable-candidates".to_string()));
+    assert!(all.contains(&"gas-immutable-candidates".to_string()));
+}
+
+#[test]
+fn all_preset_enables_every_rule_once() {
+    let mut all: Vec<String> = create_preset_rules("all")
+        .into_iter()
+        .map(|rule| rule.id)
+        .collect();
+    all.sort();
+    let mut ids: Vec<String> = create_rules().into_keys().collect();
+    ids.sort();
+
+    assert_eq!(all, ids);
+}
+
+#[test]
+fn numeric_severities_are_lsp_severities() {
+    let config = |severity: &str| {
+        format!(
+            r#"{{"name": "solidhunter", "rules": [{{"id": "reason-string", "severity": {}}}]}}"#,
+            severity
+        )
+    };
+
+    let rules = parse_rules_content(&config("1")).unwrap();
+    assert_eq!(rules.rules[0].severity, Severity::ERROR);
+    let rules = parse_rules_content(&config("4")).unwrap();
+    assert_eq!(rules.rules[0].severity, Severity::HINT);
+    for severity in ["0", "5", "-1"] {
+        let errors = validate(&config(severity));
+        assert_eq!(errors.len(), 1, "{}", severity);
+        assert_eq!(errors[0].path, "$.rules[0].severity");
+    }
+    assert!(serde_json::from_str::<Severity>("0").is_err());
+    assert!(serde_json::from_str::<Severity>("\"NOTICE\"").is_err());
+}
+
+#[test]
+fn schema_lists_every_rule_and_severity() {
+    let schema = create_config_schema();
+    let rules = schema["definitions"]["rule"]["oneOf"].as_array().unwrap();
+    let ids: Vec<&str> = rules
+        .iter()
+        .map(|rule| rule["properties"]["id"]["const"].as_str().unwrap())
+        .collect();
+
+    assert_eq!(ids.len(), solidhunter_lib::rules::create_rules().len());
+    assert!(ids.contains(&"gas-immutable-candidates"));
+    assert!(ids.contains(&"gas-struct-packing"));
+
+    let number = &schema["definitions"]["severity"]["oneOf"][1];
+    assert_eq!(number["minimum"], 1);
+    assert_eq!(number["maximum"], 4);
+}
@@ -0,0 +1,95 @@
+use solidhunter_lib::foundry::FoundryProject;
+use solidhunter_lib::linter::SolidLinter;
+use solidhunter_lib::project::normalize_path;
+use std::path::PathBuf;
+
+fn project_path(path: &str) -> String {
+    let base = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join("FoundryProject");
+    normalize_path(base.join(path).to_str().unwrap())
+}
+
+#[test]
+fn remappings_are_read_from_config_files_and_libs() {
+    let project = FoundryProject::find(&PathBuf::from(project_path("src")))
+        .unwrap()
+        .unwrap();
+    let remappings: Vec<(String, String)> = project
+        .remappings
+        .iter()
+        .map(|remapping| (remapping.prefix.clone(), remapping.target.clone()))
+        .collect();
+
+    assert_eq!(
+        remappings,
+        vec![
+            ("@oz/".to_string(), project_path("lib/oz/contracts") + "/"),
+            (
+                "ds-test/".to_string(),
+                project_path("lib/forge-std/lib/ds-test/src") + "/"
+            ),
+            (
+                "forge-std/".to_string(),
+                project_path("lib/forge-std/src") + "/"
+            ),
+            ("oz/".to_string(), project_path("lib/oz") + "/"),
+        ]
+    );
+}
+
+#[test]
+fn only_project_sources_are_linted() {
+    let mut linter = SolidLinter::new_fileless();
+    linter.initialize_foundry(&project_path("")).unwrap();
+
+    let mut files: Vec<String> = linter
+        .get_path_files(&project_path(""))
+        .iter()
+        .map(|file| normalize_path(file))
+        .collect();
+    files.sort();
+    assert_eq!(
+        files,
+        vec![
+            project_path("src/Token.sol"),
+            project_path("test/Token.t.sol")
+        ]
+    );
+
+    linter.set_include_dependencies(true);
+    assert_eq!(linter.get_path_files(&project_path("")).len(), 4);
+}
+
+#[test]
+fn imports_are_resolved_with_remappings() {
+    let mut linter = SolidLinter::new_fileless();
+    linter.initialize_foundry(&project_path("")).unwrap();
+    linter.parse_path(&project_path(""));
+    let project = linter.project();
+
+    assert_eq!(
+        project.imports(&project_path("src/Token.sol")),
+        vec![project_path("lib/oz/contracts/Ownable.sol")]
+    );
+    assert_eq!(
+        project.imports(&project_path("test/Token.t.sol")),
+        vec![
+            project_path("lib/forge-std/src/Test.sol"),
+            project_path("src/Token.sol")
+        ]
+    );
+}
+
+#[test]
+fn remappings_of_a_relative_path_are_graph_paths() {
+    // the tests run from the crate directory
+    let project = FoundryProject::find(&PathBuf::from("testdata/FoundryProject"))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        project.remappings[0].target,
+        project_path("lib/oz/contracts") + "/"
+    );
+}
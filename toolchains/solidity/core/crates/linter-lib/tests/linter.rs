@@ -49,7 +49,7 @@ fn test_directory(base_name: &str) {
     test_linter(&config, &source, &expected_findings);
 }
 
-fn test_linter(config: &str, source: &str, expected_findings: &Vec<Finding>) {
+fn test_linter(config: &str, source: &str, expected_findings: &[Finding]) {
     let mut linter: SolidLinter = SolidLinter::new();
     let _ = linter.initialize_rules(&String::from(config));
 
@@ -63,9 +63,9 @@ fn test_linter(config: &str, source: &str, expected_findings: &Vec<Finding>) {
     match result {
         Ok(file_diags) => {
             let mut found;
-            for (_, diag) in file_diags.diags.iter().enumerate() {
+            for diag in file_diags.diags.iter() {
                 found = false;
-                for (_, expected_finding) in expected_findings.iter().enumerate() {
+                for expected_finding in expected_findings.iter() {
                     if (diag.range.start == expected_finding.start)
                         && (diag.range.end == expected_finding.end)
                         && (diag.id == expected_finding.id)
@@ -79,9 +79,9 @@ fn test_linter(config: &str, source: &str, expected_findings: &Vec<Finding>) {
                     not_needed_findings.push(diag);
                 }
             }
-            for (_, expected_finding) in expected_findings.iter().enumerate() {
+            for expected_finding in expected_findings.iter() {
                 found = false;
-                for (_, found_finding) in found_findings.iter().enumerate() {
+                for found_finding in found_findings.iter() {
                     if (expected_finding.start == found_finding.start)
                         && (expected_finding.end == found_finding.end)
                         && (expected_finding.id == found_finding.id)
@@ -90,13 +90,13 @@ fn test_linter(config: &str, source: &str, expected_findings: &Vec<Finding>) {
                         break;
                     }
                 }
-                if found == false {
+                if !found {
                     not_found_findings.push(expected_finding);
                 }
             }
-            if not_needed_findings.len() > 0 {
+            if !not_needed_findings.is_empty() {
                 println!("Diagnostics not expected:");
-                for (_, finding) in not_needed_findings.iter().enumerate() {
+                for finding in not_needed_findings.iter() {
                     println!(
                         "{}:{}:{}:{}:{}",
                         finding.id,
@@ -108,9 +108,9 @@ fn test_linter(config: &str, source: &str, expected_findings: &Vec<Finding>) {
                 }
                 not_needed_found = true;
             }
-            if not_found_findings.len() > 0 {
+            if !not_found_findings.is_empty() {
                 println!("\nMissing diagnostics:");
-                for (_, finding) in not_found_findings.iter().enumerate() {
+                for finding in not_found_findings.iter() {
                     println!(
                         "{}:{}:{}:{}:{}",
                         finding.id,
@@ -122,7 +122,7 @@ fn test_linter(config: &str, source: &str, expected_findings: &Vec<Finding>) {
                 }
                 not_found = true;
             }
-            assert_eq!(not_needed_found == true || not_found == true, false, "There are some missing or not needed diagnostics:\n Not needed found: {}\n Not found: {}", not_needed_findings.len(), not_found_findings.len());
+            assert!(!(not_needed_found || not_found), "There are some missing or not needed diagnostics:\n Not needed found: {}\n Not found: {}", not_needed_findings.len(), not_found_findings.len());
         }
         Err(e) => {
             panic!("{}", e);
@@ -173,6 +173,63 @@ test_directories! {
     FoundryTestFunctions,
     AvoidTxOrigin,
     SolidHunterIgnoreRule,
+    LicenseIdentifier,
+    NoFloatingPragma,
+    CompilerVersion,
+    PragmaRequired,
+    Reentrancy,
+    UncheckedLowLevelCall,
+    GasCalldataParameters,
+    GasLengthInLoops,
+    GasIncrementByOne,
+    GasImmutableCandidates,
+    GasStructPacking,
+    GasNotEqualZero,
+    ConstantOrImmutable,
+    UnprotectedCriticalCall,
+    MissingInitializer,
+    ControlledDelegatecall,
+    NoUnusedVars,
+    NoUnusedImport,
+    NoUnusedFunctions,
+    NoUnusedErrors,
+    NoUnusedEvents,
+}
+
+fn test_fix_directory(base_name: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join(base_name);
+    let mut linter: SolidLinter = SolidLinter::new();
+    let _ = linter.initialize_rules(path.join(".solidhunter.json").to_str().unwrap());
+    // fixes may depend on the other files of the directory
+    let _ = linter.parse_path(path.to_str().unwrap());
+
+    let result = linter
+        .fix_file(path.join("file.sol").to_str().unwrap().to_string())
+        .unwrap();
+    let expected = fs::read_to_string(path.join("fixed.sol")).unwrap();
+
+    assert_eq!(result.content, expected, "Invalid fixed content");
+}
+
+macro_rules! test_fix_directories {
+    ($($name:ident => $dir:ident),+ $(,)?) => {$(
+        #[allow(non_snake_case)]
+        #[test]
+        fn $name() {
+            test_fix_directory(stringify!($dir));
+        }
+    )+};
+}
+
+test_fix_directories! {
+    NoGlobalImportFix => NoGlobalImport,
+    VisibilityModifierOrderFix => VisibilityModifierOrder,
+    ExplicitTypesFix => ExplicitTypes,
+    ImplicitTypesFix => ImplicitTypes,
+    ReasonStringFix => ReasonString,
+    PrivateVarsLeadingUnderscoreFix => PrivateVarsLeadingUnderscore,
 }
 
 #[allow(non_snake_case)]
@@ -201,3 +258,150 @@ fn SolidhunterIgnore() {
 
     assert_eq!(diags_number, 3, "Invalid number of diagnostics");
 }
+
+#[allow(non_snake_case)]
+#[test]
+fn ParallelFolder() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata");
+    let config = path.join("ReasonString").join(".solidhunter.json");
+    let mut linter: SolidLinter = SolidLinter::new();
+    let _ = linter.initialize_rules(config.to_str().unwrap());
+
+    let folder_results = linter.parse_path(path.to_str().unwrap());
+    let filepaths = linter.get_path_files(path.to_str().unwrap());
+    assert_eq!(folder_results.len(), filepaths.len());
+
+    for (filepath, folder_result) in filepaths.into_iter().zip(folder_results) {
+        let file_result = linter.parse_file(filepath.clone());
+        match (folder_result, file_result) {
+            (Ok(folder_diags), Ok(file_diags)) => {
+                let folder_json = serde_json::to_string(&folder_diags.diags).unwrap();
+                let file_json = serde_json::to_string(&file_diags.diags).unwrap();
+                assert_eq!(
+                    folder_json, file_json,
+                    "Different diagnostics for {}",
+                    filepath
+                );
+            }
+            (Err(_), Err(_)) => {}
+            _ => panic!("Different result for {}", filepath),
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn ParallelFolderAddsFilesToProject() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join("HierarchicalConfig");
+    let mut linter: SolidLinter = SolidLinter::new();
+    let _ = linter.initialize_rules(path.join(".solidhunter.json").to_str().unwrap());
+
+    let filepaths = linter.get_path_files(path.to_str().unwrap());
+    let results = linter.parse_path(path.to_str().unwrap());
+
+    assert!(!filepaths.is_empty());
+    for (filepath, result) in filepaths.iter().zip(results) {
+        assert!(result.is_ok());
+        assert!(linter.project().file(filepath).is_some());
+    }
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn HierarchicalConfig() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join("HierarchicalConfig");
+    let mut linter: SolidLinter = SolidLinter::new();
+    linter
+        .initialize_rules(path.join(".solidhunter.json").to_str().unwrap())
+        .unwrap();
+
+    let mut found: Vec<(String, Vec<String>)> = Vec::new();
+    let filepaths = linter.get_path_files(path.to_str().unwrap());
+    let results = linter.parse_path(path.to_str().unwrap());
+    for (filepath, result) in filepaths.into_iter().zip(results) {
+        let diags = result.unwrap().diags;
+        let relative = PathBuf::from(&filepath)
+            .strip_prefix(&path)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        let mut ids: Vec<String> = diags.into_iter().map(|diag| diag.id).collect();
+        ids.sort();
+        found.push((relative, ids));
+    }
+    found.sort();
+
+    assert_eq!(
+        found,
+        vec![
+            (
+                "file.sol".to_string(),
+                vec!["avoid-tx-origin".to_string(), "reason-string".to_string()]
+            ),
+            ("file.t.sol".to_string(), vec!["reason-string".to_string()]),
+            (
+                "sub/file.sol".to_string(),
+                vec!["avoid-tx-origin".to_string()]
+            ),
+        ]
+    );
+}
+
+#[allow(non_snake_case)]
+#[test]
+fn RuleLocation() {
+    let testdata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata");
+    let location = |config: &PathBuf, filepath: PathBuf, id: &str| {
+        let mut linter: SolidLinter = SolidLinter::new();
+        linter
+            .initialize_rules(config.join(".solidhunter.json").to_str().unwrap())
+            .unwrap();
+        linter
+            .rule_location(filepath.to_str().unwrap(), id)
+            .unwrap()
+            .map(|location| (location.config_path, location.override_index))
+    };
+
+    // the closest config
+    let hierarchical = testdata.join("HierarchicalConfig");
+    assert_eq!(
+        location(
+            &hierarchical,
+            hierarchical.join("file.sol"),
+            "avoid-tx-origin"
+        ),
+        Some((hierarchical.join(".solidhunter.json"), None))
+    );
+    assert_eq!(
+        location(
+            &hierarchical,
+            hierarchical.join("sub").join("file.sol"),
+            "avoid-tx-origin"
+        ),
+        Some((hierarchical.join("sub").join(".solidhunter.json"), None))
+    );
+
+    // the override enabling the rule for the file
+    let overrides = testdata.join("RuleLocation");
+    assert_eq!(
+        location(
+            &overrides,
+            overrides.join("test").join("file.t.sol"),
+            "reason-string"
+        ),
+        Some((overrides.join(".solidhunter.json"), Some(0)))
+    );
+    assert_eq!(
+        location(&overrides, overrides.join("file.sol"), "reason-string"),
+        Some((overrides.join(".solidhunter.json"), None))
+    );
+    assert_eq!(
+        location(&overrides, overrides.join("file.t.sol"), "avoid-tx-origin"),
+        Some((overrides.join(".solidhunter.json"), None))
+    );
+}
@@ -0,0 +1,122 @@
+use solidhunter_lib::linter::SolidLinter;
+use solidhunter_lib::project::{normalize_path, ContractType, Remapping};
+use std::path::PathBuf;
+
+fn project_path(path: &str) -> String {
+    let base = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join("ProjectGraph");
+    normalize_path(base.join(path).to_str().unwrap())
+}
+
+fn lint_project() -> SolidLinter {
+    let mut linter = SolidLinter::new_fileless();
+    let remapping = format!("base/={}/", project_path("deps/base"));
+    linter.set_remappings(vec![Remapping::parse(&remapping).unwrap()]);
+    let results = linter.parse_path(&project_path(""));
+    assert!(results.iter().all(|result| result.is_ok()));
+    linter
+}
+
+#[test]
+fn remapping_is_parsed() {
+    let remapping = Remapping::parse("src:@oz/=lib/openzeppelin/").unwrap();
+    assert_eq!(remapping.context.as_deref(), Some("src"));
+    assert_eq!(remapping.prefix, "@oz/");
+    assert_eq!(remapping.target, "lib/openzeppelin/");
+    assert!(Remapping::parse("no-target").is_none());
+}
+
+#[test]
+fn imports_are_resolved() {
+    let linter = lint_project();
+    let project = linter.project();
+    let token = project_path("src/Token.sol");
+
+    assert_eq!(
+        project.imports(&token),
+        vec![project_path("src/access/Ownable.sol")]
+    );
+    assert_eq!(
+        project.transitive_imports(&token),
+        vec![
+            project_path("src/access/Ownable.sol"),
+            project_path("deps/base/Base.sol")
+        ]
+    );
+    assert_eq!(
+        project.imported_by(&project_path("deps/base/Base.sol")),
+        vec![project_path("src/access/Ownable.sol")]
+    );
+}
+
+#[test]
+fn inheritance_is_resolved_across_files() {
+    let linter = lint_project();
+    let project = linter.project();
+    let token = project_path("src/Token.sol");
+
+    let ancestors: Vec<(&str, ContractType)> = project
+        .ancestors(&token, "Token")
+        .iter()
+        .map(|contract| (contract.name.as_str(), contract.kind))
+        .collect();
+    assert_eq!(
+        ancestors,
+        vec![
+            ("Ownable", ContractType::AbstractContract),
+            ("IToken", ContractType::Interface),
+            ("Base", ContractType::Contract)
+        ]
+    );
+    assert_eq!(project.derived_contracts("Base")[0].name, "Ownable");
+
+    let mut descendants: Vec<&str> = project
+        .descendants("Base")
+        .iter()
+        .map(|contract| contract.name.as_str())
+        .collect();
+    descendants.sort();
+    assert_eq!(descendants, vec!["Ownable", "Token"]);
+}
+
+#[test]
+fn relative_paths_are_the_same_files() {
+    let linter = lint_project();
+    let project = linter.project();
+
+    // the tests run from the crate directory
+    assert_eq!(
+        project.imports("testdata/ProjectGraph/src/Token.sol"),
+        vec![project_path("src/access/Ownable.sol")]
+    );
+    assert!(project
+        .file("./testdata/ProjectGraph/deps/../src/Token.sol")
+        .is_some());
+}
+
+#[test]
+fn non_relative_imports_are_resolved_from_the_root() {
+    let mut linter = lint_project();
+    linter.initialize_foundry(&project_path("")).unwrap();
+    let vault = project_path("src/Vault.sol");
+    let _ = linter.parse_content(
+        &vault,
+        "pragma solidity 0.8.0;\n\nimport {Token} from \"src/Token.sol\";\n\ncontract Vault is Token {}\n",
+    );
+    let project = linter.project();
+
+    assert_eq!(project.imports(&vault), vec![project_path("src/Token.sol")]);
+    assert_eq!(
+        project.imported_by(&project_path("src/Token.sol")),
+        vec![vault.clone()]
+    );
+    assert_eq!(project.derived_contracts("Token")[0].file, vault);
+
+    linter.delete_file(&vault);
+    let project = linter.project();
+    assert!(project
+        .imported_by(&project_path("src/Token.sol"))
+        .is_empty());
+    assert!(project.derived_contracts("Token").is_empty());
+}
@@ -0,0 +1,114 @@
+use solidhunter_lib::linter::SolidLinter;
+use solidhunter_lib::reporters::sarif::SarifLog;
+use solidhunter_lib::reporters::{CheckstyleReporter, GithubReporter, JunitReporter, Reporter};
+use solidhunter_lib::rules::create_documentation;
+use solidhunter_lib::types::LintResult;
+use std::path::PathBuf;
+
+fn lint_directory(base_name: &str) -> (SolidLinter, Vec<LintResult>) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("testdata")
+        .join(base_name);
+    let mut linter: SolidLinter = SolidLinter::new();
+    let _ = linter.initialize_rules(path.join(".solidhunter.json").to_str().unwrap());
+
+    let results = linter.parse_path(path.join("file.sol").to_str().unwrap());
+    (linter, results)
+}
+
+#[test]
+fn sarif_maps_diagnostics() {
+    let (_, results) = lint_directory("ReasonString");
+    let sarif = SarifLog::new(&results, &create_documentation());
+
+    assert_eq!(sarif.version, "2.1.0");
+    let run = &sarif.runs[0];
+    // only the rules reported are described
+    assert_eq!(run.tool.driver.rules.len(), 1);
+    let rule = &run.tool.driver.rules[0];
+    assert_eq!(rule.id, "reason-string");
+    assert_eq!(rule.properties.category, "best-practices");
+    assert!(rule.help_uri.ends_with("reason_string.rs"));
+
+    assert_eq!(run.results.len(), 6);
+    let result = &run.results[0];
+    assert_eq!(result.rule_id, "reason-string");
+    assert_eq!(result.rule_index, Some(0));
+    assert_eq!(result.level, "warning");
+    let location = &result.locations[0].physical_location.artifact_location;
+    assert_eq!(location.uri, "testdata/ReasonString/file.sol");
+    assert_eq!(location.uri_base_id.as_deref(), Some("%SRCROOT%"));
+    assert!(run.original_uri_base_ids["%SRCROOT%"].uri.ends_with("/"));
+    let region = &result.locations[0].physical_location.region;
+    assert_eq!((region.start_line, region.start_column), (5, 38));
+    assert_eq!((region.end_line, region.end_column), (5, 76));
+    assert!(run.invocations[0].execution_successful);
+}
+
+#[test]
+fn sarif_encodes_uris() {
+    let (_, mut results) = lint_directory("ReasonString");
+    for diag in results
+        .iter_mut()
+        .flatten()
+        .flat_map(|diags| &mut diags.diags)
+    {
+        diag.uri = "src/My Token#1.sol".to_string();
+    }
+    let sarif = SarifLog::new(&results, &create_documentation());
+    let location = &sarif.runs[0].results[0].locations[0]
+        .physical_location
+        .artifact_location;
+
+    assert_eq!(location.uri, "src/My%20Token%231.sol");
+}
+
+#[test]
+fn junit_groups_failures_by_rule() {
+    let (_, results) = lint_directory("ReasonString");
+    let report = JunitReporter.report(&results);
+
+    assert!(report.starts_with("<?xml"));
+    assert!(report.contains("<testsuite name=\"solidhunter\""));
+    assert_eq!(report.matches("<testcase ").count(), 1);
+    assert_eq!(report.matches("<failure ").count(), 1);
+}
+
+#[test]
+fn checkstyle_reports_each_diagnostic() {
+    let (_, results) = lint_directory("ReasonString");
+    let report = CheckstyleReporter.report(&results);
+
+    assert!(report.contains("<checkstyle version=\"4.3\">"));
+    assert_eq!(report.matches("<error ").count(), 6);
+    assert!(report.contains("line=\"5\" column=\"38\" severity=\"warning\""));
+    assert!(report.contains("source=\"solidhunter.reason-string\""));
+}
+
+#[test]
+fn github_emits_workflow_commands() {
+    let (_, results) = lint_directory("ReasonString");
+    let report = GithubReporter.report(&results);
+    let first = report.lines().next().unwrap();
+
+    assert_eq!(report.lines().count(), 6);
+    assert!(first.starts_with("::warning file="));
+    assert!(first.contains(",line=5,col=38,endLine=5,endColumn=76,title=reason-string::"));
+}
+
+#[test]
+fn errors_are_reported_with_their_file() {
+    let mut linter = SolidLinter::new();
+    let results = vec![
+        linter.parse_file("missing/A.sol".to_string()),
+        linter.parse_file("missing/B.sol".to_string()),
+    ];
+
+    let junit = JunitReporter.report(&results);
+    assert!(junit.contains("<testcase classname=\"solidhunter\" name=\"missing/A.sol\">"));
+    assert!(junit.contains("<testcase classname=\"solidhunter\" name=\"missing/B.sol\">"));
+
+    let checkstyle = CheckstyleReporter.report(&results);
+    assert!(checkstyle.contains("<file name=\"missing/A.sol\">"));
+    assert!(checkstyle.contains("<file name=\"missing/B.sol\">"));
+}
@@ -0,0 +1,291 @@
+use osmium_libs_lsp_server_wrapper::lsp_types::*;
+use serde_json::Value;
+use solidhunter_lib::types::{self as lint_types, Fix, FixResult, Ignore};
+use std::collections::HashMap;
+
+const LICENSE_IDENTIFIER: &str = "SPDX-License-Identifier";
+
+pub fn range_from_lint_range(range: &lint_types::Range) -> Range {
+    Range {
+        start: Position {
+            line: range.start.line as u32 - 1,
+            character: range.start.character as u32,
+        },
+        end: Position {
+            line: range.end.line as u32 - 1,
+            character: range.end.character as u32,
+        },
+    }
+}
+
+// The range of the whole content, to replace it
+fn content_range(content: &str) -> Range {
+    let last_line = content.rsplit('\n').next().unwrap_or_default();
+    Range {
+        start: Position::default(),
+        end: Position {
+            line: content.matches('\n').count() as u32,
+            character: last_line.encode_utf16().count() as u32,
+        },
+    }
+}
+
+fn workspace_edit(uri: &Url, edits: Vec<TextEdit>) -> WorkspaceEdit {
+    WorkspaceEdit {
+        changes: Some(HashMap::from([(uri.clone(), edits)])),
+        ..WorkspaceEdit::default()
+    }
+}
+
+fn quick_fix(title: String, diag: &Diagnostic, edit: WorkspaceEdit) -> CodeAction {
+    CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diag.clone()]),
+        edit: Some(edit),
+        ..CodeAction::default()
+    }
+}
+
+/// Applies the fix of the rule.
+pub fn fix_action(uri: &Url, diag: &Diagnostic, fix: &Fix) -> CodeAction {
+    let edits = fix
+        .edits
+        .iter()
+        .map(|edit| TextEdit {
+            range: range_from_lint_range(&edit.range),
+            new_text: edit.new_text.clone(),
+        })
+        .collect();
+    CodeAction {
+        is_preferred: Some(true),
+        ..quick_fix(fix.message.clone(), diag, workspace_edit(uri, edits))
+    }
+}
+
+/// Inserts a comment disabling the rule on the line of the diagnostic, indented like it.
+pub fn disable_line_action(uri: &Url, diag: &Diagnostic, id: &str, content: &str) -> CodeAction {
+    let line = diag.range.start.line;
+    let indent: String = content
+        .lines()
+        .nth(line as usize)
+        .unwrap_or_default()
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    let edit = TextEdit {
+        range: Range {
+            start: Position { line, character: 0 },
+            end: Position { line, character: 0 },
+        },
+        new_text: format!("{}// {} {}\n", indent, Ignore::NextLine, id),
+    };
+    quick_fix(
+        format!("Disable {} for this line", id),
+        diag,
+        workspace_edit(uri, vec![edit]),
+    )
+}
+
+/// Inserts a comment disabling the rule at the top of the file, after the license.
+pub fn disable_file_action(uri: &Url, diag: &Diagnostic, id: &str, content: &str) -> CodeAction {
+    let has_license = content
+        .lines()
+        .next()
+        .is_some_and(|line| line.contains(LICENSE_IDENTIFIER));
+    let line = if has_license { 1 } else { 0 };
+    let edit = TextEdit {
+        range: Range {
+            start: Position { line, character: 0 },
+            end: Position { line, character: 0 },
+        },
+        new_text: format!("// {} {}\n", Ignore::Disable, id),
+    };
+    quick_fix(
+        format!("Disable {} for the entire file", id),
+        diag,
+        workspace_edit(uri, vec![edit]),
+    )
+}
+
+// The position of a byte offset of the content
+fn position_at(content: &str, offset: usize) -> Position {
+    let before = &content[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+fn skip_whitespace(text: &str, offset: usize) -> usize {
+    text.len() - text[offset..].trim_start().len()
+}
+
+// The offset after the JSON value starting at `start`
+fn value_end(text: &str, start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, byte) in text.bytes().enumerate().skip(start) {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' if depth == 0 => return Some(index),
+            b'}' | b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            b',' | b':' if depth == 0 => return Some(index),
+            byte if depth == 0 && byte.is_ascii_whitespace() => return Some(index),
+            _ => {}
+        }
+    }
+    (depth == 0 && !in_string).then_some(text.len())
+}
+
+// An entry of a JSON object or array: its key in an object, and the offsets of its value
+struct JsonEntry {
+    key: Option<String>,
+    start: usize,
+    end: usize,
+}
+
+// The entries of the JSON object or array starting at `start`
+fn json_entries(text: &str, start: usize) -> Option<Vec<JsonEntry>> {
+    let is_object = text[start..].starts_with('{');
+    let mut entries = Vec::new();
+    let mut offset = skip_whitespace(text, start + 1);
+
+    while !text[offset..].starts_with(['}', ']']) {
+        let key = if is_object {
+            let key_end = value_end(text, offset)?;
+            let key: String = serde_json::from_str(&text[offset..key_end]).ok()?;
+            offset = skip_whitespace(text, key_end);
+            text[offset..].strip_prefix(':')?;
+            offset = skip_whitespace(text, offset + 1);
+            Some(key)
+        } else {
+            None
+        };
+        let end = value_end(text, offset).filter(|end| *end > offset)?;
+        entries.push(JsonEntry {
+            key,
+            start: offset,
+            end,
+        });
+        offset = skip_whitespace(text, end);
+        if text[offset..].starts_with(',') {
+            offset = skip_whitespace(text, offset + 1);
+        }
+    }
+    Some(entries)
+}
+
+// Inserts an entry after the last one of the JSON object or array starting at `start`, on a
+// new line with the same indentation if the entries are on their own lines
+fn append_json_entry(text: &str, start: usize, entry: &str) -> Option<TextEdit> {
+    let (offset, new_text) = match json_entries(text, start)?.last() {
+        Some(last) if text[start..last.start].contains('\n') => {
+            let line_start = text[..last.start].rfind('\n').map_or(0, |index| index + 1);
+            let indent: String = text[line_start..]
+                .chars()
+                .take_while(|c| *c == ' ' || *c == '\t')
+                .collect();
+            (last.end, format!(",\n{}{}", indent, entry))
+        }
+        Some(last) => (last.end, format!(", {}", entry)),
+        None => (start + 1, entry.to_string()),
+    };
+    let position = position_at(text, offset);
+    Some(TextEdit {
+        range: Range {
+            start: position,
+            end: position,
+        },
+        new_text,
+    })
+}
+
+/// Appends the rule to the `disabled` rules of the config, or of its override at
+/// `override_index`, leaving the rest of the text as it is. None if the config can't be
+/// parsed or already disables the rule there.
+pub fn disable_config_action(
+    config_uri: &Url,
+    config: &str,
+    override_index: Option<usize>,
+    diag: &Diagnostic,
+    id: &str,
+) -> Option<CodeAction> {
+    let value: Value = serde_json::from_str(config).ok()?;
+    let target = match override_index {
+        Some(index) => value.get("overrides")?.get(index)?,
+        None => &value,
+    };
+    match target.get("disabled") {
+        Some(Value::Array(disabled)) if disabled.iter().any(|disabled| disabled == id) => {
+            return None
+        }
+        Some(Value::Array(_)) | None => {}
+        Some(_) => return None,
+    }
+
+    let mut start = skip_whitespace(config, 0);
+    if let Some(index) = override_index {
+        let overrides = json_entries(config, start)?
+            .into_iter()
+            .find(|entry| entry.key.as_deref() == Some("overrides"))?;
+        start = json_entries(config, overrides.start)?.get(index)?.start;
+    }
+    let id_text = serde_json::to_string(id).ok()?;
+    let disabled = json_entries(config, start)?
+        .into_iter()
+        .find(|entry| entry.key.as_deref() == Some("disabled"));
+    let edit = match disabled {
+        Some(disabled) => append_json_entry(config, disabled.start, &id_text)?,
+        None => append_json_entry(config, start, &format!("\"disabled\": [{}]", id_text))?,
+    };
+
+    let filename = config_uri
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .unwrap_or(".solidhunter.json");
+    Some(quick_fix(
+        format!("Disable {} in {}", id, filename),
+        diag,
+        workspace_edit(config_uri, vec![edit]),
+    ))
+}
+
+/// Replaces the content with the one where every fix is applied, none if there is no fix.
+pub fn fix_all_action(uri: &Url, content: &str, result: FixResult) -> Option<CodeAction> {
+    if result.applied.is_empty() {
+        return None;
+    }
+    let edit = TextEdit {
+        range: content_range(content),
+        new_text: result.content,
+    };
+    Some(CodeAction {
+        title: "Fix all auto-fixable problems".to_string(),
+        kind: Some(CodeActionKind::SOURCE_FIX_ALL),
+        edit: Some(workspace_edit(uri, vec![edit])),
+        ..CodeAction::default()
+    })
+}
@@ -1,22 +1,80 @@
 use osmium_libs_lsp_server_wrapper::{
-    lsp_types::*, Client, LanguageServer, LspStdioServer, RequestId, Result,
+    lsp_types::*, Client, Error, LanguageServer, LspStdioServer, RequestId, Result,
+};
+use solidhunter_lib::{
+    errors::SolidHunterError,
+    linter::SolidLinter,
+    rules::{schema::create_config_schema, utils::text_from_range},
+    types::{LintDiag, Severity},
+};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+    time::{Duration, Instant},
 };
-use solidhunter_lib::{linter::SolidLinter, types::LintDiag};
-use std::{cell::RefCell, rc::Rc};
 mod utils;
 use utils::get_closest_config_filepath;
+mod code_actions;
 mod get_content;
+mod settings;
+use code_actions::{
+    disable_config_action, disable_file_action, disable_line_action, fix_action, fix_all_action,
+    range_from_lint_range,
+};
+use settings::{LintOn, Settings};
+
+// Command returning the JSON Schema of the .solidhunter.json files
+const CONFIG_SCHEMA_COMMAND: &str = "osmium.getSolidhunterConfigSchema";
+
+// Rules reporting unused code, faded out by the editors
+const UNUSED_CODE_RULES: [&str; 5] = [
+    "no-unused-vars",
+    "no-unused-import",
+    "no-unused-functions",
+    "no-unused-errors",
+    "no-unused-events",
+];
+// Rule reporting the deprecated `now` alias of `block.timestamp`
+const NOT_RELY_ON_TIME_RULE: &str = "not-rely-on-time";
+const DIAGNOSTIC_SOURCE: &str = "osmium-solidity-linter";
 use get_content::{ContentRequest, ContentRequestParams, ContentResponse};
 
 struct Backend {
     connection: Rc<RefCell<Client>>,
     linter: RefCell<Option<SolidLinter>>,
+    root_path: RefCell<Option<String>>,
+    settings: RefCell<Settings>,
+    // the content of the open documents
+    documents: RefCell<HashMap<Url, String>>,
+    // the documents to lint once their changes are debounced, with the instant to lint them at
+    pending: RefCell<HashMap<Url, Instant>>,
+    // the content and diagnostics of the last lint of each document, for the code actions
+    diagnostics: RefCell<HashMap<Url, LintedDocument>>,
+}
+
+struct LintedDocument {
+    content: String,
+    diags: Vec<LintDiag>,
 }
 
 impl LanguageServer for Backend {
     fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
         let connection = self.connection.borrow_mut();
         connection.log_message(MessageType::INFO, "Server initializing!");
+        self.root_path
+            .replace(params.root_uri.as_ref().map(filepath_from_uri));
+        if let Some(options) = &params.initialization_options {
+            match serde_json::from_value::<Settings>(options.clone()) {
+                Ok(settings) => {
+                    self.settings.replace(settings);
+                }
+                Err(e) => connection.log_message(
+                    MessageType::ERROR,
+                    format!("Invalid initialization options, using the defaults: {}", e),
+                ),
+            }
+        }
         if let Ok(Some(path)) = get_closest_config_filepath(&connection, params.clone()) {
             connection.log_message(
                 MessageType::INFO,
@@ -24,31 +82,60 @@ impl LanguageServer for Backend {
             );
             let mut linter = SolidLinter::new();
 
-            let res = linter.initialize_rules(&path);
-            if res.is_ok() {
-                self.linter.replace(Some(linter));
-            } else {
-                connection.log_message(
-                    MessageType::ERROR,
-                    "Failed to initialize linter with workspace path, using fileless linter",
-                );
-                let linter = SolidLinter::new_fileless();
-                self.linter.replace(Some(linter));
+            match linter.initialize_rules(&path) {
+                Ok(()) => {
+                    self.initialize_foundry(&connection, &mut linter);
+                    self.linter.replace(Some(linter));
+                }
+                Err(e) => {
+                    if let SolidHunterError::ConfigError(_) = e {
+                        connection.show_message(MessageType::ERROR, &e);
+                    }
+                    connection.log_message(
+                        MessageType::ERROR,
+                        format!(
+                            "Failed to initialize linter with workspace path, using fileless linter: {}",
+                            e
+                        ),
+                    );
+                    let mut linter = SolidLinter::new_fileless();
+                    self.initialize_foundry(&connection, &mut linter);
+                    self.linter.replace(Some(linter));
+                }
             }
         } else {
             connection.log_message(
                 MessageType::INFO,
                 "Initializing linter without workspace path",
             );
-            let linter = SolidLinter::new_fileless();
+            let mut linter = SolidLinter::new_fileless();
+            self.initialize_foundry(&connection, &mut linter);
             self.linter.replace(Some(linter));
         }
         Ok(InitializeResult {
             server_info: None,
             capabilities: ServerCapabilities {
-                text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                text_document_sync: Some(TextDocumentSyncCapability::Options(
+                    TextDocumentSyncOptions {
+                        open_close: Some(true),
+                        change: Some(TextDocumentSyncKind::FULL),
+                        save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+                        ..TextDocumentSyncOptions::default()
+                    },
+                )),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![
+                            CodeActionKind::QUICKFIX,
+                            CodeActionKind::SOURCE_FIX_ALL,
+                        ]),
+                        ..CodeActionOptions::default()
+                    },
                 )),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec![CONFIG_SCHEMA_COMMAND.to_string()],
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
                 ..ServerCapabilities::default()
             },
         })
@@ -73,17 +160,57 @@ impl LanguageServer for Backend {
             format!("file opened!: {:}", params.text_document.uri),
         );
 
-        self.lint(params.text_document.uri, params.text_document.text);
+        let uri = params.text_document.uri;
+        self.documents
+            .borrow_mut()
+            .insert(uri.clone(), params.text_document.text);
+        self.lint_document(uri);
+    }
+
+    fn did_change(&self, mut params: DidChangeTextDocumentParams) {
+        let uri = params.text_document.uri;
+        // the changes are synchronized in full, the last one is the new content
+        let text = match params.content_changes.pop() {
+            Some(change) => change.text,
+            None => return,
+        };
+        self.documents.borrow_mut().insert(uri.clone(), text);
+
+        let settings = self.settings.borrow();
+        if settings.lint_on == LintOn::OnType {
+            self.pending
+                .borrow_mut()
+                .insert(uri, Instant::now() + settings.debounce());
+        }
     }
 
     fn did_save(&self, params: DidSaveTextDocumentParams) {
         self.connection.borrow_mut().log_message(
             MessageType::INFO,
-            format!("file changed!: {:}", params.text_document.uri),
+            format!("file saved!: {:}", params.text_document.uri),
         );
+        let uri = params.text_document.uri;
         if let Some(text) = params.text {
-            self.lint(params.text_document.uri, text);
+            self.documents.borrow_mut().insert(uri.clone(), text);
         }
+        self.lint_document(uri);
+    }
+
+    fn did_close(&self, params: DidCloseTextDocumentParams) {
+        self.connection.borrow_mut().log_message(
+            MessageType::INFO,
+            format!("file closed!: {:}", params.text_document.uri),
+        );
+        let uri = params.text_document.uri;
+        self.documents.borrow_mut().remove(&uri);
+        self.pending.borrow_mut().remove(&uri);
+        self.diagnostics.borrow_mut().remove(&uri);
+        if let Some(linter) = self.linter.borrow_mut().as_mut() {
+            linter.delete_file(&filepath_from_uri(&uri));
+        }
+        self.connection
+            .borrow_mut()
+            .publish_diagnostics(uri, vec![], None);
     }
 
     fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
@@ -121,6 +248,93 @@ impl LanguageServer for Backend {
         );
     }
 
+    fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = params.text_document.uri;
+        // the actions are computed on fresh diagnostics, to not edit outdated ranges
+        if self.pending.borrow().contains_key(&uri) {
+            self.lint_document(uri.clone());
+        }
+        let documents = self.documents.borrow();
+        let diagnostics = self.diagnostics.borrow();
+        let (content, lint_diags) = match (documents.get(&uri), diagnostics.get(&uri)) {
+            (Some(document), Some(linted)) if *document == linted.content => {
+                (&linted.content, linted.diags.as_slice())
+            }
+            _ => return Ok(None),
+        };
+        let requested = |kind: &CodeActionKind| match &params.context.only {
+            Some(only) => only
+                .iter()
+                .any(|only| kind.as_str().starts_with(only.as_str())),
+            None => true,
+        };
+
+        let mut actions: CodeActionResponse = Vec::new();
+        if requested(&CodeActionKind::QUICKFIX) {
+            for diag in &params.context.diagnostics {
+                let id = match (&diag.source, &diag.code) {
+                    (Some(source), Some(NumberOrString::String(id)))
+                        if source == DIAGNOSTIC_SOURCE =>
+                    {
+                        id
+                    }
+                    _ => continue,
+                };
+                let fix = lint_diags
+                    .iter()
+                    .find(|d| &d.id == id && range_from_lint_range(&d.range) == diag.range)
+                    .and_then(|d| d.fix.as_ref());
+                if let Some(fix) = fix {
+                    actions.push(fix_action(&uri, diag, fix).into());
+                }
+                actions.push(disable_line_action(&uri, diag, id, content).into());
+                actions.push(disable_file_action(&uri, diag, id, content).into());
+                if let Some(action) = self.disable_config_action(&uri, diag, id) {
+                    actions.push(action.into());
+                }
+            }
+        }
+        if requested(&CodeActionKind::SOURCE_FIX_ALL) {
+            if let Some(linter) = self.linter.borrow().as_ref() {
+                let result = linter.apply_fixes(content, lint_diags);
+                if let Some(action) = fix_all_action(&uri, content, result) {
+                    actions.push(action.into());
+                }
+            }
+        }
+        Ok(Some(actions))
+    }
+
+    fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<serde_json::Value>> {
+        if params.command == CONFIG_SCHEMA_COMMAND {
+            return Ok(Some(create_config_schema()));
+        }
+        Err(Error::method_not_found())
+    }
+
+    fn next_timeout(&self) -> Option<Duration> {
+        let now = Instant::now();
+        self.pending
+            .borrow()
+            .values()
+            .min()
+            .map(|deadline| deadline.saturating_duration_since(now))
+    }
+
+    fn on_timeout(&self) {
+        let now = Instant::now();
+        let due: Vec<Url> = self
+            .pending
+            .borrow()
+            .iter()
+            .filter(|(_, deadline)| **deadline <= now)
+            .map(|(uri, _)| uri.clone())
+            .collect();
+        for uri in due {
+            self.lint_document(uri);
+        }
+    }
+
     fn on_response(&self, _: RequestId, result: Option<serde_json::Value>) {
         self.connection
             .borrow_mut()
@@ -143,16 +357,23 @@ impl LanguageServer for Backend {
         let response: ContentResponse = res.unwrap();
 
         let mut linter = SolidLinter::new();
-        let res = linter.initialize_rules_content(&response.content);
-        if res.is_ok() {
-            self.connection
-                .borrow_mut()
-                .log_message(MessageType::INFO, "configuration file loaded!");
-            self.linter.replace(Some(linter));
-        } else {
-            self.connection
-                .borrow_mut()
-                .log_message(MessageType::ERROR, "configuration file failed to load!");
+        match linter.initialize_rules_content(&response.content) {
+            Ok(()) => {
+                let connection = self.connection.borrow_mut();
+                connection.log_message(MessageType::INFO, "configuration file loaded!");
+                self.initialize_foundry(&connection, &mut linter);
+                self.linter.replace(Some(linter));
+            }
+            Err(e) => {
+                let connection = self.connection.borrow_mut();
+                if let SolidHunterError::ConfigError(_) = e {
+                    connection.show_message(MessageType::ERROR, &e);
+                }
+                connection.log_message(
+                    MessageType::ERROR,
+                    format!("configuration file failed to load: {}", e),
+                );
+            }
         }
     }
 }
@@ -162,9 +383,53 @@ impl Backend {
         Self {
             connection,
             linter: RefCell::new(None),
+            root_path: RefCell::new(None),
+            settings: RefCell::new(Settings::default()),
+            documents: RefCell::new(HashMap::new()),
+            pending: RefCell::new(HashMap::new()),
+            diagnostics: RefCell::new(HashMap::new()),
         }
     }
 
+    // Resolve the imports with the remappings of the Foundry project of the workspace
+    fn initialize_foundry(&self, connection: &Client, linter: &mut SolidLinter) {
+        if let Some(root_path) = self.root_path.borrow().as_ref() {
+            if let Err(e) = linter.initialize_foundry(root_path) {
+                connection.log_message(
+                    MessageType::ERROR,
+                    format!("Failed to read the Foundry configuration: {}", e),
+                );
+            }
+        }
+    }
+
+    // Disables the rule in the config applying to the document, with the text of the config
+    // if it's open
+    fn disable_config_action(&self, uri: &Url, diag: &Diagnostic, id: &str) -> Option<CodeAction> {
+        let location = self
+            .linter
+            .borrow_mut()
+            .as_mut()?
+            .rule_location(&filepath_from_uri(uri), id)
+            .ok()??;
+        let config_uri = Url::from_file_path(&location.config_path).ok()?;
+        let config = match self.documents.borrow().get(&config_uri) {
+            Some(text) => text.clone(),
+            None => std::fs::read_to_string(&location.config_path).ok()?,
+        };
+        disable_config_action(&config_uri, &config, location.override_index, diag, id)
+    }
+
+    // Lint the content of an open document, cancelling its debounced lint
+    fn lint_document(&self, uri: Url) {
+        self.pending.borrow_mut().remove(&uri);
+        let text = match self.documents.borrow().get(&uri) {
+            Some(text) => text.clone(),
+            None => return,
+        };
+        self.lint(uri, text);
+    }
+
     pub fn lint(&self, uri: Url, text: String) {
         let filepath = filepath_from_uri(&uri);
         let mut linter = self.linter.borrow_mut();
@@ -176,14 +441,32 @@ impl Backend {
             }
         };
         let diags_res = linter.parse_content(&filepath, &text);
+        // the source of each rule, linked from its diagnostics
+        let source_links: HashMap<String, String> = linter
+            .get_documentation()
+            .into_iter()
+            .map(|doc| (doc.id, doc.source_link))
+            .collect();
 
         if let Ok(diags) = diags_res {
+            self.diagnostics.borrow_mut().insert(
+                uri.clone(),
+                LintedDocument {
+                    content: text.clone(),
+                    diags: diags.diags.clone(),
+                },
+            );
             let diags = diags
                 .diags
                 .iter()
-                .map(|d| diagnostic_from_lintdiag(d.clone()))
+                .map(|d| {
+                    let tags = diagnostic_tags(d, &text);
+                    let link = source_links
+                        .get(&d.id)
+                        .and_then(|link| Url::parse(link).ok());
+                    diagnostic_from_lintdiag(d.clone(), link, tags)
+                })
                 .collect();
-            eprintln!("diags: {:#?}", diags);
             self.connection
                 .borrow_mut()
                 .publish_diagnostics(uri.clone(), diags, None);
@@ -200,25 +483,43 @@ pub fn filepath_from_uri(uri: &Url) -> String {
     path.to_string()
 }
 
-fn diagnostic_from_lintdiag(diag: LintDiag) -> Diagnostic {
+fn severity_from_lint_severity(severity: Severity) -> DiagnosticSeverity {
+    match severity {
+        Severity::ERROR => DiagnosticSeverity::ERROR,
+        Severity::WARNING => DiagnosticSeverity::WARNING,
+        Severity::INFO => DiagnosticSeverity::INFORMATION,
+        Severity::HINT => DiagnosticSeverity::HINT,
+    }
+}
+
+// Unused code is tagged as unnecessary, and the deprecated `now` alias of
+// `block.timestamp` as deprecated
+fn diagnostic_tags(diag: &LintDiag, text: &str) -> Option<Vec<DiagnosticTag>> {
+    if UNUSED_CODE_RULES.contains(&diag.id.as_str()) {
+        return Some(vec![DiagnosticTag::UNNECESSARY]);
+    }
+    if diag.id == NOT_RELY_ON_TIME_RULE
+        && text_from_range(text, &diag.range).as_deref() == Some("now")
+    {
+        return Some(vec![DiagnosticTag::DEPRECATED]);
+    }
+    None
+}
+
+fn diagnostic_from_lintdiag(
+    diag: LintDiag,
+    source_link: Option<Url>,
+    tags: Option<Vec<DiagnosticTag>>,
+) -> Diagnostic {
     Diagnostic {
-        range: Range {
-            start: Position {
-                line: diag.range.start.line as u32 - 1,
-                character: diag.range.start.character as u32,
-            },
-            end: Position {
-                line: diag.range.end.line as u32 - 1,
-                character: diag.range.end.character as u32,
-            },
-        },
-        severity: Some(DiagnosticSeverity::WARNING),
-        code: None,
-        code_description: None,
-        source: Some("osmium-solidity-linter".to_string()),
+        range: range_from_lint_range(&diag.range),
+        severity: Some(severity_from_lint_severity(diag.severity)),
+        code: Some(NumberOrString::String(diag.id)),
+        code_description: source_link.map(|href| CodeDescription { href }),
+        source: Some(DIAGNOSTIC_SOURCE.to_string()),
         message: diag.message,
         related_information: None,
-        tags: None,
+        tags,
         data: None,
     }
 }
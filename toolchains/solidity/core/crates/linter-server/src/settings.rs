@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Delay without changes after which a document is linted while typing
+const DEFAULT_DEBOUNCE_MS: u64 = 300;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum LintOn {
+    #[default]
+    OnType,
+    OnSave,
+}
+
+/// The settings of the server, sent by the client in the `initializationOptions`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Settings {
+    pub lint_on: LintOn,
+    pub debounce_ms: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            lint_on: LintOn::default(),
+            debounce_ms: DEFAULT_DEBOUNCE_MS,
+        }
+    }
+}
+
+impl Settings {
+    pub fn debounce(&self) -> Duration {
+        Duration::from_millis(self.debounce_ms)
+    }
+}
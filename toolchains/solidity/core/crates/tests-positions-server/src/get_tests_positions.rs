@@ -24,6 +24,7 @@ pub struct GetTestsPositionsResponse {
     pub contracts: Vec<TestContract>,
 }
 
+#[allow(dead_code)]
 pub struct GetTestsPositionsRequest {}
 
 impl Request for GetTestsPositionsRequest {
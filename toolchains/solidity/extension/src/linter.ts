@@ -2,6 +2,7 @@ import * as path from 'path';
 import * as os from 'os';
 import { workspace, ExtensionContext, Uri } from "vscode";
 import {
+	ExecuteCommandRequest,
 	LanguageClient,
 	LanguageClientOptions,
 	ServerOptions,
@@ -28,8 +29,14 @@ export async function createLinterClient(context: ExtensionContext): Promise<Lan
 		}
 	};
 
+	const settings = workspace.getConfiguration('osmium.linter');
+
 	// Options to control the language client
 	const clientOptions: LanguageClientOptions = {
+		initializationOptions: {
+			lintOn: settings.get<string>('lintOn'),
+			debounceMs: settings.get<number>('debounceMs'),
+		},
 		// Register the server for plain text documents
 		documentSelector: [{ scheme: 'file', language: 'solidity' }],
 		synchronize: {
@@ -57,5 +64,15 @@ export async function createLinterClient(context: ExtensionContext): Promise<Lan
 	// Start the client. This will also launch the server
 	await client.start();
 
+	// Schema of the .solidhunter.json files, generated by the server from its rules
+	context.subscriptions.push(workspace.registerTextDocumentContentProvider('solidhunter-schema', {
+		provideTextDocumentContent: async () => {
+			const schema = await client.sendRequest(ExecuteCommandRequest.type, {
+				command: 'osmium.getSolidhunterConfigSchema',
+			});
+			return JSON.stringify(schema);
+		}
+	}));
+
     return client;
 }
\ No newline at end of file